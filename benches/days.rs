@@ -0,0 +1,48 @@
+use advent_of_code_2023::runner::{input_path, read_input_file};
+use advent_of_code_2023::solver::SOLVERS;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// Benchmarks the parsing and both parts of every registered day against its real input in the
+/// input folder. Days without an input file, or whose input can't be solved, are skipped.
+///
+/// # Arguments
+///
+/// 'c' - The benchmark manager.
+fn bench_days(c: &mut Criterion) {
+    for solver in SOLVERS {
+        let (year, day) = (solver.year(), solver.day());
+        let path = input_path(year, day);
+
+        let Ok(input) = read_input_file(&path) else {
+            eprintln!("Skipping day {day} of {year}: {path} not found");
+            continue;
+        };
+        if let Err(e) = solver.part_one(&input).and(solver.part_two(&input)) {
+            eprintln!("Skipping {e}");
+            continue;
+        }
+
+        let mut group = c.benchmark_group(format!("{year}/day{day:02}"));
+
+        if solver.parse(&input).is_some() {
+            group.bench_function("parse", |b| b.iter(|| solver.parse(black_box(&input))));
+        }
+        group.bench_function("part_one", |b| {
+            b.iter(|| solver.part_one(black_box(&input)))
+        });
+        group.bench_function("part_two", |b| {
+            b.iter(|| solver.part_two(black_box(&input)))
+        });
+
+        group.finish();
+    }
+}
+
+criterion_group! {
+    name = benches;
+    // Some of the days take a good fraction of a second per run, so keep the sample count low
+    // enough that the whole suite finishes in a reasonable time:
+    config = Criterion::default().sample_size(10);
+    targets = bench_days
+}
+criterion_main!(benches);
@@ -0,0 +1,9 @@
+#![no_main]
+
+use advent_of_code_2023::year2023::day20;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = day20::part_one(input);
+    let _ = day20::part_two(input);
+});
@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::fs::read_to_string;
+use std::io::ErrorKind;
+
+/// The year of the answers in sections that don't give one, which were written before the project
+/// covered more than one year.
+const DEFAULT_YEAR: u32 = 2023;
+
+/// The known answers for the real puzzle inputs, read from a file such as:
+///
+/// ```toml
+/// [2023.day01]
+/// part_one = 142
+/// part_two = "281"
+/// ```
+///
+/// Values may be written either bare or as quoted strings, and '#' starts a comment. A section
+/// without a year, such as `[day01]`, is for 2023.
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    answers: HashMap<(u32, u32, u32), String>,
+}
+
+impl Answers {
+    /// Loads the answers from the given file. A missing file is treated as having no known
+    /// answers.
+    ///
+    /// # Arguments
+    ///
+    /// 'filename' - The name of the answers file.
+    pub fn load(filename: &str) -> Result<Answers, String> {
+        match read_to_string(filename) {
+            Ok(text) => Answers::parse(&text).map_err(|msg| format!("{filename}: {msg}")),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(format!("Unable to read {filename}: {e}")),
+        }
+    }
+
+    /// Parses the text of an answers file.
+    ///
+    /// # Arguments
+    ///
+    /// 'text' - The contents of the answers file.
+    pub fn parse(text: &str) -> Result<Answers, String> {
+        let mut answers = HashMap::new();
+        let mut day = None;
+
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let line = strip_comment(line).trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(section) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                let invalid = || format!("line {line_number}: invalid day section '[{section}]'");
+                let (year, number) = match section.trim().split_once('.') {
+                    Some((year, number)) => (year.parse().map_err(|_| invalid())?, number),
+                    None => (DEFAULT_YEAR, section.trim()),
+                };
+                let number = number.trim_start_matches("day");
+                day = Some((year, number.parse::<u32>().map_err(|_| invalid())?));
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(format!("line {line_number}: expected 'key = value'"))?;
+
+            let day = day.ok_or(format!(
+                "line {line_number}: answer given before any [YYYY.dayNN] section"
+            ))?;
+
+            let part = match key.trim() {
+                "part_one" => 1,
+                "part_two" => 2,
+                key => return Err(format!("line {line_number}: unknown key '{key}'")),
+            };
+
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .unwrap_or(value);
+
+            answers.insert((day.0, day.1, part), String::from(value));
+        }
+
+        Ok(Answers { answers })
+    }
+
+    /// Returns the known answer for the given day and part, if there is one.
+    ///
+    /// # Arguments
+    ///
+    /// 'year' - The year of the calendar.
+    ///
+    /// 'day' - The day of the calendar.
+    ///
+    /// 'part' - The part of the puzzle (1 or 2).
+    pub fn get(&self, year: u32, day: u32, part: u32) -> Option<&str> {
+        self.answers.get(&(year, day, part)).map(String::as_str)
+    }
+
+    /// Compares a computed answer against the known answer for the given day and part.
+    ///
+    /// # Arguments
+    ///
+    /// 'year' - The year of the calendar.
+    ///
+    /// 'day' - The day of the calendar.
+    ///
+    /// 'part' - The part of the puzzle (1 or 2).
+    ///
+    /// 'actual' - The answer that was computed.
+    pub fn check(&self, year: u32, day: u32, part: u32, actual: &str) -> Status {
+        match self.get(year, day, part) {
+            Some(expected) if expected == actual => Status::Pass,
+            Some(expected) => Status::Fail(String::from(expected)),
+            None => Status::Unknown,
+        }
+    }
+}
+
+/// Returns the given line with any trailing comment removed. A '#' inside a quoted value does
+/// not start a comment.
+fn strip_comment(line: &str) -> &str {
+    let mut quoted = false;
+
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..i],
+            _ => {}
+        }
+    }
+
+    line
+}
+
+/// The outcome of checking a computed answer against the known answers.
+#[derive(Debug, Eq, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail(String),
+    Unknown,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => write!(f, "PASS"),
+            Status::Fail(_) => write!(f, "FAIL"),
+            Status::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::*;
+
+    #[test]
+    fn parse_answers() {
+        let answers = Answers::parse(
+            "# Answers for my inputs
+[day01]
+part_one = 142
+part_two = \"281\"
+
+[day5]
+part_one = \"35\" # seeds
+",
+        )
+        .unwrap();
+
+        assert_eq!(answers.get(2023, 1, 1), Some("142"));
+        assert_eq!(answers.get(2023, 1, 2), Some("281"));
+        assert_eq!(answers.get(2023, 5, 1), Some("35"));
+        assert_eq!(answers.get(2023, 5, 2), None);
+        assert_eq!(answers.get(2023, 2, 1), None);
+
+        let answers = Answers::parse("[day13]\npart_one = \"#.#\"").unwrap();
+        assert_eq!(answers.get(2023, 13, 1), Some("#.#"));
+
+        let answers =
+            Answers::parse("[2022.day01]\npart_one = 1\n[2023.day01]\npart_one = 2").unwrap();
+        assert_eq!(answers.get(2022, 1, 1), Some("1"));
+        assert_eq!(answers.get(2023, 1, 1), Some("2"));
+    }
+
+    #[test]
+    fn parse_invalid_answers() {
+        assert!(Answers::parse("part_one = 1").is_err());
+        assert!(Answers::parse("[dayX]").is_err());
+        assert!(Answers::parse("[20X3.day01]").is_err());
+        assert!(Answers::parse("[day01]\npart_three = 1").is_err());
+        assert!(Answers::parse("[day01]\npart_one").is_err());
+    }
+
+    #[test]
+    fn check_answers() {
+        let answers = Answers::parse("[day01]\npart_one = 142").unwrap();
+
+        assert_eq!(answers.check(2023, 1, 1, "142"), Status::Pass);
+        assert_eq!(
+            answers.check(2023, 1, 1, "143"),
+            Status::Fail(String::from("142"))
+        );
+        assert_eq!(answers.check(2023, 1, 2, "281"), Status::Unknown);
+        assert_eq!(answers.check(2022, 1, 1, "142"), Status::Unknown);
+    }
+}
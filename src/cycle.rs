@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The shape of the sequence of states produced by repeatedly applying a step function to an
+/// initial state: a prefix of states that are never seen again, followed by a loop of states that
+/// repeats forever.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    /// The number of steps before the first state that is part of the loop.
+    pub prefix: usize,
+    /// The number of steps it takes to go round the loop once.
+    pub period: usize,
+}
+
+impl Cycle {
+    /// Returns the earliest step that produces the same state as step 'n'. This is 'n' itself if
+    /// it comes before the loop starts, and otherwise a step during the first time round the loop.
+    ///
+    /// # Arguments
+    ///
+    /// 'n' - The number of steps taken from the initial state.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+
+    /// Returns the state after 'n' steps, taking no more steps than it takes to reach the end of
+    /// the first time round the loop.
+    ///
+    /// # Arguments
+    ///
+    /// 'initial' - The state before any steps are taken.
+    ///
+    /// 'step' - The function that gives the next state from a state.
+    ///
+    /// 'n' - The number of steps to take.
+    pub fn state_at<S, F>(&self, initial: S, mut step: F, n: usize) -> S
+    where
+        F: FnMut(&S) -> S,
+    {
+        (0..self.equivalent_step(n)).fold(initial, |state, _| step(&state))
+    }
+}
+
+/// Finds the cycle using Floyd's tortoise and hare algorithm, which only keeps three states at a
+/// time. Returns None if no state repeats within 'limit' steps.
+///
+/// # Arguments
+///
+/// 'initial' - The state before any steps are taken.
+///
+/// 'step' - The function that gives the next state from a state.
+///
+/// 'key' - The function that gives the value used to compare two states.
+///
+/// 'limit' - The number of steps to give up after.
+pub fn floyd<S, F, K, P>(initial: S, mut step: F, mut key: K, limit: usize) -> Option<Cycle>
+where
+    F: FnMut(&S) -> S,
+    K: FnMut(&S) -> P,
+    P: PartialEq,
+{
+    // Move the hare at twice the speed of the tortoise until they meet somewhere in the loop.
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    let mut steps = 1;
+
+    while key(&tortoise) != key(&hare) {
+        if steps >= limit {
+            return None;
+        }
+
+        tortoise = step(&tortoise);
+        let next = step(&hare);
+        hare = step(&next);
+        steps += 1;
+    }
+
+    // The distance between them is now a multiple of the period, so moving them together from
+    // the start and the meeting point brings them together at the start of the loop.
+    let mut prefix = 0;
+    tortoise = initial;
+
+    while key(&tortoise) != key(&hare) {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    let mut period = 1;
+    hare = step(&tortoise);
+
+    while key(&tortoise) != key(&hare) {
+        hare = step(&hare);
+        period += 1;
+    }
+
+    Some(Cycle { prefix, period })
+}
+
+/// Finds the cycle using Brent's algorithm, which only keeps two states at a time and usually
+/// takes fewer steps than Floyd's. Returns None if no state repeats within 'limit' steps.
+///
+/// # Arguments
+///
+/// 'initial' - The state before any steps are taken.
+///
+/// 'step' - The function that gives the next state from a state.
+///
+/// 'key' - The function that gives the value used to compare two states.
+///
+/// 'limit' - The number of steps to give up after.
+pub fn brent<S, F, K, P>(initial: S, mut step: F, mut key: K, limit: usize) -> Option<Cycle>
+where
+    F: FnMut(&S) -> S,
+    K: FnMut(&S) -> P,
+    P: PartialEq,
+{
+    // Search successive powers of two for the period, comparing the hare against the state it
+    // was in at the start of each power.
+    let mut power = 1;
+    let mut period = 1;
+    let mut steps = 1;
+    let mut saved = key(&initial);
+    let mut hare = step(&initial);
+
+    while key(&hare) != saved {
+        if steps >= limit {
+            return None;
+        }
+
+        if power == period {
+            saved = key(&hare);
+            power *= 2;
+            period = 0;
+        }
+
+        hare = step(&hare);
+        period += 1;
+        steps += 1;
+    }
+
+    // With the hare a period ahead of the tortoise, they meet at the start of the loop.
+    let mut hare = (1..period).fold(step(&initial), |state, _| step(&state));
+    let mut tortoise = initial;
+    let mut prefix = 0;
+
+    while key(&tortoise) != key(&hare) {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    Some(Cycle { prefix, period })
+}
+
+/// Finds the cycle by remembering the step at which each state was first seen, which takes the
+/// fewest steps but keeps every state. Returns the cycle along with the states from the initial
+/// state up to the end of the first time round the loop, so that the state at any step 'n' is
+/// found at `cycle.equivalent_step(n)`. Returns None if no state repeats within 'limit' steps.
+///
+/// # Arguments
+///
+/// 'initial' - The state before any steps are taken.
+///
+/// 'step' - The function that gives the next state from a state.
+///
+/// 'key' - The function that gives the value used to compare two states.
+///
+/// 'limit' - The number of steps to give up after.
+pub fn find_cycle<S, F, K, P>(
+    initial: S,
+    mut step: F,
+    mut key: K,
+    limit: usize,
+) -> Option<(Cycle, Vec<S>)>
+where
+    F: FnMut(&S) -> S,
+    K: FnMut(&S) -> P,
+    P: Hash + Eq,
+{
+    let mut seen = HashMap::from([(key(&initial), 0)]);
+    let mut states = vec![initial];
+
+    for steps in 1..=limit {
+        let state = step(&states[steps - 1]);
+
+        if let Some(&prefix) = seen.get(&key(&state)) {
+            let cycle = Cycle {
+                prefix,
+                period: steps - prefix,
+            };
+            return Some((cycle, states));
+        }
+
+        seen.insert(key(&state), steps);
+        states.push(state);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cycle::*;
+
+    // 0, 1, 2, 3, 4, 5, 6, 3, 4, ...
+    fn rho(n: &usize) -> usize {
+        if *n == 6 {
+            3
+        } else {
+            n + 1
+        }
+    }
+
+    #[test]
+    fn find_cycles() {
+        let expected = Cycle {
+            prefix: 3,
+            period: 4,
+        };
+
+        assert_eq!(floyd(0, rho, |n| *n, 100), Some(expected));
+        assert_eq!(brent(0, rho, |n| *n, 100), Some(expected));
+        assert_eq!(
+            find_cycle(0, rho, |n| *n, 100),
+            Some((expected, vec![0, 1, 2, 3, 4, 5, 6]))
+        );
+    }
+
+    #[test]
+    fn find_cycles_without_a_prefix() {
+        let step = |n: &u32| (n + 1) % 5;
+        let expected = Cycle {
+            prefix: 0,
+            period: 5,
+        };
+
+        assert_eq!(floyd(0, step, |n| *n, 100), Some(expected));
+        assert_eq!(brent(0, step, |n| *n, 100), Some(expected));
+        assert_eq!(
+            find_cycle(0, step, |n| *n, 100).map(|c| c.0),
+            Some(expected)
+        );
+        assert_eq!(brent(7, |n| *n, |n| *n, 100).map(|c| c.period), Some(1));
+    }
+
+    #[test]
+    fn give_up_at_the_limit() {
+        let step = |n: &u64| n + 1;
+
+        assert_eq!(floyd(0, step, |n| *n, 1000), None);
+        assert_eq!(brent(0, step, |n| *n, 1000), None);
+        assert_eq!(find_cycle(0, step, |n| *n, 1000), None);
+    }
+
+    #[test]
+    fn find_state_at_any_step() {
+        let cycle = brent(0, rho, |n| *n, 100).unwrap();
+
+        assert_eq!(cycle.equivalent_step(2), 2);
+        assert_eq!(cycle.equivalent_step(7), 3);
+        assert_eq!(cycle.state_at(0, rho, 1_000_000_001), 5);
+        for n in 0..50 {
+            let state = (0..n).fold(0, |state, _| rho(&state));
+            assert_eq!(cycle.state_at(0, rho, n), state);
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use crate::direction::Direction::*;
+use crate::error::ParseError;
+use std::str::FromStr;
+
+/// One of the four directions on a grid, with up being towards the top of the map where y
+/// decreases.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// All four directions, in the order up, down, left and right.
+    pub const ALL: [Direction; 4] = [Up, Down, Left, Right];
+
+    /// Returns the direction faced after turning a quarter turn anticlockwise.
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Up => Left,
+            Left => Down,
+            Down => Right,
+            Right => Up,
+        }
+    }
+
+    /// Returns the direction faced after turning a quarter turn clockwise.
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Up => Right,
+            Right => Down,
+            Down => Left,
+            Left => Up,
+        }
+    }
+
+    /// Returns the opposite direction.
+    pub fn reverse(self) -> Direction {
+        match self {
+            Up => Down,
+            Down => Up,
+            Left => Right,
+            Right => Left,
+        }
+    }
+
+    /// Returns whether the direction is up or down.
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Up | Down)
+    }
+
+    /// Returns the (x, y) offset of a single step in the direction.
+    pub fn vector(self) -> (i64, i64) {
+        match self {
+            Up => (0, -1),
+            Down => (0, 1),
+            Left => (-1, 0),
+            Right => (1, 0),
+        }
+    }
+
+    /// Returns the direction named by a character, which may be a letter for up, down, left or
+    /// right ('U', 'D', 'L' or 'R'), a compass point ('N', 'S', 'W' or 'E'), or an arrow ('^',
+    /// 'v', '<' or '>').
+    ///
+    /// # Arguments
+    ///
+    /// 'c' - The character naming the direction.
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c {
+            'U' | 'N' | '^' => Some(Up),
+            'D' | 'S' | 'v' => Some(Down),
+            'L' | 'W' | '<' => Some(Left),
+            'R' | 'E' | '>' => Some(Right),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+
+        match (chars.next().and_then(Direction::from_char), chars.next()) {
+            (Some(direction), None) => Ok(direction),
+            _ => Err(ParseError::new(s, s, "a direction")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::direction::*;
+
+    #[test]
+    fn turn_directions() {
+        assert_eq!(Up.turn_left(), Left);
+        assert_eq!(Left.turn_right(), Up);
+        assert_eq!(Right.reverse(), Left);
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            assert_eq!(direction.reverse().is_vertical(), direction.is_vertical());
+        }
+    }
+
+    #[test]
+    fn direction_vectors() {
+        assert_eq!(Up.vector(), (0, -1));
+        assert_eq!(Right.vector(), (1, 0));
+        for direction in Direction::ALL {
+            let (x, y) = direction.vector();
+            assert_eq!(direction.reverse().vector(), (-x, -y));
+        }
+    }
+
+    #[test]
+    fn parse_directions() {
+        assert_eq!("U".parse(), Ok(Up));
+        assert_eq!("S".parse(), Ok(Down));
+        assert_eq!("<".parse(), Ok(Left));
+        assert_eq!(Direction::from_char('>'), Some(Right));
+        assert_eq!(Direction::from_char('x'), None);
+        assert_eq!(
+            "UD".parse::<Direction>().unwrap_err().to_string(),
+            "line 1, column 1: expected a direction but found 'UD'"
+        );
+    }
+}
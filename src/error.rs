@@ -0,0 +1,396 @@
+use crate::parallel;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// An error encountered while solving a puzzle, usually because the input is malformed. The
+/// error records the day of the calendar and the line and column of the input it relates to,
+/// when known.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    pub day: Option<u32>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl SolveError {
+    /// Creates an error that isn't tied to any particular line of the input.
+    ///
+    /// # Arguments
+    ///
+    /// 'message' - A description of what went wrong.
+    pub fn new<S: Into<String>>(message: S) -> SolveError {
+        SolveError {
+            day: None,
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    /// Creates an error for the given line of the input.
+    ///
+    /// # Arguments
+    ///
+    /// 'line' - The line number, starting from 1.
+    ///
+    /// 'message' - A description of what went wrong.
+    pub fn at_line<S: Into<String>>(line: usize, message: S) -> SolveError {
+        SolveError {
+            day: None,
+            line: Some(line),
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    /// Returns the error with the day of the calendar filled in.
+    ///
+    /// # Arguments
+    ///
+    /// 'day' - The day of the calendar the error came from.
+    pub fn with_day(mut self, day: u32) -> SolveError {
+        self.day = Some(day);
+        self
+    }
+
+    /// Returns the error with its line number moved along by the given offset, for when a
+    /// section of the input was parsed on its own.
+    ///
+    /// # Arguments
+    ///
+    /// 'offset' - The number of lines that came before the section.
+    pub fn offset_line(mut self, offset: usize) -> SolveError {
+        self.line = self.line.map(|line| line + offset);
+        self
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let location = [
+            self.day.map(|day| format!("day {day}")),
+            self.line.map(|line| format!("line {line}")),
+            self.column.map(|column| format!("column {column}")),
+        ];
+        let location: Vec<_> = location.into_iter().flatten().collect();
+
+        if location.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", location.join(", "), self.message)
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> Self {
+        SolveError {
+            day: None,
+            line: Some(e.line),
+            column: Some(e.column),
+            message: e.describe(),
+        }
+    }
+}
+
+/// An error encountered while parsing a piece of the input. The error points at the offending
+/// text, giving its line and column, and describes what was expected in its place.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub found: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Creates an error for a piece of the text being parsed.
+    ///
+    /// # Arguments
+    ///
+    /// 'text' - The text being parsed.
+    ///
+    /// 'found' - The offending piece of the text, which must be a slice of 'text' for its
+    /// location to be known.
+    ///
+    /// 'expected' - A description of what should have been found instead.
+    pub fn new<S: Into<String>>(text: &str, found: &str, expected: S) -> ParseError {
+        let (line, column) = locate(text, found);
+
+        ParseError {
+            line,
+            column,
+            found: found.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Creates an error for something missing from the end of the text being parsed.
+    ///
+    /// # Arguments
+    ///
+    /// 'text' - The text being parsed.
+    ///
+    /// 'expected' - A description of what should have been found.
+    pub fn missing<S: Into<String>>(text: &str, expected: S) -> ParseError {
+        ParseError::new(text, &text[text.len()..], expected)
+    }
+
+    /// Returns the error with its location made relative to a larger piece of text, for when a
+    /// slice of that text was parsed on its own.
+    ///
+    /// # Arguments
+    ///
+    /// 'outer' - The larger piece of text.
+    ///
+    /// 'inner' - The slice of 'outer' that was parsed.
+    pub fn within(mut self, outer: &str, inner: &str) -> ParseError {
+        let (line, column) = locate(outer, inner);
+
+        if self.line == 1 {
+            self.column += column - 1;
+        }
+        self.line += line - 1;
+        self
+    }
+
+    /// Describes what was expected and what was found, without the location.
+    fn describe(&self) -> String {
+        if self.found.is_empty() {
+            format!("expected {} but found nothing", self.expected)
+        } else {
+            format!("expected {} but found '{}'", self.expected, self.found)
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line,
+            self.column,
+            self.describe()
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Returns the line and column, both starting from 1, at which a slice of the text starts. A
+/// string that isn't a slice of the text is placed at the start.
+///
+/// # Arguments
+///
+/// 'text' - The text containing the slice.
+///
+/// 'slice' - The slice to find.
+fn locate(text: &str, slice: &str) -> (usize, usize) {
+    let offset = (slice.as_ptr() as usize).wrapping_sub(text.as_ptr() as usize);
+    let before = text.get(..offset).unwrap_or("");
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+/// Parses a value from a slice of the text, reporting where the slice is if it isn't valid.
+///
+/// # Arguments
+///
+/// 'text' - The text being parsed.
+///
+/// 'value' - The slice of 'text' holding the value.
+///
+/// 'expected' - A description of the value.
+pub fn parse_value<T: FromStr>(text: &str, value: &str, expected: &str) -> Result<T, ParseError> {
+    value
+        .parse()
+        .map_err(|_| ParseError::new(text, value, expected))
+}
+
+/// Returns the slice of the text holding the single character that starts at the given index, or
+/// an empty slice at the end of the text.
+///
+/// # Arguments
+///
+/// 'text' - The text holding the character.
+///
+/// 'index' - The byte index at which the character starts.
+pub fn char_at(text: &str, index: usize) -> &str {
+    let len = text[index..].chars().next().map_or(0, char::len_utf8);
+    &text[index..index + len]
+}
+
+/// Splits a slice of the text in two around the first occurrence of a delimiter, reporting the
+/// end of the slice if the delimiter is missing.
+///
+/// # Arguments
+///
+/// 'text' - The text being parsed.
+///
+/// 'value' - The slice of 'text' to split.
+///
+/// 'delimiter' - The delimiter to split around.
+pub fn split_value<'a>(
+    text: &str,
+    value: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    value
+        .split_once(delimiter)
+        .ok_or_else(|| ParseError::new(text, &value[value.len()..], format!("'{delimiter}'")))
+}
+
+/// Removes a prefix from a slice of the text, reporting the start of the slice if the prefix is
+/// missing.
+///
+/// # Arguments
+///
+/// 'text' - The text being parsed.
+///
+/// 'value' - The slice of 'text' that should start with the prefix.
+///
+/// 'prefix' - The expected prefix.
+pub fn strip_value<'a>(text: &str, value: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    value.strip_prefix(prefix).ok_or_else(|| {
+        let end = value
+            .char_indices()
+            .nth(prefix.chars().count())
+            .map_or(value.len(), |(i, _)| i);
+
+        ParseError::new(text, &value[..end], format!("'{prefix}'"))
+    })
+}
+
+/// Parses each line of the input with the given function, reporting the line and column of the
+/// first error.
+///
+/// # Arguments
+///
+/// 'input' - The puzzle input.
+///
+/// 'parse' - The function used to parse each line.
+pub fn parse_lines<'a, T, F>(input: &'a str, mut parse: F) -> Result<Vec<T>, SolveError>
+where
+    F: FnMut(&'a str) -> Result<T, ParseError>,
+{
+    input
+        .lines()
+        .map(|line| parse(line).map_err(|e| e.within(input, line).into()))
+        .collect()
+}
+
+/// Parses each line of the input with the given function like 'parse_lines', but spreads the
+/// lines across threads when the `parallel` feature is enabled. The error reported is still the
+/// one from the first line that couldn't be parsed.
+///
+/// # Arguments
+///
+/// 'input' - The puzzle input.
+///
+/// 'parse' - The function used to parse each line.
+pub fn parse_lines_parallel<'a, T, F>(input: &'a str, parse: F) -> Result<Vec<T>, SolveError>
+where
+    T: Send,
+    F: Fn(&'a str) -> Result<T, ParseError> + Sync + Send,
+{
+    parallel::map(input.lines().collect(), |line| {
+        parse(line).map_err(|e| SolveError::from(e.within(input, line)))
+    })
+    .into_iter()
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::*;
+
+    #[test]
+    fn describe_errors() {
+        assert_eq!(SolveError::new("Empty input").to_string(), "Empty input");
+        assert_eq!(
+            SolveError::at_line(3, "Invalid card").to_string(),
+            "line 3: Invalid card"
+        );
+        assert_eq!(
+            SolveError::new("Empty input").with_day(5).to_string(),
+            "day 5: Empty input"
+        );
+        assert_eq!(
+            SolveError::at_line(3, "Invalid card")
+                .offset_line(2)
+                .with_day(4)
+                .to_string(),
+            "day 4, line 5: Invalid card"
+        );
+    }
+
+    #[test]
+    fn locate_parse_errors() {
+        let text = "ab\ncdé fg";
+        assert_eq!(
+            ParseError::new(text, &text[8..], "a letter").to_string(),
+            "line 2, column 5: expected a letter but found 'fg'"
+        );
+        assert_eq!(
+            ParseError::missing(text, "a letter").to_string(),
+            "line 2, column 7: expected a letter but found nothing"
+        );
+        assert_eq!(
+            ParseError::new("fg", "fg", "a digit")
+                .within(text, &text[8..])
+                .to_string(),
+            "line 2, column 5: expected a digit but found 'fg'"
+        );
+        assert_eq!(
+            SolveError::from(ParseError::new(text, "xy", "a digit"))
+                .with_day(3)
+                .to_string(),
+            "day 3, line 1, column 1: expected a digit but found 'xy'"
+        );
+    }
+
+    #[test]
+    fn parse_pieces_of_text() {
+        let text = "Game 12: 3 red";
+        assert_eq!(parse_value::<u32>(text, &text[5..7], "a number"), Ok(12));
+        assert_eq!(
+            parse_value::<u32>(text, &text[9..14], "a number")
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 10: expected a number but found '3 red'"
+        );
+        assert_eq!(char_at(text, 5), "1");
+        assert_eq!(char_at(text, 14), "");
+        assert_eq!(split_value(text, text, ": "), Ok(("Game 12", "3 red")));
+        assert_eq!(
+            split_value(text, text, ";").unwrap_err().to_string(),
+            "line 1, column 15: expected ';' but found nothing"
+        );
+        assert_eq!(strip_value(text, text, "Game "), Ok("12: 3 red"));
+        assert_eq!(
+            strip_value(text, &text[9..], "red")
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 10: expected 'red' but found '3 r'"
+        );
+    }
+
+    #[test]
+    fn parse_each_line() {
+        let parse = |line| parse_value::<u32>(line, line, "a number");
+        assert_eq!(parse_lines("1\n2\n3", parse), Ok(vec![1, 2, 3]));
+        assert_eq!(
+            parse_lines("1\n2x\n3", parse).unwrap_err().to_string(),
+            "line 2, column 1: expected a number but found '2x'"
+        );
+    }
+}
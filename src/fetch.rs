@@ -0,0 +1,304 @@
+use crate::runner::input_file_name;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The site the inputs are fetched from, unless another is given.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The shortest time allowed between two requests, unless another is given.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The user agent sent with every request, so the site knows who is asking.
+pub const USER_AGENT: &str = concat!(
+    "advent_of_code_2023/",
+    env!("CARGO_PKG_VERSION"),
+    " (puzzle input fetcher)"
+);
+
+/// The file in the input directory recording when the last request to the site was made.
+pub const STAMP_FILE: &str = ".last-request";
+
+/// The settings for downloading puzzle inputs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FetchConfig {
+    /// The address of the site, without a trailing slash.
+    pub base_url: String,
+    /// The session token identifying whose inputs to download. It is only needed when an input
+    /// isn't already cached.
+    pub session: Option<String>,
+    /// The directory the inputs are cached in, with a directory inside it for each year.
+    pub input_dir: PathBuf,
+    /// The shortest time allowed between two requests.
+    pub interval: Duration,
+}
+
+/// What fetching an input did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was downloaded and saved to the given file.
+    Downloaded(PathBuf),
+    /// The input was already in the given file, so nothing was downloaded.
+    Cached(PathBuf),
+}
+
+/// Spaces out requests so that there is at least a given interval between them. The time of the
+/// last request is kept in a file, so the limit holds across separate runs as well.
+pub struct RateLimiter {
+    stamp: PathBuf,
+    interval: Duration,
+}
+
+impl RateLimiter {
+    /// Creates a rate limiter that records the time of the last request in the given file.
+    ///
+    /// # Arguments
+    ///
+    /// 'stamp' - The file recording the time of the last request.
+    ///
+    /// 'interval' - The shortest time allowed between two requests.
+    pub fn new<P: Into<PathBuf>>(stamp: P, interval: Duration) -> RateLimiter {
+        RateLimiter {
+            stamp: stamp.into(),
+            interval,
+        }
+    }
+
+    /// Waits until a request is allowed, then records that one is being made.
+    pub fn wait(&self) -> Result<(), String> {
+        let last = fs::read_to_string(&self.stamp)
+            .ok()
+            .and_then(|text| text.trim().parse().ok())
+            .map(Duration::from_nanos);
+
+        if let Some(next) = last.map(|last| last + self.interval) {
+            if let Some(remaining) = next.checked_sub(since_epoch()) {
+                thread::sleep(remaining);
+            }
+        }
+
+        fs::write(&self.stamp, since_epoch().as_nanos().to_string())
+            .map_err(|e| format!("Unable to write {}: {e}", self.stamp.display()))
+    }
+}
+
+/// Returns the time elapsed since the Unix epoch.
+pub fn since_epoch() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+/// Returns the URL of the input for the given day.
+///
+/// # Arguments
+///
+/// 'base_url' - The address of the site.
+///
+/// 'year' - The year of the calendar.
+///
+/// 'day' - The day of the calendar.
+pub fn input_url(base_url: &str, year: u32, day: u32) -> String {
+    format!("{}/{year}/day/{day}/input", base_url.trim_end_matches('/'))
+}
+
+/// Downloads the input for a day into the input directory, unless it is already there. Requests
+/// are spaced out by the configured interval.
+///
+/// # Arguments
+///
+/// 'year' - The year of the calendar.
+///
+/// 'day' - The day of the calendar.
+///
+/// 'config' - The settings for downloading inputs.
+pub fn fetch_input(year: u32, day: u32, config: &FetchConfig) -> Result<Fetched, String> {
+    let year_dir = config.input_dir.join(year.to_string());
+    let path = year_dir.join(input_file_name(day));
+    if path.exists() {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let session = config
+        .session
+        .as_deref()
+        .ok_or("A session token is needed to download inputs")?;
+
+    fs::create_dir_all(&year_dir)
+        .map_err(|e| format!("Unable to create {}: {e}", year_dir.display()))?;
+    RateLimiter::new(config.input_dir.join(STAMP_FILE), config.interval).wait()?;
+
+    let input = download(&input_url(&config.base_url, year, day), session)?;
+    save(&path, &input)?;
+
+    Ok(Fetched::Downloaded(path))
+}
+
+/// Returns the body of the page at the given URL, requested with the session token.
+///
+/// # Arguments
+///
+/// 'url' - The address of the page.
+///
+/// 'session' - The session token.
+fn download(url: &str, session: &str) -> Result<String, String> {
+    let response = ureq::get(url)
+        .set("Cookie", &format!("session={session}"))
+        .set("User-Agent", USER_AGENT)
+        .timeout(Duration::from_secs(30))
+        .call();
+
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|e| format!("Unable to read the response from {url}: {e}")),
+        Err(ureq::Error::Status(status, response)) => {
+            let reason = format!("{url} returned {status} {}", response.status_text());
+            // The site explains what went wrong in a line of plain text, which is worth passing on
+            let body = response.into_string().unwrap_or_default();
+            match body.lines().next().map(str::trim) {
+                Some(line) if !line.is_empty() && !line.starts_with('<') => {
+                    Err(format!("{reason}: {line}"))
+                }
+                _ => Err(reason),
+            }
+        }
+        Err(e) => Err(format!("Unable to reach {url}: {e}")),
+    }
+}
+
+/// Writes the input to its file, going through a temporary file so that an interrupted write
+/// can't leave a partial input behind to be mistaken for a cached one.
+///
+/// # Arguments
+///
+/// 'path' - The file to write.
+///
+/// 'input' - The puzzle input.
+fn save(path: &Path, input: &str) -> Result<(), String> {
+    let partial = path.with_extension("part");
+
+    fs::write(&partial, input)
+        .and_then(|_| fs::rename(&partial, path))
+        .map_err(|e| format!("Unable to write {}: {e}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fetch::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::time::Instant;
+
+    /// Starts a stand-in for the site that answers a single request with the given status and
+    /// body. Returns its address and a handle giving the request it received.
+    fn serve(status: &'static str, body: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let request: Vec<String> = BufReader::new(&stream)
+                .lines()
+                .map(Result::unwrap)
+                .take_while(|line| !line.is_empty())
+                .collect();
+
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            request.join("\n")
+        });
+
+        (url, handle)
+    }
+
+    /// Returns the settings for fetching from the given site into a fresh directory.
+    fn config(base_url: &str, name: &str) -> FetchConfig {
+        let input_dir = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&input_dir);
+
+        FetchConfig {
+            base_url: base_url.to_string(),
+            session: Some(String::from("abc123")),
+            input_dir,
+            interval: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn download_and_cache_inputs() {
+        let (url, server) = serve("200 OK", "1abc2\npqr3stu8vwx\n");
+        let config = config(&url, "fetch");
+        let path = config.input_dir.join("2023").join("day01.txt");
+
+        assert_eq!(
+            fetch_input(2023, 1, &config),
+            Ok(Fetched::Downloaded(path.clone()))
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1abc2\npqr3stu8vwx\n");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2023/day/1/input HTTP/1.1"));
+        assert!(request.contains("\nCookie: session=abc123"));
+
+        // The stand-in has stopped listening, so this only works because nothing is requested
+        assert_eq!(fetch_input(2023, 1, &config), Ok(Fetched::Cached(path)));
+        fs::remove_dir_all(config.input_dir).unwrap();
+    }
+
+    #[test]
+    fn report_failed_downloads() {
+        let (url, server) = serve("400 Bad Request", "Please log in to get your puzzle input.");
+        let config = config(&url, "fetch-error");
+
+        assert_eq!(
+            fetch_input(2022, 5, &config),
+            Err(format!(
+                "{url}/2022/day/5/input returned 400 Bad Request: Please log in to get your puzzle input."
+            ))
+        );
+        assert!(!config.input_dir.join("2022").join("day05.txt").exists());
+        server.join().unwrap();
+
+        let config = FetchConfig {
+            session: None,
+            ..config
+        };
+        assert_eq!(
+            fetch_input(2022, 5, &config),
+            Err(String::from("A session token is needed to download inputs"))
+        );
+        fs::remove_dir_all(config.input_dir).unwrap();
+    }
+
+    #[test]
+    fn space_out_requests() {
+        let config = config("", "rate-limit");
+        fs::create_dir_all(&config.input_dir).unwrap();
+        let limiter = RateLimiter::new(
+            config.input_dir.join(STAMP_FILE),
+            Duration::from_millis(200),
+        );
+
+        let start = Instant::now();
+        limiter.wait().unwrap();
+        limiter.wait().unwrap();
+        limiter.wait().unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(400));
+        fs::remove_dir_all(config.input_dir).unwrap();
+    }
+
+    #[test]
+    fn build_input_urls() {
+        assert_eq!(
+            input_url("https://adventofcode.com/", 2023, 7),
+            "https://adventofcode.com/2023/day/7/input"
+        );
+    }
+}
@@ -0,0 +1,1150 @@
+use crate::direction::Direction::{self, *};
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::collections::{HashMap, HashSet};
+use std::ops::RangeInclusive;
+
+/// A function that generates a random puzzle input of a given size.
+type Generator = fn(&mut StdRng, usize) -> String;
+
+/// The year of the puzzles that the generators are for.
+const YEAR: u32 = 2023;
+
+/// The generator for each day, in calendar order, along with the size that gives an input about
+/// as large as a real puzzle input.
+const GENERATORS: [(Generator, usize); 25] = [
+    (day01, 1000),
+    (day02, 100),
+    (day03, 140),
+    (day04, 200),
+    (day05, 30),
+    (day06, 4),
+    (day07, 1000),
+    (day08, 6),
+    (day09, 200),
+    (day10, 140),
+    (day11, 140),
+    (day12, 1000),
+    (day13, 100),
+    (day14, 100),
+    (day15, 4000),
+    (day16, 110),
+    (day17, 141),
+    (day18, 40),
+    (day19, 500),
+    (day20, 4),
+    (day21, 131),
+    (day22, 1200),
+    (day23, 6),
+    (day24, 300),
+    (day25, 1500),
+];
+
+/// The days whose generator can only make an input of its typical size, because the solution
+/// relies on the size.
+const FIXED_SIZE_DAYS: [u32; 1] = [21];
+
+/// Generates a random, valid input for a day's puzzle, or returns a message saying why it can't:
+/// there is no generator for the day, or the day can't be generated at the given size. The same
+/// day, size and seed always give the same input. What the size measures depends on the day,
+/// such as the number of lines or the width of a map, and is described on each day's generator.
+///
+/// # Arguments
+///
+/// 'year' - The year of the calendar.
+///
+/// 'day' - The day of the calendar to generate an input for.
+///
+/// 'size' - How large an input to generate.
+///
+/// 'seed' - The seed for the random number generator.
+pub fn generate(year: u32, day: u32, size: usize, seed: u64) -> Result<String, String> {
+    let (generator, typical) =
+        find(year, day).ok_or(format!("No generator for day {day} of {year}"))?;
+    if FIXED_SIZE_DAYS.contains(&day) && size != *typical {
+        return Err(format!(
+            "Day {day} of {year} can only be generated with size {typical}"
+        ));
+    }
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    Ok(generator(&mut rng, size))
+}
+
+/// Returns the size that gives an input about as large as a real puzzle input for a day, or None
+/// if there is no generator for the day.
+///
+/// # Arguments
+///
+/// 'year' - The year of the calendar.
+///
+/// 'day' - The day of the calendar.
+pub fn typical_size(year: u32, day: u32) -> Option<usize> {
+    let (_, size) = find(year, day)?;
+    Some(*size)
+}
+
+/// Returns the generator for a day along with its typical size, or None if there isn't one.
+///
+/// # Arguments
+///
+/// 'year' - The year of the calendar.
+///
+/// 'day' - The day of the calendar.
+fn find(year: u32, day: u32) -> Option<&'static (Generator, usize)> {
+    if year != YEAR {
+        return None;
+    }
+    GENERATORS.get((day as usize).checked_sub(1)?)
+}
+
+/// Joins the lines of an input, ending it with a newline as the real inputs are.
+///
+/// # Arguments
+///
+/// 'lines' - The lines of the input.
+fn join_lines(lines: impl IntoIterator<Item = String>) -> String {
+    lines.into_iter().map(|line| line + "\n").collect()
+}
+
+/// Joins the rows of a map into an input.
+///
+/// # Arguments
+///
+/// 'rows' - The characters of each row of the map.
+fn join_rows(rows: &[Vec<char>]) -> String {
+    join_lines(rows.iter().map(|row| row.iter().collect()))
+}
+
+/// Returns a map where each character is chosen by a function.
+///
+/// # Arguments
+///
+/// 'rng' - The random number generator.
+///
+/// 'width' - The width of the map.
+///
+/// 'height' - The height of the map.
+///
+/// 'cell' - The function that picks each character.
+fn random_rows<F>(rng: &mut StdRng, width: usize, height: usize, mut cell: F) -> Vec<Vec<char>>
+where
+    F: FnMut(&mut StdRng) -> char,
+{
+    (0..height)
+        .map(|_| (0..width).map(|_| cell(rng)).collect())
+        .collect()
+}
+
+/// Returns distinct random names made of lowercase letters, none of which are reserved.
+///
+/// # Arguments
+///
+/// 'rng' - The random number generator.
+///
+/// 'count' - The number of names to make.
+///
+/// 'length' - The range of lengths for the names.
+///
+/// 'reserved' - The names that mustn't be used.
+fn random_names(
+    rng: &mut StdRng,
+    count: usize,
+    length: RangeInclusive<usize>,
+    reserved: &[&str],
+) -> Vec<String> {
+    let mut used: HashSet<String> = reserved.iter().map(|r| String::from(*r)).collect();
+    let mut names = vec![];
+
+    while names.len() < count {
+        let name: String = (0..rng.gen_range(length.clone()))
+            .map(|_| rng.gen_range('a'..='z'))
+            .collect();
+
+        if used.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+
+    names
+}
+
+/// Returns a random closed loop of positions on a map, where each position is next to the one
+/// before it and no position is visited twice. The loop is grown on a map of half the size from
+/// a small square, by repeatedly pushing one of its sides outwards, and then scaled up. Growing
+/// the loop like this never leaves any positions inside it, which scaling it up fixes.
+///
+/// # Arguments
+///
+/// 'rng' - The random number generator.
+///
+/// 'width' - The width of the map, which must be at least 4.
+///
+/// 'height' - The height of the map, which must be at least 4.
+fn random_loop(rng: &mut StdRng, width: usize, height: usize) -> Vec<(usize, usize)> {
+    let (width, height) = (width / 2, height / 2);
+    let (x, y) = (rng.gen_range(0..width - 1), rng.gen_range(0..height - 1));
+    let mut path = vec![(x, y), (x + 1, y), (x + 1, y + 1), (x, y + 1)];
+    let mut visited: HashSet<_> = path.iter().copied().collect();
+    let bounds = (0..width, 0..height);
+    let offset = |(x, y): (usize, usize), (dx, dy): (i64, i64)| {
+        let pos = (
+            x.checked_add_signed(dx as isize)?,
+            y.checked_add_signed(dy as isize)?,
+        );
+        (bounds.0.contains(&pos.0) && bounds.1.contains(&pos.1)).then_some(pos)
+    };
+
+    for _ in 0..width * height * 8 {
+        let i = rng.gen_range(0..path.len());
+        let (a, b) = (path[i], path[(i + 1) % path.len()]);
+
+        let (dx, dy) = (b.0 as i64 - a.0 as i64, b.1 as i64 - a.1 as i64);
+        let side = if rng.gen_bool(0.5) {
+            (dy, -dx)
+        } else {
+            (-dy, dx)
+        };
+
+        if let Some((a, b)) = offset(a, side).zip(offset(b, side)) {
+            if !visited.contains(&a) && !visited.contains(&b) {
+                path.splice(i + 1..i + 1, [a, b]);
+                visited.extend([a, b]);
+            }
+        }
+    }
+
+    (0..path.len())
+        .flat_map(|i| {
+            let (a, b) = (path[i], path[(i + 1) % path.len()]);
+            [(a.0 * 2, a.1 * 2), (a.0 + b.0, a.1 + b.1)]
+        })
+        .collect()
+}
+
+/// Returns the direction of a step from one position to the next.
+///
+/// # Arguments
+///
+/// 'from' - The position before the step.
+///
+/// 'to' - The position after the step.
+fn step_direction(from: (usize, usize), to: (usize, usize)) -> Direction {
+    let vector = (to.0 as i64 - from.0 as i64, to.1 as i64 - from.1 as i64);
+    Direction::ALL
+        .into_iter()
+        .find(|d| d.vector() == vector)
+        .expect("Positions in a loop must be next to each other")
+}
+
+/// Generates lines of calibration text, each mixing letters, digits and the names of digits.
+/// The size is the number of lines.
+fn day01(rng: &mut StdRng, size: usize) -> String {
+    const NAMES: [&str; 9] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+
+    join_lines((0..size).map(|_| {
+        let mut line = String::new();
+        for _ in 0..rng.gen_range(1..6) {
+            match rng.gen_range(0..3) {
+                0 => line += NAMES.choose(rng).unwrap(),
+                1 => line.push(rng.gen_range('1'..='9')),
+                _ => line.extend((0..rng.gen_range(1..5)).map(|_| rng.gen_range('a'..='z'))),
+            }
+        }
+
+        // Every line needs at least one digit character for the first part
+        line.insert(rng.gen_range(0..=line.len()), rng.gen_range('1'..='9'));
+        line
+    }))
+}
+
+/// Generates games of cubes drawn from a bag. The size is the number of games.
+fn day02(rng: &mut StdRng, size: usize) -> String {
+    join_lines((1..=size).map(|id| {
+        let draws = (0..rng.gen_range(1..=6))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                colors.shuffle(rng);
+                let count = rng.gen_range(1..=3);
+
+                colors[..count]
+                    .iter()
+                    .map(|color| format!("{} {color}", rng.gen_range(1..=20)))
+                    .join(", ")
+            })
+            .join("; ");
+
+        format!("Game {id}: {draws}")
+    }))
+}
+
+/// Generates an engine schematic of numbers and symbols. The size is the width and height of the
+/// schematic.
+fn day03(rng: &mut StdRng, size: usize) -> String {
+    const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '@', '%', '=', '&', '-'];
+
+    let mut rows = vec![vec!['.'; size]; size];
+
+    for row in &mut rows {
+        let mut x = 0;
+        while x < size {
+            let number = rng.gen_range(1..1000).to_string();
+
+            if rng.gen_bool(0.15) && x + number.len() <= size {
+                row.splice(x..x + number.len(), number.chars());
+                // Leave a gap so the next number isn't joined on to this one
+                x += number.len() + 1;
+            } else {
+                if rng.gen_bool(0.08) {
+                    row[x] = *SYMBOLS.choose(rng).unwrap();
+                }
+                x += 1;
+            }
+        }
+    }
+
+    join_rows(&rows)
+}
+
+/// Generates scratchcards. The size is the number of cards. The winning numbers on the last cards
+/// never win copies of cards past the end of the table, and the total number of copies is kept
+/// small enough to count one at a time.
+fn day04(rng: &mut StdRng, size: usize) -> String {
+    const MAX_COPIES: u64 = 1_000_000;
+
+    let mut copies = vec![1u64; size];
+    let mut total = size as u64;
+    let format = |numbers: &[u32]| numbers.iter().map(|n| format!("{n:>2}")).join(" ");
+
+    join_lines((0..size).map(|i| {
+        let mut matches = rng.gen_range(0..=10).min(size - i - 1);
+        if rng.gen_bool(0.4) || total + copies[i] * matches as u64 > MAX_COPIES {
+            matches = 0;
+        }
+
+        for j in i + 1..=i + matches {
+            copies[j] += copies[i];
+        }
+        total += copies[i] * matches as u64;
+
+        let mut numbers: Vec<u32> = (1..100).collect();
+        numbers.shuffle(rng);
+        let winning = &numbers[..10];
+        let mut have = [&winning[..matches], &numbers[10..35 - matches]].concat();
+        have.shuffle(rng);
+
+        format!("Card {:>3}: {} | {}", i + 1, format(winning), format(&have))
+    }))
+}
+
+/// Generates an almanac, where each map moves blocks of values to new places without any of its
+/// blocks overlapping. The size is the number of blocks in each map.
+fn day05(rng: &mut StdRng, size: usize) -> String {
+    const MAPS: [&str; 7] = [
+        "seed-to-soil",
+        "soil-to-fertilizer",
+        "fertilizer-to-water",
+        "water-to-light",
+        "light-to-temperature",
+        "temperature-to-humidity",
+        "humidity-to-location",
+    ];
+    const LIMIT: i64 = 1 << 32;
+
+    let seeds = (0..size.clamp(1, 10))
+        .map(|_| {
+            let length = rng.gen_range(1..LIMIT / 20);
+            format!("{} {length}", rng.gen_range(0..LIMIT - length))
+        })
+        .join(" ");
+    let mut sections = vec![format!("seeds: {seeds}")];
+
+    for name in MAPS {
+        // Cut the values into blocks, then lay the same blocks out again in a shuffled order to
+        // find where each one moves to
+        let mut cuts: Vec<_> = (0..size.max(1) - 1)
+            .map(|_| rng.gen_range(1..LIMIT))
+            .chain([0, LIMIT])
+            .collect();
+        cuts.sort();
+        cuts.dedup();
+
+        let mut blocks: Vec<_> = cuts.windows(2).map(|w| (w[0], w[1] - w[0])).collect();
+        let mut destinations = blocks.clone();
+        destinations.shuffle(rng);
+
+        let mut start = 0;
+        let mut moved = HashMap::new();
+        for (source, length) in destinations {
+            moved.insert(source, start);
+            start += length;
+        }
+
+        blocks.shuffle(rng);
+        let lines = blocks
+            .iter()
+            .filter(|_| rng.gen_bool(0.9))
+            .map(|(source, length)| format!("{} {source} {length}", moved[source]));
+
+        sections.push([format!("{name} map:")].into_iter().chain(lines).join("\n"));
+    }
+
+    sections.join("\n\n") + "\n"
+}
+
+/// Generates the times and record distances of boat races, where every record can be beaten.
+/// The size is the number of races, which is at most 4 so that the numbers joined together for
+/// the second part don't overflow.
+fn day06(rng: &mut StdRng, size: usize) -> String {
+    let (times, distances): (Vec<_>, Vec<_>) = (0..size.clamp(1, 4))
+        .map(|_| {
+            let time = rng.gen_range(7..100);
+            let hold = rng.gen_range(1..time);
+            (time, rng.gen_range(0..hold * (time - hold)))
+        })
+        .unzip();
+
+    let format = |values: Vec<i64>| values.iter().map(|v| format!("{v:>5}")).join("");
+
+    format!(
+        "Time:    {}\nDistance:{}\n",
+        format(times),
+        format(distances)
+    )
+}
+
+/// Generates hands of camel cards with their bids. The size is the number of hands.
+fn day07(rng: &mut StdRng, size: usize) -> String {
+    const CARDS: &[u8] = b"23456789TJQKA";
+
+    join_lines((0..size).map(|_| {
+        let hand: String = (0..5)
+            .map(|_| *CARDS.choose(rng).unwrap() as char)
+            .collect();
+        format!("{hand} {}", rng.gen_range(1..=1000))
+    }))
+}
+
+/// Generates the directions and network for the haunted wasteland. Each ghost walks a loop whose
+/// length is the length of the directions times a different prime, and reaches its exit at the
+/// end of each loop, which is what finding the answer from the lowest common multiple relies on.
+/// The size is the number of ghosts, at most 14, and the first ghost starts at 'AAA' and ends at
+/// 'ZZZ'.
+fn day08(rng: &mut StdRng, size: usize) -> String {
+    const PRIMES: [usize; 14] = [3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47];
+
+    let directions: Vec<_> = (0..rng.gen_range(5..=15))
+        .map(|_| if rng.gen_bool(0.5) { 'L' } else { 'R' })
+        .collect();
+    let mut primes = PRIMES;
+    primes.shuffle(rng);
+
+    let mut used = HashSet::new();
+    let mut name = |rng: &mut StdRng, last: Option<char>| loop {
+        let last = last.unwrap_or_else(|| rng.gen_range('B'..='Y'));
+        let name = format!(
+            "{}{}{last}",
+            rng.gen_range('A'..='Z'),
+            rng.gen_range('A'..='Z')
+        );
+        if name != "AAA" && name != "ZZZ" && used.insert(name.clone()) {
+            return name;
+        }
+    };
+
+    let mut lines = vec![];
+
+    for (ghost, prime) in primes.iter().take(size.clamp(1, PRIMES.len())).enumerate() {
+        let length = directions.len() * prime;
+
+        let mut nodes = vec![match ghost {
+            0 => String::from("AAA"),
+            _ => name(rng, Some('A')),
+        }];
+        nodes.extend((1..length).map(|_| name(rng, None)));
+        nodes.push(match ghost {
+            0 => String::from("ZZZ"),
+            _ => name(rng, Some('Z')),
+        });
+
+        // The branch the directions take leads on around the loop, and the other branch leads
+        // somewhere random in it
+        let branches: Vec<_> = (0..length)
+            .map(|step| {
+                let next = nodes[step + 1].clone();
+                let other = nodes[rng.gen_range(1..length)].clone();
+                match directions[step % directions.len()] {
+                    'L' => (next, other),
+                    _ => (other, next),
+                }
+            })
+            .collect();
+
+        for (node, (left, right)) in nodes.iter().zip(branches.iter().chain([&branches[0]])) {
+            lines.push(format!("{node} = ({left}, {right})"));
+        }
+    }
+
+    lines.shuffle(rng);
+
+    format!(
+        "{}\n\n{}",
+        directions.iter().collect::<String>(),
+        join_lines(lines)
+    )
+}
+
+/// Generates sequences of readings that each come from a polynomial, so that taking differences
+/// always ends in zeroes. The size is the number of sequences.
+fn day09(rng: &mut StdRng, size: usize) -> String {
+    join_lines((0..size).map(|_| {
+        let coefficients: Vec<i64> = (0..rng.gen_range(1..=6))
+            .map(|_| rng.gen_range(-5..=5))
+            .collect();
+
+        (0..21i64)
+            .map(|x| coefficients.iter().rev().fold(0, |value, c| value * x + c))
+            .join(" ")
+    }))
+}
+
+/// Generates a map with a single loop of pipe through the start, surrounded by scraps of pipe
+/// that aren't connected to it. The size is the width and height of the map.
+fn day10(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(4);
+    let mut rows = random_rows(rng, size, size, |rng| {
+        *b"|-LJ7F....".choose(rng).unwrap() as char
+    });
+
+    let path = random_loop(rng, size, size);
+    for (i, &(x, y)) in path.iter().enumerate() {
+        let before = step_direction((x, y), path[(i + path.len() - 1) % path.len()]);
+        let after = step_direction((x, y), path[(i + 1) % path.len()]);
+
+        rows[y][x] = match (before, after) {
+            (Up, Down) | (Down, Up) => '|',
+            (Left, Right) | (Right, Left) => '-',
+            (Up, Right) | (Right, Up) => 'L',
+            (Up, Left) | (Left, Up) => 'J',
+            (Down, Left) | (Left, Down) => '7',
+            _ => 'F',
+        };
+    }
+
+    // Clear the ground around the start, so that no scraps of pipe look like they lead into it
+    let (x, y) = *path.choose(rng).unwrap();
+    let on_loop: HashSet<_> = path.iter().collect();
+    for (nx, ny) in [
+        (x + 1, y),
+        (x.wrapping_sub(1), y),
+        (x, y + 1),
+        (x, y.wrapping_sub(1)),
+    ] {
+        if nx < size && ny < size && !on_loop.contains(&(nx, ny)) {
+            rows[ny][nx] = '.';
+        }
+    }
+    rows[y][x] = 'S';
+
+    join_rows(&rows)
+}
+
+/// Generates an image of galaxies. The size is the width and height of the image.
+fn day11(rng: &mut StdRng, size: usize) -> String {
+    join_rows(&random_rows(rng, size, size, |rng| {
+        if rng.gen_bool(0.05) {
+            '#'
+        } else {
+            '.'
+        }
+    }))
+}
+
+/// Generates rows of springs, some of which are unknown, with the sizes of the groups of damaged
+/// springs taken from a complete row before it was hidden. The size is the number of rows.
+fn day12(rng: &mut StdRng, size: usize) -> String {
+    join_lines((0..size).map(|_| {
+        let mut springs: Vec<_> = (0..rng.gen_range(5..=20))
+            .map(|_| if rng.gen_bool(0.5) { '#' } else { '.' })
+            .collect();
+        let last = springs.len() - 1;
+        springs[rng.gen_range(0..=last)] = '#';
+
+        let groups = springs
+            .split(|c| *c == '.')
+            .filter(|group| !group.is_empty())
+            .map(|group| group.len())
+            .join(",");
+        let row: String = springs
+            .iter()
+            .map(|c| if rng.gen_bool(0.4) { '?' } else { *c })
+            .collect();
+
+        format!("{row} {groups}")
+    }))
+}
+
+/// Generates a pattern with an exact reflection, for the first part, and a reflection with a
+/// single smudge, for the second.
+///
+/// # Arguments
+///
+/// 'rng' - The random number generator.
+fn mirror_pattern(rng: &mut StdRng) -> Vec<Vec<char>> {
+    let (width, height) = (rng.gen_range(5..=17), rng.gen_range(5..=17));
+    let mut rows = random_rows(rng, width, height, |rng| {
+        if rng.gen_bool(0.5) {
+            '#'
+        } else {
+            '.'
+        }
+    });
+
+    // Reflect the left of the pattern between two columns, leaving some columns on the right that
+    // aren't part of the reflection...
+    let column = rng.gen_range(1..=(width - 1) / 2);
+    for row in &mut rows {
+        for x in 0..column {
+            row[2 * column - 1 - x] = row[x];
+        }
+    }
+
+    // ...then reflect the pattern between two rows, which keeps the first reflection...
+    let row = rng.gen_range(1..height);
+    for y in row - row.min(height - row)..row {
+        rows[2 * row - 1 - y] = rows[y].clone();
+    }
+
+    // ...and smudge one of the reflected rows in a column the first reflection doesn't reach
+    let x = rng.gen_range(2 * column..width);
+    let y = rng.gen_range((2 * row).saturating_sub(height)..row);
+    rows[y][x] = if rows[y][x] == '#' { '.' } else { '#' };
+
+    if rng.gen_bool(0.5) {
+        (0..width)
+            .map(|x| rows.iter().map(|row| row[x]).collect())
+            .collect()
+    } else {
+        rows
+    }
+}
+
+/// Generates patterns of ash and rocks, each with an exact reflection and a reflection with a
+/// single smudge. The size is the number of patterns.
+fn day13(rng: &mut StdRng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| join_rows(&mirror_pattern(rng)))
+        .join("\n")
+}
+
+/// Generates a platform of rounded and cube-shaped rocks. The size is the width and height of
+/// the platform.
+fn day14(rng: &mut StdRng, size: usize) -> String {
+    join_rows(&random_rows(rng, size, size, |rng| {
+        *b"OO#......".choose(rng).unwrap() as char
+    }))
+}
+
+/// Generates an initialization sequence of steps that add and remove lenses. The size is the
+/// number of steps.
+fn day15(rng: &mut StdRng, size: usize) -> String {
+    let labels = random_names(rng, (size / 4).max(1), 2..=6, &[]);
+
+    let steps = (0..size.max(1))
+        .map(|_| {
+            let label = labels.choose(rng).unwrap();
+            if rng.gen_bool(0.3) {
+                format!("{label}-")
+            } else {
+                format!("{label}={}", rng.gen_range(1..=9))
+            }
+        })
+        .join(",");
+
+    steps + "\n"
+}
+
+/// Generates a contraption of mirrors and splitters. The size is the width and height of the
+/// contraption.
+fn day16(rng: &mut StdRng, size: usize) -> String {
+    join_rows(&random_rows(rng, size, size, |rng| {
+        if rng.gen_bool(0.15) {
+            *b"/\\|-".choose(rng).unwrap() as char
+        } else {
+            '.'
+        }
+    }))
+}
+
+/// Generates a map of heat loss for each city block. The size is the width and height of the
+/// map, which is at least 5 so that the ultra crucible can reach the end.
+fn day17(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(5);
+    join_rows(&random_rows(rng, size, size, |rng| {
+        rng.gen_range('1'..='9')
+    }))
+}
+
+/// Generates a dig plan around a random loop. The color codes dig the same loop stretched by a
+/// large amount across and a different large amount down, so both parts dig a closed loop that
+/// doesn't cross itself. The size is the width and height of the grid the loop is drawn on.
+fn day18(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(4);
+    let mut path = random_loop(rng, size, size);
+
+    // Start at a corner, so that the first and last instructions go in different directions
+    let corner = (0..path.len())
+        .find(|&i| {
+            let before = path[(i + path.len() - 1) % path.len()];
+            let after = path[(i + 1) % path.len()];
+            before.0 != after.0 && before.1 != after.1
+        })
+        .unwrap();
+    path.rotate_left(corner);
+
+    let directions = (0..path.len()).map(|i| step_direction(path[i], path[(i + 1) % path.len()]));
+    let runs = directions.dedup_with_count();
+
+    let limit = 0xfffff / size;
+    let scale = (
+        rng.gen_range(1000.min(limit)..=10000.min(limit)),
+        rng.gen_range(1000.min(limit)..=10000.min(limit)),
+    );
+
+    join_lines(runs.map(|(length, direction)| {
+        let (letter, code) = match direction {
+            Right => ('R', 0),
+            Down => ('D', 1),
+            Left => ('L', 2),
+            Up => ('U', 3),
+        };
+        let large = length
+            * if direction.is_vertical() {
+                scale.1
+            } else {
+                scale.0
+            };
+
+        format!("{letter} {length} (#{large:05x}{code})")
+    }))
+}
+
+/// Generates workflows that branch out from 'in' without ever looping back, along with the
+/// ratings of parts to sort. The size is the number of workflows and the number of parts.
+fn day19(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    let mut names = vec![String::from("in")];
+    names.extend(random_names(rng, size - 1, 2..=3, &["in"]));
+
+    // Each workflow is sent to from one that comes before it, so every workflow can be reached
+    let mut results: Vec<Vec<&str>> = vec![vec![]; size];
+    for (i, name) in names.iter().enumerate().skip(1) {
+        results[rng.gen_range(0..i)].push(name);
+    }
+
+    let mut workflows: Vec<_> = names
+        .iter()
+        .zip(&mut results)
+        .map(|(name, results)| {
+            let count = rng.gen_range(2..=4).max(results.len());
+            while results.len() < count {
+                results.push(if rng.gen_bool(0.5) { "A" } else { "R" });
+            }
+            results.shuffle(rng);
+
+            let (default, results) = results.split_last().unwrap();
+            let rules = results.iter().map(|result| {
+                let variable = *b"xmas".choose(rng).unwrap() as char;
+                let operator = if rng.gen_bool(0.5) { '<' } else { '>' };
+                format!("{variable}{operator}{}:{result}", rng.gen_range(1..=4000))
+            });
+
+            format!("{name}{{{}}}", rules.chain([default.to_string()]).join(","))
+        })
+        .collect();
+    workflows.shuffle(rng);
+
+    let parts = (0..size).map(|_| {
+        let [x, m, a, s] = [(); 4].map(|_| rng.gen_range(1..=4000));
+        format!("{{x={x},m={m},a={a},s={s}}}")
+    });
+
+    format!("{}\n{}", join_lines(workflows), join_lines(parts))
+}
+
+/// Generates the modules of a machine in which each period is counted by a chain of twelve
+/// flip-flops and a conjunction that resets them, with one counter for each period. The counters
+/// all feed a final conjunction that sends to 'rx', so 'rx' first gets a low pulse after the
+/// product of the periods.
+///
+/// # Arguments
+///
+/// 'rng' - The random number generator.
+///
+/// 'periods' - The period of each counter, each of which must be an odd number of 12 bits.
+fn counter_modules(rng: &mut StdRng, periods: &[u64]) -> String {
+    let mut names = random_names(rng, periods.len() * 14 + 1, 2..=2, &["rx"]).into_iter();
+    let last = names.next().unwrap();
+    let mut firsts = vec![];
+    let mut lines = vec![format!("&{last} -> rx")];
+
+    for period in periods {
+        let flip_flops: Vec<_> = names.by_ref().take(12).collect();
+        let (hub, inverter) = (names.next().unwrap(), names.next().unwrap());
+        let mut hub_targets = vec![inverter.clone(), flip_flops[0].clone()];
+
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut targets: Vec<_> = flip_flops.get(bit + 1).into_iter().collect();
+            if period & (1 << bit) != 0 {
+                targets.push(&hub);
+            } else {
+                hub_targets.push(flip_flop.clone());
+            }
+            targets.shuffle(rng);
+            lines.push(format!("%{flip_flop} -> {}", targets.iter().join(", ")));
+        }
+
+        hub_targets.shuffle(rng);
+        lines.push(format!("&{hub} -> {}", hub_targets.join(", ")));
+        lines.push(format!("&{inverter} -> {last}"));
+        firsts.push(flip_flops[0].clone());
+    }
+
+    lines.push(format!("broadcaster -> {}", firsts.join(", ")));
+    lines.shuffle(rng);
+
+    join_lines(lines)
+}
+
+/// Generates the modules of a machine made of counters with prime periods. The size is the
+/// number of counters, at most 5 so that the answer fits in 64 bits.
+fn day20(rng: &mut StdRng, size: usize) -> String {
+    let primes: Vec<u64> = (2049..4096u64)
+        .step_by(2)
+        .filter(|n| (3..64).step_by(2).all(|d| n % d != 0))
+        .collect();
+    let periods: Vec<_> = primes
+        .choose_multiple(rng, size.clamp(1, 5))
+        .copied()
+        .collect();
+
+    counter_modules(rng, &periods)
+}
+
+/// Generates a garden with the start in the middle and open paths along the middle row, the
+/// middle column and the edges, as the second part relies on. The size is the width of the
+/// garden, which can only be 131, as that is the only useful size that tiles evenly into the
+/// number of steps taken in the second part.
+fn day21(rng: &mut StdRng, size: usize) -> String {
+    let center = size / 2;
+
+    let mut rows = random_rows(
+        rng,
+        size,
+        size,
+        |rng| {
+            if rng.gen_bool(0.12) {
+                '#'
+            } else {
+                '.'
+            }
+        },
+    );
+    for i in 0..size {
+        for (x, y) in [
+            (i, 0),
+            (i, size - 1),
+            (0, i),
+            (size - 1, i),
+            (i, center),
+            (center, i),
+        ] {
+            rows[y][x] = '.';
+        }
+    }
+    rows[center][center] = 'S';
+
+    join_rows(&rows)
+}
+
+/// Generates a snapshot of falling bricks, each a straight line of up to four cubes, that don't
+/// overlap. The size is the number of bricks.
+fn day22(rng: &mut StdRng, size: usize) -> String {
+    let top = (size / 3).max(10) as i32;
+    let mut occupied = HashSet::new();
+    let mut bricks = vec![];
+
+    while bricks.len() < size {
+        let mut start = [
+            rng.gen_range(0..10),
+            rng.gen_range(0..10),
+            rng.gen_range(1..=top),
+        ];
+        let axis = rng.gen_range(0..3);
+        let length = rng.gen_range(0..4);
+        start[axis] = start[axis].min(if axis == 2 { top } else { 9 } - length);
+
+        let cubes: Vec<_> = (0..=length)
+            .map(|i| {
+                let mut cube = start;
+                cube[axis] += i;
+                cube
+            })
+            .collect();
+
+        if cubes.iter().all(|cube| !occupied.contains(cube)) {
+            let end = cubes[cubes.len() - 1];
+            occupied.extend(cubes);
+            bricks.push(format!(
+                "{}~{}",
+                start.iter().join(","),
+                end.iter().join(",")
+            ));
+        }
+    }
+
+    join_lines(bricks)
+}
+
+/// Generates a map of hiking trails that cross at the junctions of a lattice, with slopes that
+/// only let the trails be followed to the right and downwards. The size is the number of
+/// junctions along each side of the lattice.
+fn day23(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    let position = |rng: &mut StdRng| {
+        (0..size)
+            .scan(0, |at, _| {
+                *at += rng.gen_range(4..=9);
+                Some(*at)
+            })
+            .collect::<Vec<usize>>()
+    };
+    let (xs, ys) = (position(rng), position(rng));
+    let (last_x, last_y) = (xs[size - 1], ys[size - 1]);
+    let mut rows = vec![vec!['#'; last_x + 3]; last_y + 3];
+
+    // The trail starts at the top left and joins the first junction...
+    for row in &mut rows[..=ys[0]] {
+        row[1] = '.';
+    }
+    rows[ys[0]][1..xs[0]].fill('.');
+    rows[ys[0]][xs[0] - 1] = '>';
+
+    // ...then runs between each junction and the ones to its right and below...
+    for (i, &x) in xs.iter().enumerate() {
+        for (j, &y) in ys.iter().enumerate() {
+            rows[y][x] = '.';
+
+            if let Some(&next) = xs.get(i + 1) {
+                rows[y][x + 1..next].fill('.');
+                (rows[y][x + 1], rows[y][next - 1]) = ('>', '>');
+            }
+            if let Some(&next) = ys.get(j + 1) {
+                for row in &mut rows[y + 1..next] {
+                    row[x] = '.';
+                }
+                (rows[y + 1][x], rows[next - 1][x]) = ('v', 'v');
+            }
+        }
+    }
+
+    // ...and leaves the last junction to end on the bottom row
+    for row in &mut rows[last_y..] {
+        row[last_x + 1] = '.';
+    }
+
+    join_rows(&rows)
+}
+
+/// Generates hailstones that are all hit by a rock thrown from a given position with a given
+/// velocity.
+///
+/// # Arguments
+///
+/// 'rng' - The random number generator.
+///
+/// 'count' - The number of hailstones.
+///
+/// 'rock' - The position and velocity of the rock.
+fn hailstones(rng: &mut StdRng, count: usize, rock: ([i64; 3], [i64; 3])) -> String {
+    let (position, velocity) = rock;
+    let mut times = HashSet::new();
+
+    join_lines((0..count).map(|_| {
+        let time = loop {
+            let time = rng.gen_range(100_000_000_000..1_000_000_000_000i64);
+            if times.insert(time) {
+                break time;
+            }
+        };
+
+        // Keep every hailstone moving relative to the rock, so that each hit happens only once
+        let hail = velocity.map(|v| loop {
+            let h = rng.gen_range(-300..=300);
+            if h != v {
+                break h;
+            }
+        });
+        let start: Vec<_> = (0..3)
+            .map(|i| position[i] + (velocity[i] - hail[i]) * time)
+            .collect();
+
+        format!("{} @ {}", start.iter().join(", "), hail.iter().join(", "))
+    }))
+}
+
+/// Generates hailstones that can all be hit by a single rock. The size is the number of
+/// hailstones, which is at least 5 as the second part needs that many.
+fn day24(rng: &mut StdRng, size: usize) -> String {
+    let position = [(); 3].map(|_| rng.gen_range(100_000_000_000_000..400_000_000_000_000));
+    let velocity = [(); 3].map(|_| rng.gen_range(-300..=300));
+
+    hailstones(rng, size.max(5), (position, velocity))
+}
+
+/// Generates a wiring diagram of two groups of components, where each group is well connected
+/// within itself and the groups are joined by exactly three wires.
+///
+/// # Arguments
+///
+/// 'rng' - The random number generator.
+///
+/// 'sizes' - The number of components in each group, which must be at least 5.
+fn wiring(rng: &mut StdRng, sizes: (usize, usize)) -> String {
+    let total = sizes.0 + sizes.1;
+    let mut wires = HashSet::new();
+
+    // Joining each component to the next two in a ring means at least four wires must be cut to
+    // split a group, so the three wires between the groups are the only cut of three
+    for (first, size) in [(0, sizes.0), (sizes.0, sizes.1)] {
+        for i in 0..size {
+            for step in [1, 2, rng.gen_range(3..size.max(4))] {
+                let j = (i + step) % size;
+                if i != j {
+                    wires.insert((first + i.min(j), first + i.max(j)));
+                }
+            }
+        }
+    }
+
+    let left = rand::seq::index::sample(rng, sizes.0, 3);
+    let right = rand::seq::index::sample(rng, sizes.1, 3);
+    for (a, b) in left.iter().zip(right.iter()) {
+        wires.insert((a, sizes.0 + b));
+    }
+
+    let names = random_names(rng, total, 3..=3, &[]);
+    let mut connections: HashMap<usize, Vec<&str>> = HashMap::new();
+    for (a, b) in wires.into_iter().sorted() {
+        let (from, to) = if rng.gen_bool(0.5) { (a, b) } else { (b, a) };
+        connections.entry(from).or_default().push(&names[to]);
+    }
+
+    let mut lines: Vec<_> = connections
+        .into_iter()
+        .sorted()
+        .map(|(from, to)| format!("{}: {}", names[from], to.join(" ")))
+        .collect();
+    lines.shuffle(rng);
+
+    join_lines(lines)
+}
+
+/// Generates a wiring diagram that can be split in two by cutting three wires. The size is the
+/// number of components, which is at least 10.
+fn day25(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(10);
+    let first = rng.gen_range(5..=size - 5);
+
+    wiring(rng, (first, size - first))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generate::*;
+    use crate::solver;
+    use crate::year2023::{day20, day25};
+
+    #[test]
+    fn generated_inputs_can_be_solved() {
+        for solver in solver::SOLVERS.iter().filter(|s| s.year() == YEAR) {
+            let day = solver.day();
+            let size = typical_size(YEAR, day).unwrap();
+            let size = if FIXED_SIZE_DAYS.contains(&day) {
+                size
+            } else {
+                size.min(4)
+            };
+
+            for seed in 0..3 {
+                let input = generate(YEAR, day, size, seed).unwrap();
+                let context = format!("day {day}, seed {seed}:\n{input}");
+
+                if let Some(parsed) = solver.parse(&input) {
+                    assert!(parsed.is_ok(), "{context}");
+                }
+                assert!(solver.part_one(&input).is_ok(), "{context}");
+
+                // Day 21's map is always full size, where the second part is too slow to run
+                // without optimizations
+                if day != 21 {
+                    assert!(solver.part_two(&input).is_ok(), "{context}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn generation_is_repeatable() {
+        assert_eq!(generate(2023, 7, 20, 1), generate(2023, 7, 20, 1));
+        assert_ne!(generate(2023, 7, 20, 1), generate(2023, 7, 20, 2));
+        assert_eq!(generate(2023, 7, 20, 1).unwrap().lines().count(), 20);
+        assert_eq!(
+            generate(2023, 0, 20, 1),
+            Err(String::from("No generator for day 0 of 2023"))
+        );
+        assert!(generate(2023, 26, 20, 1).is_err());
+        assert!(generate(2022, 7, 20, 1).is_err());
+        assert_eq!(typical_size(2023, 21), Some(131));
+        assert!(generate(2023, 21, 131, 1).is_ok());
+        assert_eq!(
+            generate(2023, 21, 20, 1),
+            Err(String::from(
+                "Day 21 of 2023 can only be generated with size 131"
+            ))
+        );
+    }
+
+    #[test]
+    fn planted_answers_are_found() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let input = wiring(&mut rng, (7, 9));
+        assert_eq!(day25::part_one(&input), Ok(63));
+
+        let input = counter_modules(&mut rng, &[3847, 4027, 3761]);
+        assert_eq!(day20::part_two(&input), Ok(3847 * 4027 * 3761));
+
+        let (position, velocity) = ([24, 13, 10], [-3, 1, 2]);
+        let input = hailstones(&mut rng, 5, (position, velocity));
+        for line in input.lines() {
+            let values: Vec<i64> = line
+                .split(|c: char| !c.is_ascii_digit() && c != '-')
+                .filter_map(|value| value.parse().ok())
+                .collect();
+            let time = (values[0] - position[0]) / (velocity[0] - values[3]);
+
+            assert!(time > 0);
+            for i in 0..3 {
+                assert_eq!(
+                    values[i] + values[i + 3] * time,
+                    position[i] + velocity[i] * time
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,564 @@
+use rand::random;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// A directed graph with weighted edges. Each node is named by a key, such as a string from the
+/// puzzle input, which is interned to a small integer id the first time it is seen, so the
+/// algorithms below can work with plain vectors indexed by id.
+#[derive(Debug, Clone)]
+pub struct Graph<K> {
+    keys: Vec<K>,
+    ids: HashMap<K, usize>,
+    edges: Vec<Vec<(usize, usize)>>,
+}
+
+/// A cut splitting a graph in two, found by contracting its edges.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Cut {
+    /// The number of edges crossing the cut.
+    pub edges: usize,
+    /// The number of nodes on each side of the cut.
+    pub sizes: (usize, usize),
+}
+
+impl<K: Clone + Eq + Hash> Default for Graph<K> {
+    fn default() -> Self {
+        Graph::new()
+    }
+}
+
+impl<K: Clone + Eq + Hash> Graph<K> {
+    /// Creates an empty graph.
+    pub fn new() -> Graph<K> {
+        Graph {
+            keys: vec![],
+            ids: HashMap::new(),
+            edges: vec![],
+        }
+    }
+
+    /// Returns the id of the node with the given key, adding the node if it hasn't been seen
+    /// before.
+    ///
+    /// # Arguments
+    ///
+    /// 'key' - The key naming the node.
+    pub fn add_node(&mut self, key: K) -> usize {
+        if let Some(id) = self.ids.get(&key) {
+            return *id;
+        }
+
+        let id = self.keys.len();
+        self.keys.push(key.clone());
+        self.ids.insert(key, id);
+        self.edges.push(vec![]);
+        id
+    }
+
+    /// Adds an edge leading from one node to another, adding either node if it hasn't been seen
+    /// before. Edges are kept in the order they are added.
+    ///
+    /// # Arguments
+    ///
+    /// 'from' - The key of the node the edge leads from.
+    ///
+    /// 'to' - The key of the node the edge leads to.
+    ///
+    /// 'weight' - The cost of following the edge.
+    pub fn add_edge(&mut self, from: K, to: K, weight: usize) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+
+        self.edges[from].push((to, weight));
+    }
+
+    /// Adds a pair of edges leading both ways between two nodes.
+    ///
+    /// # Arguments
+    ///
+    /// 'a' - The key of the node at one end of the edge.
+    ///
+    /// 'b' - The key of the node at the other end of the edge.
+    ///
+    /// 'weight' - The cost of following the edge in either direction.
+    pub fn add_undirected_edge(&mut self, a: K, b: K, weight: usize) {
+        self.add_edge(a.clone(), b.clone(), weight);
+        self.add_edge(b, a, weight);
+    }
+
+    /// Returns the id of the node with the given key, or None if there is no such node.
+    ///
+    /// # Arguments
+    ///
+    /// 'key' - The key naming the node.
+    pub fn id(&self, key: &K) -> Option<usize> {
+        self.ids.get(key).copied()
+    }
+}
+
+impl<K> Graph<K> {
+    /// Returns the key naming the node with the given id.
+    ///
+    /// # Arguments
+    ///
+    /// 'id' - The id of the node.
+    pub fn key(&self, id: usize) -> &K {
+        &self.keys[id]
+    }
+
+    /// Returns the number of nodes in the graph.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns whether the graph has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns the id and key of every node, in the order they were added.
+    pub fn nodes(&self) -> impl Iterator<Item = (usize, &K)> {
+        self.keys.iter().enumerate()
+    }
+
+    /// Returns the edges leading from a node, as pairs of the id of the node each leads to and
+    /// its weight.
+    ///
+    /// # Arguments
+    ///
+    /// 'id' - The id of the node.
+    pub fn edges(&self, id: usize) -> &[(usize, usize)] {
+        &self.edges[id]
+    }
+
+    /// Returns the ids of the nodes that the edges from a node lead to.
+    ///
+    /// # Arguments
+    ///
+    /// 'id' - The id of the node.
+    pub fn neighbours(&self, id: usize) -> impl Iterator<Item = usize> + '_ {
+        self.edges[id].iter().map(|(to, _)| *to)
+    }
+
+    /// Returns the ids of the nodes with an edge leading to a node.
+    ///
+    /// # Arguments
+    ///
+    /// 'id' - The id of the node.
+    pub fn predecessors(&self, id: usize) -> Vec<usize> {
+        (0..self.len())
+            .filter(|from| self.neighbours(*from).any(|to| to == id))
+            .collect()
+    }
+
+    /// Returns the number of edges on the shortest route from a node to every other node, found
+    /// with a breadth first search, or None for the nodes that can't be reached.
+    ///
+    /// # Arguments
+    ///
+    /// 'start' - The id of the node to search from.
+    pub fn bfs(&self, start: usize) -> Vec<Option<usize>> {
+        let mut steps = vec![None; self.len()];
+        let mut queue = VecDeque::new();
+
+        steps[start] = Some(0);
+        queue.push_back(start);
+
+        while let Some(id) = queue.pop_front() {
+            let next_steps = steps[id].map(|s| s + 1);
+
+            for to in self.neighbours(id) {
+                if steps[to].is_none() {
+                    steps[to] = next_steps;
+                    queue.push_back(to);
+                }
+            }
+        }
+
+        steps
+    }
+
+    /// Returns the ids of every node that can be reached from a node, in the order they are first
+    /// visited by a depth first search.
+    ///
+    /// # Arguments
+    ///
+    /// 'start' - The id of the node to search from.
+    pub fn dfs(&self, start: usize) -> Vec<usize> {
+        let mut visited = vec![false; self.len()];
+        let mut stack = vec![start];
+        let mut order = vec![];
+
+        while let Some(id) = stack.pop() {
+            if visited[id] {
+                continue;
+            }
+            visited[id] = true;
+            order.push(id);
+
+            // Push the edges in reverse so that the first edge is followed first
+            stack.extend(
+                self.edges[id]
+                    .iter()
+                    .rev()
+                    .map(|(to, _)| *to)
+                    .filter(|to| !visited[*to]),
+            );
+        }
+
+        order
+    }
+
+    /// Returns the lowest total weight of the edges on a route from a node to every other node,
+    /// found with Dijkstra's algorithm, or None for the nodes that can't be reached.
+    ///
+    /// # Arguments
+    ///
+    /// 'start' - The id of the node to search from.
+    pub fn dijkstra(&self, start: usize) -> Vec<Option<usize>> {
+        let mut costs = vec![None; self.len()];
+        let mut heap = BinaryHeap::new();
+
+        heap.push(Reverse((0, start)));
+
+        while let Some(Reverse((cost, id))) = heap.pop() {
+            if costs[id].is_some() {
+                continue;
+            }
+            costs[id] = Some(cost);
+
+            for (to, weight) in &self.edges[id] {
+                if costs[*to].is_none() {
+                    heap.push(Reverse((cost + weight, *to)));
+                }
+            }
+        }
+
+        costs
+    }
+
+    /// Returns the ids of every node ordered so that each edge leads from an earlier node to a
+    /// later one, or None if the graph has a cycle and so has no such order.
+    pub fn topological_sort(&self) -> Option<Vec<usize>> {
+        let mut incoming = vec![0; self.len()];
+        for id in 0..self.len() {
+            for to in self.neighbours(id) {
+                incoming[to] += 1;
+            }
+        }
+
+        let mut ready: VecDeque<_> = (0..self.len()).filter(|id| incoming[*id] == 0).collect();
+        let mut order = vec![];
+
+        while let Some(id) = ready.pop_front() {
+            order.push(id);
+
+            for to in self.neighbours(id) {
+                incoming[to] -= 1;
+                if incoming[to] == 0 {
+                    ready.push_back(to);
+                }
+            }
+        }
+
+        (order.len() == self.len()).then_some(order)
+    }
+
+    /// Returns the groups of nodes that are connected to each other, ignoring the direction of
+    /// the edges. Each group lists its ids in order, and the groups are ordered by their first id.
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        // A union-find, where each node points towards the representative of its group
+        let mut parents: Vec<_> = (0..self.len()).collect();
+
+        fn find(parents: &mut [usize], id: usize) -> usize {
+            let mut root = id;
+            while parents[root] != root {
+                root = parents[root];
+            }
+
+            // Point everything on the way straight at the root to speed up later searches
+            let mut id = id;
+            while parents[id] != root {
+                let next = parents[id];
+                parents[id] = root;
+                id = next;
+            }
+
+            root
+        }
+
+        for id in 0..self.len() {
+            for to in self.neighbours(id) {
+                let a = find(&mut parents, id);
+                let b = find(&mut parents, to);
+                parents[a.max(b)] = a.min(b);
+            }
+        }
+
+        let mut groups: Vec<Vec<usize>> = vec![];
+        let mut group_of_root = HashMap::new();
+
+        for id in 0..self.len() {
+            let root = find(&mut parents, id);
+            let group = *group_of_root.entry(root).or_insert_with(|| {
+                groups.push(vec![]);
+                groups.len() - 1
+            });
+            groups[group].push(id);
+        }
+
+        groups
+    }
+
+    /// Runs a single trial of Karger's algorithm, contracting randomly chosen edges until only two
+    /// nodes remain, and returns the cut between them. The direction of the edges is ignored. The
+    /// cut found is only likely to be the minimum cut after many trials, so see 'min_cut'.
+    /// Returns None if the graph has fewer than two nodes or isn't connected.
+    pub fn contract(&self) -> Option<Cut> {
+        let mut num_nodes = self.len();
+        if num_nodes < 2 {
+            return None;
+        }
+
+        // As we collapse the graph, we'll keep track of the number of initial nodes that have
+        // been merged into any remaining nodes. The initial value for each node is 1 since at the
+        // beginning, each node only represents itself.
+        let mut counts = vec![1usize; num_nodes];
+
+        // Each edge is only needed once, whichever way it leads
+        let mut edges: Vec<_> = (0..self.len())
+            .flat_map(|a| self.neighbours(a).map(move |b| (a.min(b), a.max(b))))
+            .filter(|(a, b)| a != b)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+
+        // We'll continue to collapse edges until only two nodes remain.
+        while num_nodes > 2 {
+            if edges.is_empty() {
+                return None;
+            }
+
+            // Pick a random edge to collapse and get the two node ID's for the edge.
+            // (Karger's algorithm works because there are far more edges NOT in the minimal cut,
+            // so odds are we'll select an edge not on the minimal cut)
+            let (a, b) = edges[random::<usize>() % edges.len()];
+
+            // Go through all the edges and replace any references to node B with node A since we
+            // are merging the two nodes together and only need to keep one.
+            edges.iter_mut().for_each(|edge| {
+                if edge.0 == b {
+                    edge.0 = a;
+                };
+                if edge.1 == b {
+                    edge.1 = a;
+                };
+            });
+            // Remove any edges where both nodes are the same - those are the edges we just
+            // collapsed (there may be more than one since as we continue to collapse the graph,
+            // we may end up with multiple edges connecting any two nodes)
+            edges.retain(|e| e.0 != e.1);
+
+            // Transfer the node counts from node B into node A since we just merged them both
+            // together.
+            counts[a] += counts[b];
+            counts[b] = 0;
+
+            num_nodes -= 1;
+        }
+
+        // With only two nodes left, all the remaining edges must connect those two nodes, so we
+        // can get the two node ID's from any remaining edge (like the first one).
+        let (a, b) = *edges.first()?;
+
+        Some(Cut {
+            edges: edges.len(),
+            sizes: (counts[a], counts[b]),
+        })
+    }
+
+    /// Returns the smallest cut found by running a number of trials of Karger's algorithm, or None
+    /// if the graph has fewer than two nodes or isn't connected. A single trial finds the minimum
+    /// cut with a chance of at least 2 / (N * N), where N is the number of nodes, so it takes
+    /// about N * N * ln(N) trials to be all but certain of finding it. See 'stoer_wagner' for a
+    /// cut that is always the minimum.
+    ///
+    /// # Arguments
+    ///
+    /// 'trials' - The number of trials to run.
+    pub fn min_cut(&self, trials: usize) -> Option<Cut> {
+        (0..trials)
+            .map(|_| self.contract())
+            .min_by_key(|cut| cut.map(|cut| cut.edges))
+            .flatten()
+    }
+
+    /// Returns the minimum cut of the graph, found with the Stoer-Wagner algorithm, or None if
+    /// the graph has fewer than two nodes. The direction of the edges is ignored, and the cut's
+    /// edges are the total weight of the edges crossing it, with nodes joined both ways counted
+    /// once. A graph that isn't connected has a cut with no edges.
+    pub fn stoer_wagner(&self) -> Option<Cut> {
+        let num_nodes = self.len();
+        if num_nodes < 2 {
+            return None;
+        }
+
+        // The weight joining each pair of nodes. As nodes are merged, the weights of the merged
+        // node's edges are added to the node it was merged into.
+        let mut weights: Vec<HashMap<usize, usize>> = vec![HashMap::new(); num_nodes];
+        for a in 0..num_nodes {
+            for (b, weight) in &self.edges[a] {
+                if a != *b {
+                    weights[a].insert(*b, *weight);
+                    weights[*b].insert(a, *weight);
+                }
+            }
+        }
+
+        let mut counts = vec![1usize; num_nodes];
+        let mut merged = vec![false; num_nodes];
+        let mut best: Option<Cut> = None;
+
+        for _ in 1..num_nodes {
+            // Add the remaining nodes one at a time, always picking the one most tightly joined
+            // to those already added. The last two added are the two ends of this phase's cut.
+            let mut joined = vec![0; num_nodes];
+            let mut added = merged.clone();
+            let mut heap: BinaryHeap<_> = (0..num_nodes)
+                .filter(|id| !merged[*id])
+                .map(|id| (0, id))
+                .collect();
+            let (mut previous, mut last) = (usize::MAX, usize::MAX);
+
+            while let Some((weight, id)) = heap.pop() {
+                if added[id] || weight != joined[id] {
+                    continue;
+                }
+                added[id] = true;
+                (previous, last) = (last, id);
+
+                for (to, weight) in &weights[id] {
+                    if !added[*to] {
+                        joined[*to] += weight;
+                        heap.push((joined[*to], *to));
+                    }
+                }
+            }
+
+            // Cutting the last node away from everything else is the lightest cut between the
+            // last two nodes, so the minimum cut is the lightest of these over all the phases
+            if best.is_none_or(|cut| joined[last] < cut.edges) {
+                best = Some(Cut {
+                    edges: joined[last],
+                    sizes: (counts[last], num_nodes - counts[last]),
+                });
+            }
+
+            // Merge the last node into the one before it for the next phase
+            for (to, weight) in std::mem::take(&mut weights[last]) {
+                weights[to].remove(&last);
+                if to != previous {
+                    *weights[previous].entry(to).or_insert(0) += weight;
+                    *weights[to].entry(previous).or_insert(0) += weight;
+                }
+            }
+            counts[previous] += counts[last];
+            merged[last] = true;
+        }
+
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::*;
+
+    fn build(edges: &[(&'static str, &'static str, usize)]) -> Graph<&'static str> {
+        let mut graph = Graph::new();
+        for (a, b, weight) in edges {
+            graph.add_edge(*a, *b, *weight);
+        }
+        graph
+    }
+
+    #[test]
+    fn intern_nodes() {
+        let mut graph = build(&[("a", "b", 1), ("b", "c", 2), ("a", "c", 5)]);
+
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph.id(&"b"), Some(1));
+        assert_eq!(graph.id(&"d"), None);
+        assert_eq!(*graph.key(2), "c");
+        assert_eq!(graph.add_node("a"), 0);
+        assert_eq!(graph.edges(0), &[(1, 1), (2, 5)]);
+        assert_eq!(graph.neighbours(1).collect::<Vec<_>>(), vec![2]);
+        assert_eq!(graph.predecessors(2), vec![0, 1]);
+    }
+
+    #[test]
+    fn search_graph() {
+        let graph = build(&[("a", "b", 1), ("b", "c", 2), ("a", "c", 5), ("d", "a", 1)]);
+
+        assert_eq!(graph.bfs(0), vec![Some(0), Some(1), Some(1), None]);
+        assert_eq!(graph.dfs(0), vec![0, 1, 2]);
+        assert_eq!(graph.dfs(3), vec![3, 0, 1, 2]);
+        assert_eq!(graph.dijkstra(0), vec![Some(0), Some(1), Some(3), None]);
+    }
+
+    #[test]
+    fn sort_graph() {
+        let graph = build(&[("a", "b", 1), ("b", "c", 2), ("a", "c", 5), ("d", "a", 1)]);
+        assert_eq!(graph.topological_sort(), Some(vec![3, 0, 1, 2]));
+
+        let graph = build(&[("a", "b", 1), ("b", "a", 1)]);
+        assert_eq!(graph.topological_sort(), None);
+    }
+
+    #[test]
+    fn find_components() {
+        let graph = build(&[("a", "b", 1), ("c", "d", 1), ("d", "b", 1), ("e", "f", 1)]);
+        assert_eq!(
+            graph.connected_components(),
+            vec![vec![0, 1, 2, 3], vec![4, 5]]
+        );
+
+        let mut graph = build(&[]);
+        graph.add_node("a");
+        assert_eq!(graph.connected_components(), vec![vec![0]]);
+    }
+
+    #[test]
+    fn cut_graph() {
+        // Two triangles joined by a single edge
+        let mut graph = Graph::new();
+        for (a, b) in [
+            ("a", "b"),
+            ("b", "c"),
+            ("c", "a"),
+            ("d", "e"),
+            ("e", "f"),
+            ("f", "d"),
+            ("c", "d"),
+        ] {
+            graph.add_undirected_edge(a, b, 1);
+        }
+
+        let cut = graph.min_cut(100).unwrap();
+        assert_eq!(cut.edges, 1);
+        assert_eq!(cut.sizes.0 * cut.sizes.1, 9);
+        assert_eq!(
+            graph.stoer_wagner(),
+            Some(Cut {
+                edges: 1,
+                sizes: (3, 3)
+            })
+        );
+
+        let graph = build(&[("a", "b", 1), ("c", "d", 1), ("e", "f", 1)]);
+        assert_eq!(graph.contract(), None);
+        assert_eq!(graph.stoer_wagner().map(|cut| cut.edges), Some(0));
+        assert_eq!(build(&[]).stoer_wagner(), None);
+    }
+}
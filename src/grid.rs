@@ -0,0 +1,432 @@
+use crate::error::{char_at, ParseError};
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// The offsets to the four neighbours of a cell that share an edge with it: up, down, left and
+/// right.
+const EDGE_OFFSETS: [(i64, i64); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+
+/// The offsets to all eight neighbours of a cell, including the diagonals, in reading order.
+const ALL_OFFSETS: [(i64, i64); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid of cells, such as a map from the puzzle input. Positions are given as
+/// (x, y) pairs, with (0, 0) at the top left corner and y increasing downwards.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid of the given size, with each cell set by calling a function with its
+    /// position.
+    ///
+    /// # Arguments
+    ///
+    /// 'width' - The number of columns.
+    ///
+    /// 'height' - The number of rows.
+    ///
+    /// 'f' - The function returning the value of the cell at each position.
+    pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Grid<T>
+    where
+        F: FnMut((usize, usize)) -> T,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a grid from text, with one row of the grid on each line, by mapping each character
+    /// to a cell. The text must contain at least one row, and every row must be the same length.
+    ///
+    /// # Arguments
+    ///
+    /// 'text' - The text to parse.
+    ///
+    /// 'expected' - A description of the characters that are allowed, used when reporting an
+    /// error.
+    ///
+    /// 'f' - The function mapping each character to a cell, or returning None if the character
+    /// isn't allowed.
+    pub fn parse<F>(text: &str, expected: &str, mut f: F) -> Result<Grid<T>, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let width = text.lines().next().map_or(0, |line| line.chars().count());
+        if width == 0 {
+            return Err(ParseError::missing(text, "a grid"));
+        }
+
+        let mut cells = vec![];
+        let mut height = 0;
+
+        for line in text.lines() {
+            for (i, c) in line.char_indices() {
+                let cell = f(c).ok_or_else(|| ParseError::new(text, char_at(line, i), expected))?;
+                cells.push(cell);
+            }
+
+            height += 1;
+            if cells.len() != width * height {
+                return Err(ParseError::new(
+                    text,
+                    line,
+                    format!("a row of {width} characters"),
+                ));
+            }
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Returns the number of columns in the grid.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows in the grid.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the cell at the given position, or None if the position is outside the grid.
+    ///
+    /// # Arguments
+    ///
+    /// 'pos' - The (x, y) position of the cell.
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the cell at the given position, or None if the position is
+    /// outside the grid.
+    ///
+    /// # Arguments
+    ///
+    /// 'pos' - The (x, y) position of the cell.
+    pub fn get_mut(&mut self, (x, y): (usize, usize)) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the cell at the given position on an infinite map made by repeating the grid in
+    /// every direction, so positions off one edge wrap around to the opposite edge.
+    ///
+    /// # Arguments
+    ///
+    /// 'pos' - The (x, y) position of the cell, which may be negative or beyond the grid.
+    pub fn get_wrapping(&self, (x, y): (i64, i64)) -> &T {
+        let x = x.rem_euclid(self.width as i64) as usize;
+        let y = y.rem_euclid(self.height as i64) as usize;
+
+        &self.cells[y * self.width + x]
+    }
+
+    /// Returns the position reached by moving from the given position by an offset, or None if
+    /// that would leave the grid.
+    ///
+    /// # Arguments
+    ///
+    /// 'pos' - The (x, y) position to move from.
+    ///
+    /// 'offset' - The (x, y) distance to move.
+    pub fn offset(&self, (x, y): (usize, usize), (dx, dy): (i64, i64)) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx as isize)?;
+        let y = y.checked_add_signed(dy as isize)?;
+
+        (x < self.width && y < self.height).then_some((x, y))
+    }
+
+    /// Returns the positions of the up to four cells that share an edge with the given position,
+    /// leaving out any that are outside the grid.
+    ///
+    /// # Arguments
+    ///
+    /// 'pos' - The (x, y) position whose neighbours to find.
+    pub fn neighbours(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        EDGE_OFFSETS
+            .iter()
+            .filter_map(move |offset| self.offset(pos, *offset))
+    }
+
+    /// Returns the positions of the up to eight cells surrounding the given position, including
+    /// those diagonally adjacent to it, leaving out any that are outside the grid.
+    ///
+    /// # Arguments
+    ///
+    /// 'pos' - The (x, y) position whose neighbours to find.
+    pub fn all_neighbours(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ALL_OFFSETS
+            .iter()
+            .filter_map(move |offset| self.offset(pos, *offset))
+    }
+
+    /// Returns the cells in the given row.
+    ///
+    /// # Arguments
+    ///
+    /// 'y' - The index of the row.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Returns the rows of the grid, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// Returns the cells in the given column, from top to bottom.
+    ///
+    /// # Arguments
+    ///
+    /// 'x' - The index of the column.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Returns every position in the grid, in reading order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Returns every position in the grid along with its cell, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Returns every position in the grid along with a mutable reference to its cell, in reading
+    /// order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        self.positions().zip(self.cells.iter_mut())
+    }
+
+    /// Returns the position of the first cell, in reading order, that matches a predicate.
+    ///
+    /// # Arguments
+    ///
+    /// 'predicate' - The test for each cell.
+    pub fn find<P>(&self, mut predicate: P) -> Option<(usize, usize)>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// Returns a grid of the same size with each cell converted by a function.
+    ///
+    /// # Arguments
+    ///
+    /// 'f' - The function converting each cell.
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid of the given size with every cell set to the same value.
+    ///
+    /// # Arguments
+    ///
+    /// 'width' - The number of columns.
+    ///
+    /// 'height' - The number of rows.
+    ///
+    /// 'value' - The value of every cell.
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Returns the grid flipped over its main diagonal, so that its rows become columns.
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    /// Returns the grid rotated a quarter turn clockwise.
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |(x, y)| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    /// Returns the grid rotated a quarter turn anticlockwise.
+    pub fn rotate_anticlockwise(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |(x, y)| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("Position {pos:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("Position {pos:?} is outside the grid"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the slice of the text holding the character at the given position of a grid parsed
+/// from it, for reporting an error about that cell. The slice is empty if there is no such
+/// character.
+///
+/// # Arguments
+///
+/// 'text' - The text the grid was parsed from.
+///
+/// 'pos' - The (x, y) position of the cell.
+pub fn char_at_position(text: &str, (x, y): (usize, usize)) -> &str {
+    let line = text.lines().nth(y).unwrap_or_default();
+    let index = line.char_indices().nth(x).map_or(line.len(), |(i, _)| i);
+
+    char_at(line, index)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::*;
+
+    fn digits(text: &str) -> Grid<u32> {
+        Grid::parse(text, "a digit", |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn parse_grid() {
+        let grid = digits("123\n456");
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get((1, 0)), Some(&2));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456\n");
+
+        let parse = |text| Grid::parse(text, "a digit", |c| c.to_digit(10));
+        assert_eq!(
+            parse("123\n4x6").unwrap_err().to_string(),
+            "line 2, column 2: expected a digit but found 'x'"
+        );
+        assert_eq!(
+            parse("123\n45").unwrap_err().to_string(),
+            "line 2, column 1: expected a row of 3 characters but found '45'"
+        );
+        assert_eq!(char_at_position("123\n4x6", (1, 1)), "x");
+        assert_eq!(
+            parse("").unwrap_err().to_string(),
+            "line 1, column 1: expected a grid but found nothing"
+        );
+    }
+
+    #[test]
+    fn index_positions() {
+        let mut grid = digits("123\n456");
+
+        grid[(0, 1)] = 9;
+        assert_eq!(grid.row(1), &[9, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.rows().count(), 2);
+
+        assert_eq!(*grid.get_wrapping((3, 0)), 1);
+        assert_eq!(*grid.get_wrapping((-1, -1)), 6);
+        assert_eq!(*grid.get_wrapping((7, 5)), 5);
+
+        assert_eq!(grid.offset((1, 1), (1, -1)), Some((2, 0)));
+        assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+        assert_eq!(grid.offset((2, 1), (0, 1)), None);
+
+        assert_eq!(grid.find(|d| *d == 5), Some((1, 1)));
+        assert_eq!(grid.positions().nth(4), Some((1, 1)));
+        assert_eq!(grid.map(|d| d * 2)[(2, 0)], 6);
+    }
+
+    #[test]
+    fn find_neighbours() {
+        let grid = digits("123\n456\n789");
+
+        assert_eq!(
+            grid.neighbours((1, 1)).collect::<Vec<_>>(),
+            vec![(1, 0), (1, 2), (0, 1), (2, 1)]
+        );
+        assert_eq!(
+            grid.neighbours((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.all_neighbours((1, 1)).count(), 8);
+        assert_eq!(
+            grid.all_neighbours((2, 2)).collect::<Vec<_>>(),
+            vec![(1, 1), (2, 1), (1, 2)]
+        );
+    }
+
+    #[test]
+    fn transform_grid() {
+        let grid = digits("123\n456");
+
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "41\n52\n63\n");
+        assert_eq!(grid.rotate_anticlockwise().to_string(), "36\n25\n14\n");
+        assert_eq!(grid.rotate_clockwise().rotate_anticlockwise(), grid);
+        assert_eq!(Grid::new(2, 1, '.').to_string(), "..\n");
+    }
+}
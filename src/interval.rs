@@ -0,0 +1,300 @@
+use std::cmp::Ordering;
+
+/// A half-open range of integers, including its start but not its end. An interval whose end
+/// isn't after its start is empty.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    /// Creates the half-open interval from 'start' up to but not including 'end'.
+    ///
+    /// # Arguments
+    ///
+    /// 'start' - The first value in the interval.
+    ///
+    /// 'end' - The value just after the last value in the interval.
+    pub fn new(start: i64, end: i64) -> Interval {
+        Interval { start, end }
+    }
+
+    /// Creates the interval from 'first' up to and including 'last'.
+    ///
+    /// # Arguments
+    ///
+    /// 'first' - The first value in the interval.
+    ///
+    /// 'last' - The last value in the interval.
+    pub fn inclusive(first: i64, last: i64) -> Interval {
+        Interval {
+            start: first,
+            end: last + 1,
+        }
+    }
+
+    /// Returns the last value in the interval, for when it is used as an inclusive range.
+    pub fn last(&self) -> i64 {
+        self.end - 1
+    }
+
+    /// Returns the number of values in the interval.
+    pub fn len(&self) -> i64 {
+        (self.end - self.start).max(0)
+    }
+
+    /// Returns whether the interval contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    /// Returns whether the interval contains a value.
+    ///
+    /// # Arguments
+    ///
+    /// 'value' - The value to look for.
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Returns the interval with every value moved by the same amount.
+    ///
+    /// # Arguments
+    ///
+    /// 'offset' - The amount to add to each value.
+    pub fn shift(&self, offset: i64) -> Interval {
+        Interval::new(self.start + offset, self.end + offset)
+    }
+
+    /// Splits the interval into the values before a point and the values from the point onwards,
+    /// giving None for either part that would be empty.
+    ///
+    /// # Arguments
+    ///
+    /// 'point' - The first value of the second part.
+    pub fn split_at(&self, point: i64) -> (Option<Interval>, Option<Interval>) {
+        let point = point.clamp(self.start, self.end.max(self.start));
+        let before = Interval::new(self.start, point);
+        let after = Interval::new(point, self.end);
+
+        (
+            (!before.is_empty()).then_some(before),
+            (!after.is_empty()).then_some(after),
+        )
+    }
+
+    /// Returns the values found in both intervals, or None if they don't overlap.
+    ///
+    /// # Arguments
+    ///
+    /// 'other' - The interval to intersect with.
+    pub fn intersect(&self, other: &Interval) -> Option<Interval> {
+        let result = Interval::new(self.start.max(other.start), self.end.min(other.end));
+        (!result.is_empty()).then_some(result)
+    }
+
+    /// Returns the values in this interval that aren't in another, which may take up to two
+    /// intervals.
+    ///
+    /// # Arguments
+    ///
+    /// 'other' - The interval to remove.
+    pub fn subtract(&self, other: &Interval) -> Vec<Interval> {
+        if self.intersect(other).is_none() {
+            return if self.is_empty() { vec![] } else { vec![*self] };
+        }
+
+        [
+            Interval::new(self.start, other.start),
+            Interval::new(other.end, self.end),
+        ]
+        .into_iter()
+        .filter(|i| !i.is_empty())
+        .collect()
+    }
+}
+
+impl PartialOrd for Interval {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Interval {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.start, self.end).cmp(&(other.start, other.end))
+    }
+}
+
+/// Returns the union of a set of intervals as the fewest intervals possible, in order. Intervals
+/// that overlap or touch are merged, and empty intervals are dropped.
+///
+/// # Arguments
+///
+/// 'intervals' - The intervals to merge.
+pub fn merge(intervals: &[Interval]) -> Vec<Interval> {
+    let mut sorted: Vec<_> = intervals.iter().filter(|i| !i.is_empty()).collect();
+    sorted.sort();
+
+    let mut result: Vec<Interval> = vec![];
+
+    for interval in sorted {
+        match result.last_mut() {
+            Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+            _ => result.push(*interval),
+        }
+    }
+
+    result
+}
+
+/// A box in N dimensions, made from an interval along each axis.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Region<const N: usize> {
+    pub axes: [Interval; N],
+}
+
+impl<const N: usize> Region<N> {
+    /// Creates a region from its interval along each axis.
+    ///
+    /// # Arguments
+    ///
+    /// 'axes' - The interval along each axis.
+    pub fn new(axes: [Interval; N]) -> Region<N> {
+        Region { axes }
+    }
+
+    /// Returns the number of points in the region.
+    pub fn volume(&self) -> i64 {
+        self.axes.iter().map(Interval::len).product()
+    }
+
+    /// Returns whether the region contains no points.
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(Interval::is_empty)
+    }
+
+    /// Returns whether the region contains a point.
+    ///
+    /// # Arguments
+    ///
+    /// 'point' - The position of the point along each axis.
+    pub fn contains(&self, point: [i64; N]) -> bool {
+        self.axes.iter().zip(point).all(|(i, v)| i.contains(v))
+    }
+
+    /// Returns the points found in both regions, or None if they don't overlap.
+    ///
+    /// # Arguments
+    ///
+    /// 'other' - The region to intersect with.
+    pub fn intersect(&self, other: &Region<N>) -> Option<Region<N>> {
+        let mut axes = self.axes;
+
+        for (axis, other) in axes.iter_mut().zip(&other.axes) {
+            *axis = axis.intersect(other)?;
+        }
+
+        Some(Region { axes })
+    }
+
+    /// Splits the region in two along one axis, into the points before a value on that axis and
+    /// those from the value onwards, giving None for either part that would be empty.
+    ///
+    /// # Arguments
+    ///
+    /// 'axis' - The index of the axis to split along.
+    ///
+    /// 'point' - The first value along the axis of the second part.
+    pub fn split_at(&self, axis: usize, point: i64) -> (Option<Region<N>>, Option<Region<N>>) {
+        let (before, after) = self.axes[axis].split_at(point);
+        let with_axis = |interval| {
+            let mut axes = self.axes;
+            axes[axis] = interval;
+            Region { axes }
+        };
+
+        (before.map(with_axis), after.map(with_axis))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interval::*;
+
+    #[test]
+    fn measure_intervals() {
+        let interval = Interval::inclusive(3, 7);
+
+        assert_eq!(interval, Interval::new(3, 8));
+        assert_eq!(interval.last(), 7);
+        assert_eq!(interval.len(), 5);
+        assert!(interval.contains(7));
+        assert!(!interval.contains(8));
+        assert!(Interval::new(4, 4).is_empty());
+        assert_eq!(Interval::new(5, 2).len(), 0);
+        assert_eq!(interval.shift(-3), Interval::new(0, 5));
+    }
+
+    #[test]
+    fn split_intervals() {
+        let interval = Interval::new(3, 8);
+
+        assert_eq!(
+            interval.split_at(5),
+            (Some(Interval::new(3, 5)), Some(Interval::new(5, 8)))
+        );
+        assert_eq!(interval.split_at(3), (None, Some(interval)));
+        assert_eq!(interval.split_at(10), (Some(interval), None));
+        assert_eq!(interval.split_at(-10), (None, Some(interval)));
+    }
+
+    #[test]
+    fn combine_intervals() {
+        let a = Interval::new(0, 10);
+        let b = Interval::new(5, 15);
+
+        assert_eq!(a.intersect(&b), Some(Interval::new(5, 10)));
+        assert_eq!(a.intersect(&Interval::new(10, 12)), None);
+        assert_eq!(a.subtract(&b), vec![Interval::new(0, 5)]);
+        assert_eq!(
+            a.subtract(&Interval::new(3, 4)),
+            vec![Interval::new(0, 3), Interval::new(4, 10)]
+        );
+        assert_eq!(a.subtract(&Interval::new(-5, 20)), vec![]);
+        assert_eq!(a.subtract(&Interval::new(20, 30)), vec![a]);
+        assert_eq!(
+            merge(&[
+                Interval::new(12, 14),
+                b,
+                Interval::new(20, 20),
+                a,
+                Interval::new(15, 16),
+                Interval::new(30, 31),
+            ]),
+            vec![Interval::new(0, 16), Interval::new(30, 31)]
+        );
+    }
+
+    #[test]
+    fn split_regions() {
+        let region = Region::new([Interval::new(0, 10), Interval::new(0, 4)]);
+
+        assert_eq!(region.volume(), 40);
+        assert!(region.contains([9, 3]));
+        assert!(!region.contains([3, 9]));
+
+        let (before, after) = region.split_at(0, 3);
+        assert_eq!(before.map(|r| r.volume()), Some(12));
+        assert_eq!(after.map(|r| r.volume()), Some(28));
+        assert_eq!(region.split_at(1, 4), (Some(region), None));
+
+        let other = Region::new([Interval::new(5, 20), Interval::new(2, 3)]);
+        assert_eq!(
+            region.intersect(&other),
+            Some(Region::new([Interval::new(5, 10), Interval::new(2, 3)]))
+        );
+        assert!(Region::new([Interval::new(0, 0)]).is_empty());
+    }
+}
@@ -0,0 +1,213 @@
+use std::fmt::{Display, Formatter};
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// A flat JSON object that is written out on a single line, with fields in the order they were
+/// added.
+#[derive(Default)]
+pub struct JsonObject {
+    fields: Vec<(String, String)>,
+}
+
+impl JsonObject {
+    /// Creates an empty object.
+    pub fn new() -> JsonObject {
+        JsonObject::default()
+    }
+
+    /// Adds a string field to the object.
+    ///
+    /// # Arguments
+    ///
+    /// 'key' - The name of the field.
+    ///
+    /// 'value' - The string value, which will be quoted and escaped.
+    pub fn string(mut self, key: &str, value: &str) -> JsonObject {
+        self.fields.push((escape(key), escape(value)));
+        self
+    }
+
+    /// Adds a numeric field to the object.
+    ///
+    /// # Arguments
+    ///
+    /// 'key' - The name of the field.
+    ///
+    /// 'value' - The numeric value.
+    pub fn number<N: Into<u128>>(mut self, key: &str, value: N) -> JsonObject {
+        self.fields.push((escape(key), value.into().to_string()));
+        self
+    }
+
+    /// Parses an object with string and numeric fields, written on a single line as this module
+    /// writes them.
+    ///
+    /// # Arguments
+    ///
+    /// 'text' - The text of the object.
+    pub fn parse(text: &str) -> Result<JsonObject, String> {
+        let mut chars = text.trim().chars().peekable();
+        let mut object = JsonObject::new();
+
+        if chars.next() != Some('{') {
+            return Err(String::from("Expected '{' at the start of the object"));
+        }
+        if chars.next_if_eq(&'}').is_none() {
+            loop {
+                let key = read_string(&mut chars)?;
+                if chars.next() != Some(':') {
+                    return Err(format!("Expected ':' after \"{key}\""));
+                }
+
+                let value = if chars.peek() == Some(&'"') {
+                    escape(&read_string(&mut chars)?)
+                } else {
+                    let mut number = String::new();
+                    while let Some(c) = chars.next_if(|c| c.is_ascii_digit() || *c == '-') {
+                        number.push(c);
+                    }
+                    if number.is_empty() {
+                        return Err(format!("Expected a string or number for \"{key}\""));
+                    }
+                    number
+                };
+                object.fields.push((escape(&key), value));
+
+                match chars.next() {
+                    Some(',') => continue,
+                    Some('}') => break,
+                    _ => return Err(String::from("Expected ',' or '}' after a field")),
+                }
+            }
+        }
+
+        match chars.next() {
+            Some(c) => Err(format!("Unexpected '{c}' after the object")),
+            None => Ok(object),
+        }
+    }
+
+    /// Returns the value of a field: the text of a string, or the digits of a number.
+    ///
+    /// # Arguments
+    ///
+    /// 'key' - The name of the field.
+    pub fn get(&self, key: &str) -> Option<String> {
+        let key = escape(key);
+        let (_, value) = self.fields.iter().find(|(k, _)| *k == key)?;
+
+        if value.starts_with('"') {
+            read_string(&mut value.chars().peekable()).ok()
+        } else {
+            Some(value.clone())
+        }
+    }
+}
+
+impl Display for JsonObject {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{")?;
+        for (i, (key, value)) in self.fields.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{key}:{value}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+/// Returns the given text as a quoted JSON string, escaping any characters that need it.
+fn escape(text: &str) -> String {
+    let mut result = String::with_capacity(text.len() + 2);
+    result.push('"');
+
+    for c in text.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+
+    result.push('"');
+    result
+}
+
+/// Reads a quoted JSON string from the characters, returning the text with any escapes undone.
+fn read_string(chars: &mut Peekable<Chars>) -> Result<String, String> {
+    if chars.next() != Some('"') {
+        return Err(String::from("Expected a string"));
+    }
+
+    let mut result = String::new();
+    loop {
+        match chars.next().ok_or("Unterminated string")? {
+            '"' => return Ok(result),
+            '\\' => match chars.next().ok_or("Unterminated string")? {
+                'n' => result.push('\n'),
+                'r' => result.push('\r'),
+                't' => result.push('\t'),
+                'u' => {
+                    let code: String = chars.by_ref().take(4).collect();
+                    let c = u32::from_str_radix(&code, 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .ok_or(format!("Invalid escape '\\u{code}'"))?;
+                    result.push(c);
+                }
+                c => result.push(c),
+            },
+            c => result.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::json::*;
+
+    #[test]
+    fn escape_strings() {
+        assert_eq!(escape("abc"), "\"abc\"");
+        assert_eq!(escape("a \"b\" c"), "\"a \\\"b\\\" c\"");
+        assert_eq!(escape("a\\b\nc"), "\"a\\\\b\\nc\"");
+        assert_eq!(escape("\u{1}"), "\"\\u0001\"");
+    }
+
+    #[test]
+    fn write_object() {
+        assert_eq!(JsonObject::new().to_string(), "{}");
+        assert_eq!(
+            JsonObject::new()
+                .number("day", 5u32)
+                .string("part_one", "35")
+                .to_string(),
+            "{\"day\":5,\"part_one\":\"35\"}"
+        );
+    }
+
+    #[test]
+    fn read_object() {
+        let json = JsonObject::new()
+            .number("day", 5u32)
+            .string("error", "bad \"input\"\n\u{1}");
+        let parsed = JsonObject::parse(&json.to_string()).unwrap();
+
+        assert_eq!(parsed.to_string(), json.to_string());
+        assert_eq!(parsed.get("day"), Some(String::from("5")));
+        assert_eq!(
+            parsed.get("error"),
+            Some(String::from("bad \"input\"\n\u{1}"))
+        );
+        assert_eq!(parsed.get("part_one"), None);
+        assert_eq!(JsonObject::parse("{}").unwrap().to_string(), "{}");
+        assert!(JsonObject::parse("{\"day\":}").is_err());
+        assert!(JsonObject::parse("{\"day\":5").is_err());
+        assert!(JsonObject::parse("Compiling...").is_err());
+    }
+}
@@ -0,0 +1,18 @@
+pub mod answers;
+pub mod cycle;
+pub mod direction;
+pub mod error;
+pub mod fetch;
+pub mod generate;
+pub mod graph;
+pub mod grid;
+pub mod interval;
+pub mod json;
+pub mod parallel;
+pub mod runner;
+pub mod scaffold;
+pub mod solver;
+pub mod submit;
+pub mod visualize;
+pub mod watch;
+pub mod year2023;
@@ -1,199 +1,713 @@
-extern crate core;
-
-use num_format::{Locale, ToFormattedString};
-
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
-
+use advent_of_code_2023::answers::Answers;
+use advent_of_code_2023::fetch::{FetchConfig, Fetched, RateLimiter};
+use advent_of_code_2023::runner::{Format, RunConfig};
+use advent_of_code_2023::solver::Solver;
+use advent_of_code_2023::submit::{History, SubmitConfig, Verdict};
+use advent_of_code_2023::visualize::{VisualizeOptions, Visualizer};
+use advent_of_code_2023::watch::{Snapshot, Watcher};
+use advent_of_code_2023::{fetch, generate, runner, scaffold, solver, submit, visualize, watch};
 use std::env;
-use std::fs::read_to_string;
-use std::time::SystemTime;
+use std::path::{Path, PathBuf};
+use std::process::{self, Stdio};
+use std::thread;
+use std::time::Duration;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    let puzzle_number = if args.len() >= 2 {
-        args[1].parse().ok()
-    } else {
-        None
-    };
+/// The file containing the known answers for the real puzzle inputs.
+const ANSWERS_FILE: &str = "answers.toml";
 
-    let output1: String;
-    let output2: String;
+/// The file recording every answer submitted.
+const SUBMISSIONS_FILE: &str = "submissions.txt";
 
-    let start = SystemTime::now();
+/// The environment variable holding the session token used to download inputs and submit
+/// answers.
+const SESSION_VAR: &str = "AOC_SESSION";
 
-    match puzzle_number {
-        Some(1) => {
-            let input = read_input_file("input/day01.txt");
-            output1 = day01::part_one(&input).to_string();
-            output2 = day01::part_two(&input).to_string();
-        }
-        Some(2) => {
-            let input = read_input_file("input/day02.txt");
-            output1 = day02::part_one(&input).to_string();
-            output2 = day02::part_two(&input).to_string();
-        }
-        Some(3) => {
-            let input = read_input_file("input/day03.txt");
-            output1 = day03::part_one(&input).to_string();
-            output2 = day03::part_two(&input).to_string();
-        }
-        Some(4) => {
-            let input = read_input_file("input/day04.txt");
-            output1 = day04::part_one(&input).to_string();
-            output2 = day04::part_two(&input).to_string();
-        }
-        Some(5) => {
-            let input = read_input_file("input/day05.txt");
-            output1 = day05::part_one(&input).to_string();
-            output2 = day05::part_two(&input).to_string();
-        }
-        Some(6) => {
-            let input = read_input_file("input/day06.txt");
-            output1 = day06::part_one(&input).to_string();
-            output2 = day06::part_two(&input).to_string();
-        }
-        Some(7) => {
-            let input = read_input_file("input/day07.txt");
-            output1 = day07::part_one(&input).to_string();
-            output2 = day07::part_two(&input).to_string();
-        }
-        Some(8) => {
-            let input = read_input_file("input/day08.txt");
-            output1 = day08::part_one(&input).to_string();
-            output2 = day08::part_two(&input).to_string();
-        }
-        Some(9) => {
-            let input = read_input_file("input/day09.txt");
-            output1 = day09::part_one(&input).to_string();
-            output2 = day09::part_two(&input).to_string();
-        }
-        Some(10) => {
-            let input = read_input_file("input/day10.txt");
-            output1 = day10::part_one(&input).to_string();
-            output2 = day10::part_two(&input).to_string();
-        }
-        Some(11) => {
-            let input = read_input_file("input/day11.txt");
-            output1 = day11::part_one(&input).to_string();
-            output2 = day11::part_two(&input).to_string();
+/// The options given on the command line.
+struct Options {
+    days: Vec<String>,
+    check: bool,
+    config: RunConfig,
+    visualize: VisualizeOptions,
+}
+
+/// Parses the command line arguments (not including the program name) into a set of options.
+///
+/// # Arguments
+///
+/// * `args` - The command line arguments
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        days: vec![],
+        check: false,
+        config: RunConfig {
+            input: None,
+            repeat: 1,
+            format: Format::Text,
+        },
+        visualize: VisualizeOptions {
+            terminal: false,
+            delay: Duration::from_millis(50),
+            every: 1,
+            frames: None,
+        },
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--check" => options.check = true,
+            "--format" => {
+                options.config.format = match args.next().map(String::as_str) {
+                    Some("text") => Format::Text,
+                    Some("json") => Format::Json,
+                    Some(value) => return Err(format!("Unknown format '{value}'")),
+                    None => return Err(String::from("Missing value for --format")),
+                };
+            }
+            "--input" => {
+                let value = args.next().ok_or("Missing value for --input")?;
+                options.config.input = Some(value.clone());
+            }
+            "--repeat" => {
+                let value = args.next().ok_or("Missing value for --repeat")?;
+                options.config.repeat = value
+                    .parse()
+                    .ok()
+                    .filter(|n| *n > 0)
+                    .ok_or(format!("Invalid repeat count '{value}'"))?;
+            }
+            "--visualize" => options.visualize.terminal = true,
+            "--delay" => {
+                let value = args.next().ok_or("Missing value for --delay")?;
+                let millis = value
+                    .parse()
+                    .map_err(|_| format!("Invalid delay '{value}'"))?;
+                options.visualize.delay = Duration::from_millis(millis);
+            }
+            "--every" => {
+                let value = args.next().ok_or("Missing value for --every")?;
+                options.visualize.every = value
+                    .parse()
+                    .ok()
+                    .filter(|n| *n > 0)
+                    .ok_or(format!("Invalid step count '{value}'"))?;
+            }
+            "--frames" => {
+                let value = args.next().ok_or("Missing value for --frames")?;
+                options.visualize.frames = Some(PathBuf::from(value));
+            }
+            _ if arg.starts_with("--") => return Err(format!("Unknown option '{arg}'")),
+            _ => options.days.push(arg.clone()),
         }
-        Some(12) => {
-            let input = read_input_file("input/day12.txt");
-            output1 = day12::part_one(&input).to_string();
-            output2 = day12::part_two(&input).to_string();
+    }
+
+    Ok(options)
+}
+
+/// Splits the positional arguments naming a day into its year and the arguments that follow
+/// the day. The year may be left out, in which case it is the latest year with a solution.
+///
+/// # Arguments
+///
+/// * `args` - The positional arguments, starting with the optional year and the day
+/// * `count` - The number of arguments expected after the day
+fn parse_day(args: &[String], count: usize) -> Result<(u32, u32, &[String]), String> {
+    let (year, args) = match args.len() {
+        n if n == count + 2 => (
+            args[0]
+                .parse()
+                .map_err(|_| format!("Invalid year '{}'", args[0]))?,
+            &args[1..],
+        ),
+        n if n == count + 1 => (solver::latest().year(), args),
+        n if n > count + 2 => return Err(format!("Unexpected argument '{}'", args[count + 2])),
+        _ => return Err(String::from("Missing day")),
+    };
+    let day = args[0]
+        .parse()
+        .map_err(|_| format!("Invalid day '{}'", args[0]))?;
+
+    Ok((year, day, &args[1..]))
+}
+
+/// The options given to the generate subcommand.
+struct GenerateOptions {
+    year: u32,
+    day: u32,
+    size: usize,
+    seed: Option<u64>,
+}
+
+/// Parses the arguments of the generate subcommand (not including the subcommand itself).
+///
+/// # Arguments
+///
+/// * `args` - The arguments following `generate`
+fn parse_generate_args(args: &[String]) -> Result<GenerateOptions, String> {
+    let mut positional = vec![];
+    let mut size = None;
+    let mut seed = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--size" => {
+                let value = args.next().ok_or("Missing value for --size")?;
+                size = Some(
+                    value
+                        .parse()
+                        .ok()
+                        .filter(|n| *n > 0)
+                        .ok_or(format!("Invalid size '{value}'"))?,
+                );
+            }
+            "--seed" => {
+                let value = args.next().ok_or("Missing value for --seed")?;
+                seed = Some(
+                    value
+                        .parse()
+                        .map_err(|_| format!("Invalid seed '{value}'"))?,
+                );
+            }
+            _ if arg.starts_with("--") => return Err(format!("Unknown option '{arg}'")),
+            _ => positional.push(arg.clone()),
         }
-        Some(13) => {
-            let input = read_input_file("input/day13.txt");
-            output1 = day13::part_one(&input).to_string();
-            output2 = day13::part_two(&input).to_string();
+    }
+
+    if positional.is_empty() {
+        return Err(String::from("Missing day to generate an input for"));
+    }
+    let (year, day, _) = parse_day(&positional, 0)?;
+    let typical =
+        generate::typical_size(year, day).ok_or(format!("No generator for day {day} of {year}"))?;
+    let size = size.unwrap_or(typical);
+
+    Ok(GenerateOptions {
+        year,
+        day,
+        size,
+        seed,
+    })
+}
+
+/// Prints a generated input for a day, reporting the seed used so that it can be generated
+/// again.
+///
+/// # Arguments
+///
+/// * `args` - The arguments following `generate`
+fn run_generate(args: &[String]) {
+    let options = parse_generate_args(args).unwrap_or_else(|msg| {
+        eprintln!("{msg}");
+        process::exit(1);
+    });
+
+    let seed = options.seed.unwrap_or_else(rand::random);
+    let input =
+        generate::generate(options.year, options.day, options.size, seed).unwrap_or_else(|msg| {
+            eprintln!("{msg}");
+            process::exit(1);
+        });
+
+    eprintln!(
+        "Generating day {} of {} with size {} and seed {seed}",
+        options.day, options.year, options.size
+    );
+    print!("{input}");
+}
+
+/// Parses the arguments of the subcommands that talk to the site (not including the subcommand
+/// itself) into the settings for reaching the site and the remaining positional arguments.
+///
+/// # Arguments
+///
+/// * `args` - The arguments following the subcommand
+fn parse_site_args(args: &[String]) -> Result<(Vec<String>, FetchConfig), String> {
+    let mut positional = vec![];
+    let mut config = FetchConfig {
+        base_url: String::from(fetch::DEFAULT_BASE_URL),
+        session: env::var(SESSION_VAR).ok().filter(|s| !s.is_empty()),
+        input_dir: PathBuf::from(runner::INPUT_DIR),
+        interval: fetch::DEFAULT_INTERVAL,
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--url" => {
+                let value = args.next().ok_or("Missing value for --url")?;
+                config.base_url = value.clone();
+            }
+            "--session" => {
+                let value = args.next().ok_or("Missing value for --session")?;
+                config.session = Some(value.clone());
+            }
+            "--interval" => {
+                let value = args.next().ok_or("Missing value for --interval")?;
+                let millis = value
+                    .parse()
+                    .map_err(|_| format!("Invalid interval '{value}'"))?;
+                config.interval = Duration::from_millis(millis);
+            }
+            _ if arg.starts_with("--") => return Err(format!("Unknown option '{arg}'")),
+            _ => positional.push(arg.clone()),
         }
-        Some(14) => {
-            let input = read_input_file("input/day14.txt");
-            output1 = day14::part_one(&input).to_string();
-            output2 = day14::part_two(&input).to_string();
+    }
+
+    Ok((positional, config))
+}
+
+/// Parses the arguments of the fetch subcommand (not including the subcommand itself) into the
+/// days to fetch and the settings for fetching them.
+///
+/// # Arguments
+///
+/// * `args` - The arguments following `fetch`
+fn parse_fetch_args(args: &[String]) -> Result<(Vec<(u32, u32)>, FetchConfig), String> {
+    let (positional, config) = parse_site_args(args)?;
+
+    if positional.is_empty() {
+        return Err(String::from("Missing day to fetch the input for"));
+    }
+    let days = solver::select_args(&positional)?
+        .iter()
+        .map(|s| (s.year(), s.day()))
+        .collect();
+
+    Ok((days, config))
+}
+
+/// Downloads the inputs for the selected days into the input directory, skipping any that are
+/// already there.
+///
+/// # Arguments
+///
+/// * `args` - The arguments following `fetch`
+fn run_fetch(args: &[String]) {
+    let (days, config) = parse_fetch_args(args).unwrap_or_else(|msg| {
+        eprintln!("{msg}");
+        process::exit(1);
+    });
+
+    let mut succeeded = true;
+    for (year, day) in days {
+        match fetch::fetch_input(year, day, &config) {
+            Ok(Fetched::Downloaded(path)) => {
+                println!("Day {day} of {year}: saved to {}", path.display())
+            }
+            Ok(Fetched::Cached(path)) => {
+                println!("Day {day} of {year}: already in {}", path.display())
+            }
+            Err(msg) if config.session.is_none() => {
+                eprintln!("Day {day} of {year}: {msg}, set {SESSION_VAR} or pass --session");
+                process::exit(1);
+            }
+            Err(msg) => {
+                eprintln!("Day {day} of {year}: {msg}");
+                succeeded = false;
+            }
         }
-        Some(15) => {
-            let input = read_input_file("input/day15.txt");
-            output1 = day15::part_one(&input).to_string();
-            output2 = day15::part_two(&input).to_string();
+    }
+
+    if !succeeded {
+        process::exit(1);
+    }
+}
+
+/// Prints an error message and exits with a failure status.
+///
+/// # Arguments
+///
+/// * `msg` - The error message
+fn fail(msg: String) -> ! {
+    eprintln!("{msg}");
+    process::exit(1);
+}
+
+/// The options given to the submit subcommand.
+struct SubmitOptions {
+    solver: &'static dyn Solver,
+    part: u32,
+    config: SubmitConfig,
+}
+
+/// Parses the arguments of the submit subcommand (not including the subcommand itself).
+///
+/// # Arguments
+///
+/// * `args` - The arguments following `submit`
+fn parse_submit_args(args: &[String]) -> Result<SubmitOptions, String> {
+    let (positional, config) = parse_site_args(args)?;
+
+    if !(2..=3).contains(&positional.len()) {
+        return Err(String::from("Expected the day and part to submit"));
+    }
+    let (year, day, rest) = parse_day(&positional, 1)?;
+    let solver =
+        solver::find(year, day).ok_or(format!("No solution found for day {day} of {year}"))?;
+    let part = &rest[0];
+    let part = part
+        .parse()
+        .ok()
+        .filter(|part| matches!(part, 1 | 2))
+        .ok_or(format!("Invalid part '{part}'"))?;
+
+    let limiter = RateLimiter::new(config.input_dir.join(fetch::STAMP_FILE), config.interval);
+    let config = SubmitConfig {
+        base_url: config.base_url,
+        session: config.session,
+        limiter,
+    };
+
+    Ok(SubmitOptions {
+        solver,
+        part,
+        config,
+    })
+}
+
+/// Solves a part of a day and submits the answer, unless the earlier submissions show that it
+/// can't be right.
+///
+/// # Arguments
+///
+/// * `args` - The arguments following `submit`
+fn run_submit(args: &[String]) {
+    let options = parse_submit_args(args).unwrap_or_else(|msg| fail(msg));
+    let (year, day, part) = (options.solver.year(), options.solver.day(), options.part);
+
+    let filename = runner::input_path(year, day);
+    let input = runner::read_input_file(&filename)
+        .unwrap_or_else(|e| fail(format!("Unable to read {filename}: {e}")));
+    let answer = match part {
+        1 => options.solver.part_one(&input),
+        _ => options.solver.part_two(&input),
+    };
+    let answer = answer
+        .map(|answer| answer.to_string())
+        .unwrap_or_else(|e| fail(format!("Error in {}", e.with_day(day))));
+
+    let mut history = History::load(SUBMISSIONS_FILE).unwrap_or_else(|msg| fail(msg));
+    println!("Day {day} of {year} part {part}: submitting {answer}");
+
+    match submit::submit_answer(year, day, part, &answer, &options.config, &mut history) {
+        Ok(response) => {
+            println!("{}", response.message);
+            if response.verdict != Verdict::Right {
+                process::exit(1);
+            }
         }
-        Some(16) => {
-            let input = read_input_file("input/day16.txt");
-            output1 = day16::part_one(&input).to_string();
-            output2 = day16::part_two(&input).to_string();
+        Err(msg) if options.config.session.is_none() => {
+            fail(format!("{msg}, set {SESSION_VAR} or pass --session"))
         }
-        Some(17) => {
-            let input = read_input_file("input/day17.txt");
-            output1 = day17::part_one(&input).to_string();
-            output2 = day17::part_two(&input).to_string();
+        Err(msg) => fail(msg),
+    }
+}
+
+/// Parses the arguments of the new subcommand (not including the subcommand itself) into the
+/// year, day and puzzle title.
+///
+/// # Arguments
+///
+/// * `args` - The arguments following `new`
+fn parse_new_args(args: &[String]) -> Result<(u32, u32, String), String> {
+    let mut positional = vec![];
+    let mut title = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--title" => {
+                let value = args.next().ok_or("Missing value for --title")?;
+                title = Some(value.clone());
+            }
+            _ if arg.starts_with("--") => return Err(format!("Unknown option '{arg}'")),
+            _ => positional.push(arg),
         }
-        Some(18) => {
-            let input = read_input_file("input/day18.txt");
-            output1 = day18::part_one(&input).to_string();
-            output2 = day18::part_two(&input).to_string();
+    }
+
+    let [year, day] = positional[..] else {
+        return Err(String::from("Expected the year and day to create"));
+    };
+    let year = year.parse().map_err(|_| format!("Invalid year '{year}'"))?;
+    let day = day.parse().map_err(|_| format!("Invalid day '{day}'"))?;
+    let title = title.unwrap_or_else(|| format!("Day {day}"));
+
+    Ok((year, day, title))
+}
+
+/// Creates the module for a new day, along with its tests, and registers it.
+///
+/// # Arguments
+///
+/// * `args` - The arguments following `new`
+fn run_new(args: &[String]) {
+    let (year, day, title) = parse_new_args(args).unwrap_or_else(|msg| fail(msg));
+    let paths =
+        scaffold::scaffold(Path::new("."), year, day, &title).unwrap_or_else(|msg| fail(msg));
+
+    for path in paths {
+        println!("Wrote {}", path.display());
+    }
+    println!(
+        "Paste the sample input into the tests, then run `cargo test {}::{}`",
+        scaffold::year_module_name(year),
+        scaffold::module_name(day)
+    );
+}
+
+/// The options given to the watch subcommand.
+struct WatchOptions {
+    year: u32,
+    day: u32,
+    input: Option<String>,
+    repeat: usize,
+    interval: Duration,
+}
+
+/// Parses the arguments of the watch subcommand (not including the subcommand itself).
+///
+/// # Arguments
+///
+/// * `args` - The arguments following `watch`
+fn parse_watch_args(args: &[String]) -> Result<WatchOptions, String> {
+    let mut positional = vec![];
+    let mut input = None;
+    let mut repeat = 1;
+    let mut interval = watch::DEFAULT_INTERVAL;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => {
+                let value = args.next().ok_or("Missing value for --input")?;
+                if value == "-" {
+                    return Err(String::from("Standard input can't be watched"));
+                }
+                input = Some(value.clone());
+            }
+            "--repeat" => {
+                let value = args.next().ok_or("Missing value for --repeat")?;
+                repeat = value
+                    .parse()
+                    .ok()
+                    .filter(|n| *n > 0)
+                    .ok_or(format!("Invalid repeat count '{value}'"))?;
+            }
+            "--interval" => {
+                let value = args.next().ok_or("Missing value for --interval")?;
+                let millis = value
+                    .parse()
+                    .map_err(|_| format!("Invalid interval '{value}'"))?;
+                interval = Duration::from_millis(millis);
+            }
+            _ if arg.starts_with("--") => return Err(format!("Unknown option '{arg}'")),
+            _ => positional.push(arg.clone()),
         }
-        Some(19) => {
-            let input = read_input_file("input/day19.txt");
-            output1 = day19::part_one(&input).to_string();
-            output2 = day19::part_two(&input).to_string();
+    }
+
+    if positional.is_empty() {
+        return Err(String::from("Missing day to watch"));
+    }
+    let (year, day, _) = parse_day(&positional, 0)?;
+    solver::find(year, day).ok_or(format!("No solution found for day {day} of {year}"))?;
+
+    Ok(WatchOptions {
+        year,
+        day,
+        input,
+        repeat,
+        interval,
+    })
+}
+
+/// Runs the sample tests and then the solver for the watched day, printing the answers and how
+/// the timings compare with the previous run. The solver is run from a fresh build, so that
+/// changes to its source are picked up.
+///
+/// # Arguments
+///
+/// * `options` - The options given to the watch subcommand
+/// * `previous` - The result of the previous successful run, replaced by this one if it succeeds
+fn rerun(options: &WatchOptions, previous: &mut Option<Snapshot>) {
+    let (year, day) = (options.year, options.day);
+
+    println!("Running the sample tests for day {day} of {year}");
+    match watch::test_command(year, day).status() {
+        Ok(status) if status.success() => println!("Sample tests passed"),
+        Ok(_) => println!("Sample tests failed"),
+        Err(e) => eprintln!("Unable to run the sample tests: {e}"),
+    }
+
+    let output = watch::run_command(year, day, options.input.as_deref(), options.repeat)
+        .stderr(Stdio::inherit())
+        .output();
+    let output = match output {
+        Ok(output) => String::from_utf8_lossy(&output.stdout).into_owned(),
+        Err(e) => {
+            eprintln!("Unable to run day {day} of {year}: {e}");
+            return;
         }
-        Some(20) => {
-            let input = read_input_file("input/day20.txt");
-            output1 = day20::part_one(&input).to_string();
-            output2 = day20::part_two(&input).to_string();
+    };
+
+    match output.lines().last().map(Snapshot::parse) {
+        Some(Ok(snapshot)) => {
+            for line in snapshot.report(previous.as_ref()) {
+                println!("{line}");
+            }
+            *previous = Some(snapshot);
         }
-        Some(21) => {
-            let input = read_input_file("input/day21.txt");
-            output1 = day21::part_one(&input).to_string();
-            output2 = day21::part_two(&input).to_string();
+        Some(Err(msg)) => eprintln!("Error in day {day} of {year}: {msg}"),
+        None => eprintln!("Day {day} of {year} didn't run, see the errors above"),
+    }
+}
+
+/// Runs a day, then runs it again whenever its input or source file changes, until interrupted.
+///
+/// # Arguments
+///
+/// * `args` - The arguments following `watch`
+fn run_watch(args: &[String]) -> ! {
+    let options = parse_watch_args(args).unwrap_or_else(|msg| fail(msg));
+    let input = options
+        .input
+        .clone()
+        .unwrap_or_else(|| runner::input_path(options.year, options.day));
+    let source = watch::source_path(options.year, options.day);
+
+    let mut watcher = Watcher::new([PathBuf::from(&input), source.clone()]);
+    let mut previous = None;
+    println!(
+        "Watching {input} and {} for changes, press Ctrl-C to stop",
+        source.display()
+    );
+
+    loop {
+        rerun(&options, &mut previous);
+
+        let mut changed = vec![];
+        while changed.is_empty() {
+            thread::sleep(options.interval);
+            changed = watcher.changed();
         }
-        Some(22) => {
-            let input = read_input_file("input/day22.txt");
-            output1 = day22::part_one(&input).to_string();
-            output2 = day22::part_two(&input).to_string();
+        // Editors often save a file in several steps, so let it settle before running again
+        thread::sleep(options.interval);
+        watcher.changed();
+
+        println!();
+        for path in changed {
+            println!("{} changed", path.display());
         }
-        Some(23) => {
-            let input = read_input_file("input/day23.txt");
-            output1 = day23::part_one(&input).to_string();
-            output2 = day23::part_two(&input).to_string();
+    }
+}
+
+/// Steps through a day's solution, drawing it in the terminal or saving its frames as the options
+/// ask. Returns false if the day couldn't be visualized.
+///
+/// # Arguments
+///
+/// * `year` - The year of the day to visualize
+/// * `day` - The day to visualize
+/// * `options` - The options given on the command line
+fn run_visualize(year: u32, day: u32, options: &Options) -> bool {
+    let source = options.config.input_source(year, day);
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Unable to read {source}: {e}");
+            return false;
         }
-        Some(24) => {
-            let input = read_input_file("input/day24.txt");
-            output1 = day24::part_one(&input).to_string();
-            output2 = day24::part_two(&input).to_string();
+    };
+
+    let mut visualizer = Visualizer::new(options.visualize.clone());
+    match visualize::visualize(year, day, &input, &mut visualizer) {
+        Some(Ok(())) => true,
+        Some(Err(e)) => {
+            eprintln!("Error in {e}");
+            false
         }
-        _ => {
-            let input = read_input_file("input/day25.txt");
-            output1 = day25::part_one(&input).to_string();
-            output2 = day25::part_two(&input).to_string();
+        None => {
+            let days: Vec<_> = visualize::days()
+                .map(|(year, day)| format!("{day} of {year}"))
+                .collect();
+            eprintln!(
+                "Day {day} of {year} can't be visualized, only days {}",
+                days.join(", ")
+            );
+            false
         }
     }
+}
 
-    let elapsed = start.elapsed();
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
 
-    println!("Part one output: {output1}");
-    println!("Part two output: {output2}");
+    if args.first().map(String::as_str) == Some("generate") {
+        run_generate(&args[1..]);
+        return;
+    }
 
-    if let Ok(time) = elapsed {
-        println!(
-            "Total elapsed time: {} ns",
-            time.as_nanos().to_formatted_string(&Locale::en)
-        );
+    if args.first().map(String::as_str) == Some("fetch") {
+        run_fetch(&args[1..]);
+        return;
     }
-}
 
-/// Returns the contents of the given file, with any '\r' characters stripped out so we don't
-/// have to worry about them when running on a Windows system.
-///
-/// # Arguments
-///
-/// * `filename` - The name of the file to read
-fn read_input_file(filename: &str) -> String {
-    let mut input = read_to_string(filename).unwrap();
-    input.retain(|c| c != '\r');
-    input
+    if args.first().map(String::as_str) == Some("submit") {
+        run_submit(&args[1..]);
+        return;
+    }
+
+    if args.first().map(String::as_str) == Some("new") {
+        run_new(&args[1..]);
+        return;
+    }
+
+    if args.first().map(String::as_str) == Some("watch") {
+        run_watch(&args[1..]);
+    }
+
+    let options = parse_args(&args).unwrap_or_else(|msg| {
+        eprintln!("{msg}");
+        process::exit(1);
+    });
+
+    let solvers = if options.days.is_empty() && options.check {
+        solver::SOLVERS.to_vec()
+    } else {
+        solver::select_args(&options.days).unwrap_or_else(|msg| {
+            eprintln!("{msg}");
+            process::exit(1);
+        })
+    };
+
+    if options.config.input.is_some() && solvers.len() > 1 {
+        eprintln!("A custom input can only be used when running a single day");
+        process::exit(1);
+    }
+
+    let visualizing = options.visualize.terminal || options.visualize.frames.is_some();
+
+    let succeeded = if visualizing {
+        let [solver] = solvers[..] else {
+            eprintln!("Only a single day can be visualized");
+            process::exit(1);
+        };
+        run_visualize(solver.year(), solver.day(), &options)
+    } else if options.check {
+        let answers = Answers::load(ANSWERS_FILE).unwrap_or_else(|msg| {
+            eprintln!("{msg}");
+            process::exit(1);
+        });
+        runner::run_check(&solvers, &options.config, &answers)
+    } else if options.config.format == Format::Json {
+        runner::run_json(&solvers, &options.config)
+    } else if let [solver] = solvers[..] {
+        runner::run_single(solver, &options.config)
+    } else {
+        runner::run_many(&solvers, &options.config)
+    };
+
+    if !succeeded {
+        process::exit(1);
+    }
 }
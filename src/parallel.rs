@@ -0,0 +1,119 @@
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Applies a function to every item, returning the results in the same order as the items. The
+/// items are spread across rayon's thread pool when the `parallel` feature is enabled, and are
+/// otherwise worked on one at a time, with the same results either way.
+///
+/// # Arguments
+///
+/// 'items' - The items to work on.
+///
+/// 'f' - The function to apply to each item.
+pub fn map<T, R, F>(items: Vec<T>, f: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    return items.into_par_iter().map(f).collect();
+
+    #[cfg(not(feature = "parallel"))]
+    return items.into_iter().map(f).collect();
+}
+
+/// Applies a function to every item, along with some working state that the function may change
+/// but must leave ready for the next item. Each thread gets its own state from 'init', so there
+/// is only one state when running on a single thread. Returns the results in the same order as
+/// the items.
+///
+/// # Arguments
+///
+/// 'items' - The items to work on.
+///
+/// 'init' - The function that creates the working state.
+///
+/// 'f' - The function to apply to each item.
+pub fn map_with<T, S, R, I, F>(items: Vec<T>, init: I, f: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    I: Fn() -> S + Sync + Send,
+    F: Fn(&mut S, T) -> R + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    return items.into_par_iter().map_init(init, f).collect();
+
+    #[cfg(not(feature = "parallel"))]
+    {
+        let mut state = init();
+        items.into_iter().map(|item| f(&mut state, item)).collect()
+    }
+}
+
+/// Repeats a randomized trial until one of them succeeds, and returns its result, or None if
+/// none of the allowed number of trials succeeded. When running on several threads, whichever
+/// successful trial finishes first wins, so the trial must only succeed when its result is the
+/// one being looked for.
+///
+/// # Arguments
+///
+/// 'limit' - The most trials to run.
+///
+/// 'trial' - The function that runs one trial, returning None if it was unsuccessful.
+pub fn repeat_until<R, F>(limit: usize, trial: F) -> Option<R>
+where
+    R: Send,
+    F: Fn() -> Option<R> + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    return (0..limit).into_par_iter().find_map_any(|_| trial());
+
+    #[cfg(not(feature = "parallel"))]
+    return (0..limit).find_map(|_| trial());
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parallel::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn results_keep_their_order() {
+        let items: Vec<u64> = (0..1000).collect();
+
+        assert_eq!(
+            map(items.clone(), |n| n * n),
+            items.iter().map(|n| n * n).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            map_with(items.clone(), Vec::new, |seen, n| {
+                seen.push(n);
+                n + 1
+            }),
+            (1..=1000).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn repeat_until_success() {
+        let trials = AtomicUsize::new(0);
+        let result = repeat_until(1000, || {
+            let count = trials.fetch_add(1, Ordering::Relaxed);
+            (count >= 10).then_some("found")
+        });
+
+        assert_eq!(result, Some("found"));
+        assert!(trials.load(Ordering::Relaxed) > 10);
+
+        let trials = AtomicUsize::new(0);
+        let result = repeat_until(50, || {
+            trials.fetch_add(1, Ordering::Relaxed);
+            None::<u32>
+        });
+
+        assert_eq!(result, None);
+        assert_eq!(trials.load(Ordering::Relaxed), 50);
+    }
+}
@@ -0,0 +1,619 @@
+use crate::answers::{Answers, Status};
+use crate::error::SolveError;
+use crate::json::JsonObject;
+use crate::parallel;
+use crate::solver::Solver;
+use num_format::{Locale, ToFormattedString};
+use std::fmt::{Display, Formatter};
+use std::fs::read_to_string;
+use std::io;
+use std::io::Read;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// The format used to print results.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+/// The settings shared by all the ways of running days.
+pub struct RunConfig {
+    /// A custom input to use instead of the day's input file, where "-" means standard input.
+    pub input: Option<String>,
+    /// The number of times to run each day.
+    pub repeat: usize,
+    /// The format used to print results.
+    pub format: Format,
+}
+
+impl RunConfig {
+    /// Returns where the input for the given day should be read from.
+    ///
+    /// # Arguments
+    ///
+    /// 'year' - The year of the calendar.
+    ///
+    /// 'day' - The day of the calendar.
+    pub fn input_source(&self, year: u32, day: u32) -> InputSource {
+        match self.input.as_deref() {
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(path.to_string()),
+            None => InputSource::File(input_path(year, day)),
+        }
+    }
+}
+
+/// The directory holding the puzzle input files, with a directory inside it for each year.
+pub const INPUT_DIR: &str = "input";
+
+/// Returns the name of the input file for the given day, within its year's input directory.
+///
+/// # Arguments
+///
+/// 'day' - The day of the calendar.
+pub fn input_file_name(day: u32) -> String {
+    format!("day{day:02}.txt")
+}
+
+/// Returns the path of the input file for the given day, such as "input/2023/day05.txt".
+///
+/// # Arguments
+///
+/// 'year' - The year of the calendar.
+///
+/// 'day' - The day of the calendar.
+pub fn input_path(year: u32, day: u32) -> String {
+    format!("{INPUT_DIR}/{year}/{}", input_file_name(day))
+}
+
+/// Where the puzzle input for a run is read from.
+pub enum InputSource {
+    File(String),
+    Stdin,
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::File(filename) => write!(f, "{filename}"),
+            InputSource::Stdin => write!(f, "standard input"),
+        }
+    }
+}
+
+impl InputSource {
+    /// Reads the puzzle input from this source.
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            InputSource::File(filename) => read_input_file(filename),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                input.retain(|c| c != '\r');
+                Ok(input)
+            }
+        }
+    }
+
+    /// Returns true if the input is a file that doesn't exist.
+    pub fn is_missing(&self) -> bool {
+        matches!(self, InputSource::File(filename) if !Path::new(filename).exists())
+    }
+
+    /// Returns true if the input can be read more than once.
+    fn is_rereadable(&self) -> bool {
+        matches!(self, InputSource::File(_))
+    }
+}
+
+/// The durations measured for one stage of a run, with one sample per repetition.
+pub struct Timing {
+    samples: Vec<Duration>,
+}
+
+impl Timing {
+    /// Creates a timing from the given samples, which must not be empty.
+    fn new(mut samples: Vec<Duration>) -> Timing {
+        assert!(!samples.is_empty());
+        samples.sort();
+        Timing { samples }
+    }
+
+    /// Returns the number of samples that were taken.
+    pub fn runs(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Returns the shortest of the sampled durations.
+    pub fn fastest(&self) -> Duration {
+        self.samples[0]
+    }
+
+    /// Returns the median of the sampled durations.
+    pub fn median(&self) -> Duration {
+        let mid = self.samples.len() / 2;
+        if self.samples.len().is_multiple_of(2) {
+            (self.samples[mid - 1] + self.samples[mid]) / 2
+        } else {
+            self.samples[mid]
+        }
+    }
+}
+
+/// The answers and timings from running both parts of a single day.
+pub struct DayResult {
+    pub year: u32,
+    pub day: u32,
+    pub part_one: String,
+    pub part_two: String,
+    pub read_time: Timing,
+    pub part_one_time: Timing,
+    pub part_two_time: Timing,
+}
+
+impl DayResult {
+    /// Returns the combined median time taken to read the input and solve both parts.
+    pub fn total_time(&self) -> Duration {
+        self.read_time.median() + self.part_one_time.median() + self.part_two_time.median()
+    }
+}
+
+/// Reads the input and runs both parts of the given solver against it, timing each stage
+/// separately. Every stage is repeated the given number of times so the timings can be compared
+/// across runs. Since standard input can only be read once, repeated runs against it reuse the
+/// input from the first read.
+///
+/// # Arguments
+///
+/// 'solver' - The solver to run.
+///
+/// 'source' - Where to read the input from.
+///
+/// 'repeat' - The number of times to run each stage (at least one).
+pub fn run(
+    solver: &dyn Solver,
+    source: &InputSource,
+    repeat: usize,
+) -> Result<DayResult, SolveError> {
+    let mut read_samples = vec![];
+    let mut part_one_samples = vec![];
+    let mut part_two_samples = vec![];
+
+    let mut input = None;
+    let mut answers = None;
+
+    for _ in 0..repeat.max(1) {
+        if input.is_none() || source.is_rereadable() {
+            let start = Instant::now();
+            let text = source.read().map_err(|e| {
+                SolveError::new(format!("Unable to read {source}: {e}")).with_day(solver.day())
+            })?;
+            read_samples.push(start.elapsed());
+            input = Some(text);
+        }
+        let input = input.as_deref().unwrap();
+
+        let start = Instant::now();
+        let part_one = solver.part_one(input)?;
+        part_one_samples.push(start.elapsed());
+
+        let start = Instant::now();
+        let part_two = solver.part_two(input)?;
+        part_two_samples.push(start.elapsed());
+
+        answers.get_or_insert_with(|| (part_one.to_string(), part_two.to_string()));
+    }
+
+    let (part_one, part_two) = answers.unwrap();
+
+    Ok(DayResult {
+        year: solver.year(),
+        day: solver.day(),
+        part_one,
+        part_two,
+        read_time: Timing::new(read_samples),
+        part_one_time: Timing::new(part_one_samples),
+        part_two_time: Timing::new(part_two_samples),
+    })
+}
+
+/// Returns the contents of the given file, with any '\r' characters stripped out so we don't
+/// have to worry about them when running on a Windows system.
+///
+/// # Arguments
+///
+/// * `filename` - The name of the file to read
+pub fn read_input_file(filename: &str) -> io::Result<String> {
+    let mut input = read_to_string(filename)?;
+    input.retain(|c| c != '\r');
+    Ok(input)
+}
+
+/// Formats a duration as a number of nanoseconds, with digits grouped for readability.
+pub fn format_nanos(time: Duration) -> String {
+    time.as_nanos().to_formatted_string(&Locale::en)
+}
+
+/// Formats a timing for display, showing the fastest and median durations if more than one
+/// sample was taken.
+pub fn format_timing(timing: &Timing) -> String {
+    if timing.runs() == 1 {
+        format!("{} ns", format_nanos(timing.median()))
+    } else {
+        format!(
+            "{} ns fastest, {} ns median of {} runs",
+            format_nanos(timing.fastest()),
+            format_nanos(timing.median()),
+            timing.runs()
+        )
+    }
+}
+
+/// Prints the answers and timings for a single day.
+///
+/// # Arguments
+///
+/// 'solver' - The solver that produced the result.
+///
+/// 'result' - The result to print.
+pub fn print_result(solver: &dyn Solver, result: &DayResult) {
+    println!(
+        "Day {} of {}: {}",
+        solver.day(),
+        solver.year(),
+        solver.title()
+    );
+    println!("Part one output: {}", result.part_one);
+    println!("Part two output: {}", result.part_two);
+    println!("Input read time: {}", format_timing(&result.read_time));
+    println!("Part one time: {}", format_timing(&result.part_one_time));
+    println!("Part two time: {}", format_timing(&result.part_two_time));
+    println!(
+        "Total elapsed time: {} ns",
+        format_nanos(result.total_time())
+    );
+}
+
+/// Runs a single day and prints its answers along with the time taken. Returns false if the day
+/// couldn't be run.
+///
+/// # Arguments
+///
+/// 'solver' - The solver for the day to run.
+///
+/// 'config' - The settings for the run.
+pub fn run_single(solver: &dyn Solver, config: &RunConfig) -> bool {
+    match run(
+        solver,
+        &config.input_source(solver.year(), solver.day()),
+        config.repeat,
+    ) {
+        Ok(result) => {
+            print_result(solver, &result);
+            true
+        }
+        Err(e) => {
+            eprintln!("Error in {e}");
+            false
+        }
+    }
+}
+
+/// Runs each of the given days, returning their results in the same order as the solvers. The
+/// days run concurrently when the `parallel` feature is enabled, which gets through them sooner
+/// but makes each day's timings less reliable.
+///
+/// # Arguments
+///
+/// 'solvers' - The solvers for the days to run.
+///
+/// 'config' - The settings for the run.
+pub fn run_all(solvers: &[&dyn Solver], config: &RunConfig) -> Vec<Result<DayResult, SolveError>> {
+    parallel::map(solvers.to_vec(), |solver| {
+        run(
+            solver,
+            &config.input_source(solver.year(), solver.day()),
+            config.repeat,
+        )
+    })
+}
+
+/// Runs each of the given days and prints a summary table of the results. Days that fail to run
+/// are left out of the table, with the error printed. Returns false if any day failed for a
+/// reason other than its input file not being there.
+///
+/// # Arguments
+///
+/// 'solvers' - The solvers for the days to run.
+///
+/// 'config' - The settings for the run.
+pub fn run_many(solvers: &[&dyn Solver], config: &RunConfig) -> bool {
+    let mut results = vec![];
+    let mut succeeded = true;
+
+    for (solver, result) in solvers.iter().zip(run_all(solvers, config)) {
+        match result {
+            Ok(result) => results.push(result),
+            Err(e)
+                if config
+                    .input_source(solver.year(), solver.day())
+                    .is_missing() =>
+            {
+                eprintln!("Skipping {e}")
+            }
+            Err(e) => {
+                eprintln!("Error in {e}");
+                succeeded = false;
+            }
+        }
+    }
+
+    print_table(&results);
+    succeeded
+}
+
+/// Runs each of the given days and prints one JSON object per line for each of them, in order.
+/// Days that can't be run are reported with an error, in which case false is returned.
+///
+/// # Arguments
+///
+/// 'solvers' - The solvers for the days to run.
+///
+/// 'config' - The settings for the run.
+pub fn run_json(solvers: &[&dyn Solver], config: &RunConfig) -> bool {
+    let mut succeeded = true;
+
+    for (solver, result) in solvers.iter().zip(run_all(solvers, config)) {
+        match result {
+            Ok(result) => println!("{}", to_json(&result)),
+            Err(e) => {
+                println!("{}", error_to_json(solver.year(), solver.day(), &e));
+                succeeded = false;
+            }
+        }
+    }
+
+    succeeded
+}
+
+/// Runs each of the given days and checks the answers against the known answers, returning false
+/// if any answer is wrong. A day that fails to run fails each of its parts that has a known
+/// answer, and is skipped if it has none.
+///
+/// # Arguments
+///
+/// 'solvers' - The solvers for the days to check.
+///
+/// 'config' - The settings for the run.
+///
+/// 'answers' - The known answers to check against.
+pub fn run_check(solvers: &[&dyn Solver], config: &RunConfig, answers: &Answers) -> bool {
+    let mut counts = [0; 3];
+
+    for (solver, result) in solvers.iter().zip(run_all(solvers, config)) {
+        let result = match result {
+            Ok(result) => result,
+            Err(e) => {
+                let (year, day) = (solver.year(), solver.day());
+                let known: Vec<u32> = [1, 2]
+                    .into_iter()
+                    .filter(|part| answers.get(year, day, *part).is_some())
+                    .collect();
+
+                if config.format == Format::Json {
+                    println!("{}", error_to_json(year, day, &e));
+                } else if known.is_empty() {
+                    eprintln!("Skipping {e}");
+                }
+                if config.format == Format::Text {
+                    for part in &known {
+                        println!("Day {day:>2} of {year} part {part}: FAIL (error in {e})");
+                    }
+                }
+
+                counts[1] += known.len();
+                continue;
+            }
+        };
+
+        let mut json = to_json(&result);
+
+        for (part, actual) in [(1, &result.part_one), (2, &result.part_two)] {
+            let status = answers.check(result.year, result.day, part, actual);
+
+            if config.format == Format::Json {
+                let key = if part == 1 { "part_one" } else { "part_two" };
+                json = json.string(&format!("{key}_status"), &status.to_string());
+            } else {
+                let detail = match &status {
+                    Status::Fail(expected) => format!("expected {expected}, got {actual}"),
+                    _ => actual.clone(),
+                };
+                println!(
+                    "Day {:>2} of {} part {part}: {status} ({detail})",
+                    result.day, result.year
+                );
+            }
+
+            counts[match status {
+                Status::Pass => 0,
+                Status::Fail(_) => 1,
+                Status::Unknown => 2,
+            }] += 1;
+        }
+
+        if config.format == Format::Json {
+            println!("{json}");
+        }
+    }
+
+    if config.format == Format::Text {
+        println!();
+        println!(
+            "{} passed, {} failed, {} unknown",
+            counts[0], counts[1], counts[2]
+        );
+    }
+
+    counts[1] == 0
+}
+
+/// Returns a JSON object describing the given result, with each time given as the median number
+/// of nanoseconds taken.
+///
+/// # Arguments
+///
+/// 'result' - The result to describe.
+pub fn to_json(result: &DayResult) -> JsonObject {
+    JsonObject::new()
+        .number("year", result.year)
+        .number("day", result.day)
+        .string("part_one", &result.part_one)
+        .string("part_two", &result.part_two)
+        .number("read_ns", result.read_time.median().as_nanos())
+        .number("part_one_ns", result.part_one_time.median().as_nanos())
+        .number("part_two_ns", result.part_two_time.median().as_nanos())
+        .number("runs", result.part_one_time.runs() as u64)
+}
+
+/// Returns a JSON object describing a day that could not be run, including the line and column of
+/// the input that caused the error when they are known.
+///
+/// # Arguments
+///
+/// 'year' - The year of the calendar.
+///
+/// 'day' - The day of the calendar.
+///
+/// 'error' - The error that stopped the day from running.
+pub fn error_to_json(year: u32, day: u32, error: &SolveError) -> JsonObject {
+    let json = JsonObject::new().number("year", year).number("day", day);
+    let json = match error.line {
+        Some(line) => json.number("line", line as u64),
+        None => json,
+    };
+    let json = match error.column {
+        Some(column) => json.number("column", column as u64),
+        None => json,
+    };
+    json.string("error", &error.message)
+}
+
+/// Prints a summary table of the given results, including a grand total of the time taken. When
+/// more than one run was made, each time is shown as the fastest and median of those runs.
+///
+/// # Arguments
+///
+/// 'results' - The results to print, one row per day.
+pub fn print_table(results: &[DayResult]) {
+    let total: Duration = results.iter().map(DayResult::total_time).sum();
+
+    let cell = |timing: &Timing| {
+        if timing.runs() == 1 {
+            format_nanos(timing.median())
+        } else {
+            format!(
+                "{} / {}",
+                format_nanos(timing.fastest()),
+                format_nanos(timing.median())
+            )
+        }
+    };
+
+    let rows: Vec<[String; 7]> = results
+        .iter()
+        .map(|r| {
+            [
+                r.year.to_string(),
+                r.day.to_string(),
+                r.part_one.clone(),
+                r.part_two.clone(),
+                cell(&r.read_time),
+                cell(&r.part_one_time),
+                cell(&r.part_two_time),
+            ]
+        })
+        .collect();
+
+    let header = [
+        "Year",
+        "Day",
+        "Part one",
+        "Part two",
+        "Read (ns)",
+        "Part one (ns)",
+        "Part two (ns)",
+    ];
+
+    let mut widths = header.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    print_row(&header, &widths);
+
+    let separator: Vec<_> = widths.iter().map(|w| "-".repeat(*w)).collect();
+    println!("{}", separator.join("-+-"));
+
+    for row in &rows {
+        print_row(row, &widths);
+    }
+
+    println!();
+    if let Some(runs) = results
+        .first()
+        .map(|r| r.part_one_time.runs())
+        .filter(|n| *n > 1)
+    {
+        println!("Times are shown as fastest / median of {runs} runs.");
+    }
+    println!("Total elapsed time: {} ns", format_nanos(total));
+}
+
+/// Prints a single row of the summary table, padding each cell to the width of its column.
+fn print_row<S: AsRef<str>>(cells: &[S; 7], widths: &[usize; 7]) {
+    println!(
+        "{:>w0$} | {:>w1$} | {:<w2$} | {:<w3$} | {:>w4$} | {:>w5$} | {:>w6$}",
+        cells[0].as_ref(),
+        cells[1].as_ref(),
+        cells[2].as_ref(),
+        cells[3].as_ref(),
+        cells[4].as_ref(),
+        cells[5].as_ref(),
+        cells[6].as_ref(),
+        w0 = widths[0],
+        w1 = widths[1],
+        w2 = widths[2],
+        w3 = widths[3],
+        w4 = widths[4],
+        w5 = widths[5],
+        w6 = widths[6],
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::runner::*;
+
+    #[test]
+    fn fastest_and_median_timing() {
+        let ms = Duration::from_millis;
+
+        let timing = Timing::new(vec![ms(5), ms(1), ms(3)]);
+        assert_eq!(timing.runs(), 3);
+        assert_eq!(timing.fastest(), ms(1));
+        assert_eq!(timing.median(), ms(3));
+
+        let timing = Timing::new(vec![ms(8), ms(2), ms(4), ms(6)]);
+        assert_eq!(timing.fastest(), ms(2));
+        assert_eq!(timing.median(), ms(5));
+
+        let timing = Timing::new(vec![ms(7)]);
+        assert_eq!(timing.fastest(), ms(7));
+        assert_eq!(timing.median(), ms(7));
+    }
+}
@@ -0,0 +1,388 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The line that opens the list of registered solvers in the registry.
+const REGISTRY_START: &str = "pub static SOLVERS: &[&dyn Solver] = &[";
+
+/// The first year of the calendar.
+const FIRST_YEAR: u32 = 2015;
+
+/// Returns the name of the module for the given day, within its year's module.
+///
+/// # Arguments
+///
+/// 'day' - The day of the calendar.
+pub fn module_name(day: u32) -> String {
+    format!("day{day:02}")
+}
+
+/// Returns the name of the module holding the days of the given year.
+///
+/// # Arguments
+///
+/// 'year' - The year of the calendar.
+pub fn year_module_name(year: u32) -> String {
+    format!("year{year}")
+}
+
+/// Returns the source of a new day module, with empty solutions for both parts and tests
+/// waiting for the sample input from the puzzle.
+///
+/// # Arguments
+///
+/// 'year' - The year of the calendar.
+///
+/// 'day' - The day of the calendar.
+pub fn module_source(year: u32, day: u32) -> String {
+    let module = format!("{}::{}", year_module_name(year), module_name(day));
+
+    format!(
+        r#"use crate::error::SolveError;
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_one(input: &str) -> Result<u64, SolveError> {{
+    Err(SolveError::new(format!(
+        "Part one isn't solved yet ({{}} lines of input)",
+        input.lines().count()
+    )))
+}}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_two(input: &str) -> Result<u64, SolveError> {{
+    Err(SolveError::new(format!(
+        "Part two isn't solved yet ({{}} lines of input)",
+        input.lines().count()
+    )))
+}}
+
+#[cfg(test)]
+mod tests {{
+    use crate::{module}::*;
+
+    #[test]
+    fn part_one_correct() {{
+        assert_eq!(part_one("TODO: paste the sample input here"), Ok(0));
+    }}
+
+    #[test]
+    fn part_two_correct() {{
+        assert_eq!(part_two("TODO: paste the sample input here"), Ok(0));
+    }}
+}}
+"#
+    )
+}
+
+/// Returns the source of a module with a declaration for the given submodule added among the
+/// others, in alphabetical order.
+///
+/// # Arguments
+///
+/// 'source' - The source of the module.
+///
+/// 'name' - The name of the submodule.
+pub fn add_module(source: &str, name: &str) -> Result<String, String> {
+    let declaration = format!("pub mod {name};");
+    if source.lines().any(|line| line.trim() == declaration) {
+        return Err(format!("The {name} module is already declared"));
+    }
+
+    let mut lines: Vec<&str> = source.lines().collect();
+    let modules: Vec<usize> = (0..lines.len())
+        .filter(|i| lines[*i].starts_with("pub mod "))
+        .collect();
+    let position = match modules.iter().find(|i| lines[**i] > declaration.as_str()) {
+        Some(i) => *i,
+        None => modules.last().map_or(lines.len(), |i| i + 1),
+    };
+
+    lines.insert(position, &declaration);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Returns the registry with an entry for the day added among the others, in calendar order.
+///
+/// # Arguments
+///
+/// 'registry' - The source of the solver registry.
+///
+/// 'year' - The year of the calendar.
+///
+/// 'day' - The day of the calendar.
+///
+/// 'title' - The title of the puzzle.
+pub fn add_registry_entry(
+    registry: &str,
+    year: u32,
+    day: u32,
+    title: &str,
+) -> Result<String, String> {
+    let start = registry
+        .find(REGISTRY_START)
+        .ok_or("Unable to find the list of solvers in the registry")?
+        + REGISTRY_START.len();
+    let end = start
+        + registry[start..]
+            .find("\n];")
+            .ok_or("Unable to find the end of the list of solvers in the registry")?;
+
+    // Each entry starts with "day!(" followed by its year and day, possibly on the next lines
+    let mut position = end + 1;
+    for (offset, _) in registry[start..end].match_indices("day!(") {
+        let entry = start + offset;
+        let numbers: Vec<u32> = registry[entry + 5..]
+            .split(',')
+            .take(2)
+            .filter_map(|number| number.trim().parse().ok())
+            .collect();
+
+        match numbers[..] {
+            [y, d] if (y, d) == (year, day) => {
+                return Err(format!("Day {day} of {year} is already registered"));
+            }
+            [y, d] if (y, d) > (year, day) => {
+                position = registry[..entry].rfind('\n').map_or(0, |i| i + 1);
+                break;
+            }
+            _ => {}
+        }
+    }
+
+    let title = title.replace('\\', "\\\\").replace('"', "\\\"");
+    let line = format!(
+        "    day!({year}, {day}, {}::{}, \"{title}\"),\n",
+        year_module_name(year),
+        module_name(day)
+    );
+
+    Ok(format!(
+        "{}{line}{}",
+        &registry[..position],
+        &registry[position..]
+    ))
+}
+
+/// Adds a new day to the project under the given root directory: writes its module, declares it
+/// in its year's module, which is created for a new year, and registers it. Returns the files
+/// that were created or changed.
+///
+/// # Arguments
+///
+/// 'root' - The root directory of the project.
+///
+/// 'year' - The year of the calendar.
+///
+/// 'day' - The day of the calendar.
+///
+/// 'title' - The title of the puzzle.
+pub fn scaffold(root: &Path, year: u32, day: u32, title: &str) -> Result<Vec<PathBuf>, String> {
+    if year < FIRST_YEAR {
+        return Err(format!("Invalid year '{year}'"));
+    }
+    if !(1..=25).contains(&day) {
+        return Err(format!("Invalid day '{day}'"));
+    }
+
+    let src = root.join("src");
+    let year_name = year_module_name(year);
+    let module = src
+        .join(&year_name)
+        .join(format!("{}.rs", module_name(day)));
+    if module.exists() {
+        return Err(format!("{} already exists", module.display()));
+    }
+
+    let read = |path: &Path| {
+        fs::read_to_string(path).map_err(|e| format!("Unable to read {}: {e}", path.display()))
+    };
+    let lib = src.join("lib.rs");
+    let year_module = src.join(format!("{year_name}.rs"));
+    let registry = src.join("solver.rs");
+
+    // Work out every change before writing anything, so that a failure leaves nothing half done
+    let mut changes = vec![(module.clone(), module_source(year, day))];
+    if year_module.exists() {
+        let source = add_module(&read(&year_module)?, &module_name(day))?;
+        changes.push((year_module, source));
+    } else {
+        changes.push((year_module, add_module("", &module_name(day))?));
+        changes.push((lib.clone(), add_module(&read(&lib)?, &year_name)?));
+    }
+    let source = add_registry_entry(&read(&registry)?, year, day, title)?;
+    changes.push((registry, source));
+
+    fs::create_dir_all(src.join(&year_name))
+        .map_err(|e| format!("Unable to create {}: {e}", src.join(&year_name).display()))?;
+    for (path, text) in &changes {
+        fs::write(path, text).map_err(|e| format!("Unable to write {}: {e}", path.display()))?;
+    }
+
+    Ok(changes.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scaffold::*;
+
+    #[test]
+    fn declare_modules_in_order() {
+        let lib = "pub mod answers;\npub mod day01;\npub mod day03;\npub mod direction;\n";
+
+        assert_eq!(
+            add_module(lib, "day02"),
+            Ok(String::from(
+                "pub mod answers;\npub mod day01;\npub mod day02;\npub mod day03;\npub mod direction;\n"
+            ))
+        );
+        assert_eq!(
+            add_module("pub mod answers;\n", "year2024"),
+            Ok(String::from("pub mod answers;\npub mod year2024;\n"))
+        );
+        assert_eq!(
+            add_module("", "day01"),
+            Ok(String::from("pub mod day01;\n"))
+        );
+        assert_eq!(
+            add_module(lib, "day03"),
+            Err(String::from("The day03 module is already declared"))
+        );
+    }
+
+    #[test]
+    fn register_days_in_order() {
+        let registry = "pub static SOLVERS: &[&dyn Solver] = &[
+    day!(2023, 1, year2023::day01, \"Trebuchet?!\"),
+    day!(
+        2023,
+        5,
+        year2023::day05,
+        \"If You Give A Seed A Fertilizer\",
+        year2023::day05::parse_almanac
+    ),
+];
+";
+
+        assert_eq!(
+            add_registry_entry(registry, 2023, 3, "Gear \"Ratios\""),
+            Ok(String::from(
+                "pub static SOLVERS: &[&dyn Solver] = &[
+    day!(2023, 1, year2023::day01, \"Trebuchet?!\"),
+    day!(2023, 3, year2023::day03, \"Gear \\\"Ratios\\\"\"),
+    day!(
+        2023,
+        5,
+        year2023::day05,
+        \"If You Give A Seed A Fertilizer\",
+        year2023::day05::parse_almanac
+    ),
+];
+"
+            ))
+        );
+        assert!(add_registry_entry(registry, 2022, 7, "Camel Cards")
+            .unwrap()
+            .contains(
+                "&[\n    day!(2022, 7, year2022::day07, \"Camel Cards\"),\n    day!(2023, 1,"
+            ));
+        assert!(add_registry_entry(registry, 2024, 1, "Next")
+            .unwrap()
+            .ends_with("    ),\n    day!(2024, 1, year2024::day01, \"Next\"),\n];\n"));
+        assert_eq!(
+            add_registry_entry(registry, 2023, 5, "Again"),
+            Err(String::from("Day 5 of 2023 is already registered"))
+        );
+        assert_eq!(
+            add_registry_entry(include_str!("solver.rs"), 2023, 12, "Again"),
+            Err(String::from("Day 12 of 2023 is already registered"))
+        );
+    }
+
+    #[test]
+    fn scaffold_new_days() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(
+            root.join("src/lib.rs"),
+            "pub mod grid;\npub mod year2023;\n",
+        )
+        .unwrap();
+        fs::write(root.join("src/year2023.rs"), "pub mod day01;\n").unwrap();
+        fs::write(
+            root.join("src/solver.rs"),
+            "pub static SOLVERS: &[&dyn Solver] = &[\n    day!(2023, 1, year2023::day01, \"Trebuchet?!\"),\n];\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            scaffold(&root, 2023, 2, "Cube Conundrum"),
+            Ok(vec![
+                root.join("src/year2023/day02.rs"),
+                root.join("src/year2023.rs"),
+                root.join("src/solver.rs")
+            ])
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("src/year2023/day02.rs")).unwrap(),
+            module_source(2023, 2)
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("src/year2023.rs")).unwrap(),
+            "pub mod day01;\npub mod day02;\n"
+        );
+        assert!(fs::read_to_string(root.join("src/solver.rs"))
+            .unwrap()
+            .contains("    day!(2023, 2, year2023::day02, \"Cube Conundrum\"),\n];"));
+
+        assert_eq!(
+            scaffold(&root, 2024, 1, "Next"),
+            Ok(vec![
+                root.join("src/year2024/day01.rs"),
+                root.join("src/year2024.rs"),
+                root.join("src/lib.rs"),
+                root.join("src/solver.rs")
+            ])
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("src/lib.rs")).unwrap(),
+            "pub mod grid;\npub mod year2023;\npub mod year2024;\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("src/year2024.rs")).unwrap(),
+            "pub mod day01;\n"
+        );
+
+        assert!(scaffold(&root, 2023, 2, "Again")
+            .unwrap_err()
+            .ends_with("day02.rs already exists"));
+        assert_eq!(
+            scaffold(&root, 2023, 26, "Too late"),
+            Err(String::from("Invalid day '26'"))
+        );
+        assert_eq!(
+            scaffold(&root, 2014, 1, "Too early"),
+            Err(String::from("Invalid year '2014'"))
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn new_modules_follow_the_layout() {
+        let source = module_source(2024, 7);
+
+        assert!(source.starts_with("use crate::error::SolveError;\n\n///\n///\n/// #Argument"));
+        assert!(source.contains("pub fn part_one(input: &str) -> Result<u64, SolveError> {"));
+        assert!(source.contains("pub fn part_two(input: &str) -> Result<u64, SolveError> {"));
+        assert!(source.contains("    use crate::year2024::day07::*;"));
+        assert!(source.contains("    fn part_one_correct() {"));
+        assert!(source.contains("    fn part_two_correct() {"));
+    }
+}
@@ -0,0 +1,461 @@
+use crate::error::{parse_lines, SolveError};
+use crate::*;
+use std::fmt::Display;
+
+/// The answer produced by a solver for one part of a puzzle.
+pub type Answer = Box<dyn Display + Send>;
+
+/// A solution to a single day's puzzle.
+pub trait Solver: Sync {
+    /// Returns the year of the calendar this solver is for.
+    fn year(&self) -> u32;
+
+    /// Returns the day of the calendar this solver is for.
+    fn day(&self) -> u32;
+
+    /// Returns the title of the puzzle.
+    fn title(&self) -> &'static str;
+
+    /// Solves the first part of the puzzle for the given input, or returns an error describing
+    /// why the input couldn't be solved.
+    ///
+    /// # Arguments
+    ///
+    /// 'input' - The puzzle input.
+    fn part_one(&self, input: &str) -> Result<Answer, SolveError>;
+
+    /// Solves the second part of the puzzle for the given input, or returns an error describing
+    /// why the input couldn't be solved.
+    ///
+    /// # Arguments
+    ///
+    /// 'input' - The puzzle input.
+    fn part_two(&self, input: &str) -> Result<Answer, SolveError>;
+
+    /// Parses the given input without solving either part, so that the cost of parsing can be
+    /// measured on its own. Returns None if the day doesn't parse its input as a separate step.
+    ///
+    /// # Arguments
+    ///
+    /// 'input' - The puzzle input.
+    fn parse(&self, input: &str) -> Option<Result<(), SolveError>>;
+}
+
+/// The function used to parse a day's input, with the parsed result thrown away.
+type Parser = fn(&str) -> Result<(), SolveError>;
+
+/// A solver built from the `part_one` and `part_two` functions of a day module.
+struct Day<A, B> {
+    year: u32,
+    day: u32,
+    title: &'static str,
+    part_one: fn(&str) -> Result<A, SolveError>,
+    part_two: fn(&str) -> Result<B, SolveError>,
+    parse: Option<Parser>,
+}
+
+impl<A, B> Solver for Day<A, B>
+where
+    A: Display + Send + 'static,
+    B: Display + Send + 'static,
+{
+    fn year(&self) -> u32 {
+        self.year
+    }
+
+    fn day(&self) -> u32 {
+        self.day
+    }
+
+    fn title(&self) -> &'static str {
+        self.title
+    }
+
+    fn part_one(&self, input: &str) -> Result<Answer, SolveError> {
+        match (self.part_one)(input) {
+            Ok(answer) => Ok(Box::new(answer)),
+            Err(e) => Err(e.with_day(self.day)),
+        }
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, SolveError> {
+        match (self.part_two)(input) {
+            Ok(answer) => Ok(Box::new(answer)),
+            Err(e) => Err(e.with_day(self.day)),
+        }
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<(), SolveError>> {
+        self.parse
+            .map(|parse| parse(input).map_err(|e| e.with_day(self.day)))
+    }
+}
+
+/// Registers a day module with the given year, day number and title, optionally along with the
+/// function that parses its input.
+macro_rules! day {
+    ($year:literal, $day:literal, $($module:ident)::+, $title:literal) => {
+        &Day {
+            year: $year,
+            day: $day,
+            title: $title,
+            part_one: $($module)::+::part_one,
+            part_two: $($module)::+::part_two,
+            parse: None,
+        }
+    };
+    ($year:literal, $day:literal, $($module:ident)::+, $title:literal, $parse:expr) => {
+        &Day {
+            year: $year,
+            day: $day,
+            title: $title,
+            part_one: $($module)::+::part_one,
+            part_two: $($module)::+::part_two,
+            parse: Some(|input| $parse(input).map(drop).map_err(SolveError::from)),
+        }
+    };
+}
+
+/// All the registered solvers, in calendar order, with the years in order too.
+pub static SOLVERS: &[&dyn Solver] = &[
+    day!(2023, 1, year2023::day01, "Trebuchet?!"),
+    day!(2023, 2, year2023::day02, "Cube Conundrum", |input| {
+        parse_lines(input, str::parse::<year2023::day02::Game>)
+    }),
+    day!(
+        2023,
+        3,
+        year2023::day03,
+        "Gear Ratios",
+        year2023::day03::parse_schematic
+    ),
+    day!(2023, 4, year2023::day04, "Scratchcards", |input| {
+        parse_lines(input, str::parse::<year2023::day04::Card>)
+    }),
+    day!(
+        2023,
+        5,
+        year2023::day05,
+        "If You Give A Seed A Fertilizer",
+        year2023::day05::parse_almanac
+    ),
+    day!(2023, 6, year2023::day06, "Wait For It"),
+    day!(
+        2023,
+        7,
+        year2023::day07,
+        "Camel Cards",
+        |input| parse_lines(input, str::parse::<year2023::day07::Hand>)
+    ),
+    day!(
+        2023,
+        8,
+        year2023::day08,
+        "Haunted Wasteland",
+        year2023::day08::parse_input
+    ),
+    day!(2023, 9, year2023::day09, "Mirage Maintenance", |input| {
+        parse_lines(input, year2023::day09::to_vec)
+    }),
+    day!(
+        2023,
+        10,
+        year2023::day10,
+        "Pipe Maze",
+        year2023::day10::read_map
+    ),
+    day!(
+        2023,
+        11,
+        year2023::day11,
+        "Cosmic Expansion",
+        year2023::day11::parse_input
+    ),
+    day!(2023, 12, year2023::day12, "Hot Springs", |input| {
+        parse_lines(input, year2023::day12::split_row)
+    }),
+    day!(
+        2023,
+        13,
+        year2023::day13,
+        "Point of Incidence",
+        year2023::day13::parse_patterns
+    ),
+    day!(
+        2023,
+        14,
+        year2023::day14,
+        "Parabolic Reflector Dish",
+        year2023::day14::parse_map
+    ),
+    day!(
+        2023,
+        15,
+        year2023::day15,
+        "Lens Library",
+        year2023::day15::parse_steps
+    ),
+    day!(
+        2023,
+        16,
+        year2023::day16,
+        "The Floor Will Be Lava",
+        year2023::day16::parse_map
+    ),
+    day!(
+        2023,
+        17,
+        year2023::day17,
+        "Clumsy Crucible",
+        year2023::day17::Map::try_from
+    ),
+    day!(2023, 18, year2023::day18, "Lavaduct Lagoon", |input| {
+        parse_lines(input, year2023::day18::Command::try_from)
+    }),
+    day!(
+        2023,
+        19,
+        year2023::day19,
+        "Aplenty",
+        year2023::day19::parse_system
+    ),
+    day!(
+        2023,
+        20,
+        year2023::day20,
+        "Pulse Propagation",
+        year2023::day20::parse_modules
+    ),
+    day!(
+        2023,
+        21,
+        year2023::day21,
+        "Step Counter",
+        year2023::day21::Map::try_from
+    ),
+    day!(
+        2023,
+        22,
+        year2023::day22,
+        "Sand Slabs",
+        year2023::day22::parse_bricks
+    ),
+    day!(
+        2023,
+        23,
+        year2023::day23,
+        "A Long Walk",
+        year2023::day23::build_graph
+    ),
+    day!(
+        2023,
+        24,
+        year2023::day24,
+        "Never Tell Me The Odds",
+        |input| parse_lines(input, year2023::day24::Hailstone::try_from)
+    ),
+    day!(
+        2023,
+        25,
+        year2023::day25,
+        "Snowverload",
+        year2023::day25::parse_graph
+    ),
+];
+
+/// Returns the solver registered for the given day of a year, or None if there isn't one.
+///
+/// # Arguments
+///
+/// 'year' - The year of the calendar to look up.
+///
+/// 'day' - The day of the calendar to look up.
+pub fn find(year: u32, day: u32) -> Option<&'static dyn Solver> {
+    SOLVERS
+        .iter()
+        .copied()
+        .find(|s| s.year() == year && s.day() == day)
+}
+
+/// Returns the solver for the latest day of the latest year that has been registered.
+pub fn latest() -> &'static dyn Solver {
+    *SOLVERS.iter().max_by_key(|s| (s.year(), s.day())).unwrap()
+}
+
+/// Returns the years that have solvers registered, in order.
+pub fn years() -> Vec<u32> {
+    let mut years: Vec<_> = SOLVERS.iter().map(|s| s.year()).collect();
+    years.dedup();
+    years
+}
+
+/// Returns the solvers for a year selected by a day specification, which may be a single day
+/// ("5"), an inclusive range of days ("1-10"), or "all" for every registered day of the year.
+///
+/// # Arguments
+///
+/// 'year' - The year of the calendar.
+///
+/// 'spec' - The day specification to parse.
+pub fn select(year: u32, spec: &str) -> Result<Vec<&'static dyn Solver>, String> {
+    let in_year = SOLVERS.iter().copied().filter(|s| s.year() == year);
+
+    if spec == "all" {
+        let selected: Vec<_> = in_year.collect();
+        return if selected.is_empty() {
+            Err(format!("No solutions found for {year}"))
+        } else {
+            Ok(selected)
+        };
+    }
+
+    let parse_day = |s: &str| {
+        s.trim()
+            .parse::<u32>()
+            .map_err(|_| format!("Invalid day '{s}'"))
+    };
+
+    let (first, last) = match spec.split_once('-') {
+        Some((first, last)) => (parse_day(first)?, parse_day(last)?),
+        None => {
+            let day = parse_day(spec)?;
+            return find(year, day)
+                .map(|s| vec![s])
+                .ok_or_else(|| format!("No solution found for day '{spec}' of {year}"));
+        }
+    };
+
+    let selected: Vec<_> = in_year
+        .filter(|s| (first..=last).contains(&s.day()))
+        .collect();
+
+    if selected.is_empty() {
+        Err(format!("No solution found for day '{spec}' of {year}"))
+    } else {
+        Ok(selected)
+    }
+}
+
+/// Returns the solvers selected by the command line arguments naming the days to run: a year
+/// followed by a day specification, either of which may be left out. A year on its own selects
+/// every day of that year, days without a year are taken from the latest year, "all" on its own
+/// selects every registered day, and nothing at all selects the latest day.
+///
+/// # Arguments
+///
+/// 'args' - The arguments naming the days.
+pub fn select_args<S: AsRef<str>>(args: &[S]) -> Result<Vec<&'static dyn Solver>, String> {
+    let parse_year = |s: &str| {
+        s.parse::<u32>()
+            .ok()
+            .filter(|_| s.len() == 4)
+            .ok_or_else(|| format!("Invalid year '{s}'"))
+    };
+
+    match args {
+        [] => Ok(vec![latest()]),
+        [spec] if spec.as_ref() == "all" => Ok(SOLVERS.to_vec()),
+        [spec] => match parse_year(spec.as_ref()) {
+            Ok(year) => select(year, "all"),
+            Err(_) => select(latest().year(), spec.as_ref()),
+        },
+        [year, spec] => select(parse_year(year.as_ref())?, spec.as_ref()),
+        [_, _, extra, ..] => Err(format!("Unexpected argument '{}'", extra.as_ref())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solver::*;
+
+    #[test]
+    fn days_are_registered_in_order() {
+        let days: Vec<_> = SOLVERS.iter().map(|s| (s.year(), s.day())).collect();
+        let mut sorted = days.clone();
+        sorted.sort();
+        sorted.dedup();
+
+        assert_eq!(days, sorted);
+        assert_eq!(SOLVERS.iter().filter(|s| s.year() == 2023).count(), 25);
+        assert_eq!(years().first(), Some(&2023));
+    }
+
+    #[test]
+    fn find_registered_day() {
+        assert_eq!(
+            find(2023, 5).map(|s| s.title()),
+            Some("If You Give A Seed A Fertilizer")
+        );
+        assert!(find(2023, 0).is_none());
+        assert!(find(2023, 26).is_none());
+        assert!(find(2014, 5).is_none());
+        let newest = SOLVERS.iter().map(|s| (s.year(), s.day())).max();
+        assert_eq!(Some((latest().year(), latest().day())), newest);
+        assert_eq!(years().last(), newest.map(|(year, _)| year).as_ref());
+    }
+
+    #[test]
+    fn solve_through_registry() {
+        let solver = find(2023, 9).unwrap();
+        let input = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+        assert_eq!(solver.part_one(input).unwrap().to_string(), "114");
+        assert_eq!(solver.part_two(input).unwrap().to_string(), "2");
+
+        let error = solver.part_one("0 3 x").err().unwrap();
+        assert_eq!((error.day, error.line), (Some(9), Some(1)));
+    }
+
+    #[test]
+    fn parse_through_registry() {
+        assert_eq!(find(2023, 9).unwrap().parse("0 3 6\n1 3 6"), Some(Ok(())));
+        assert!(find(2023, 1).unwrap().parse("1abc2").is_none());
+
+        let error = find(2023, 9)
+            .unwrap()
+            .parse("0 3 6\n1 x 6")
+            .unwrap()
+            .unwrap_err();
+        assert_eq!((error.day, error.line), (Some(9), Some(2)));
+    }
+
+    #[test]
+    fn select_days() {
+        let days = |spec| select(2023, spec).map(|v| v.iter().map(|s| s.day()).collect::<Vec<_>>());
+
+        assert_eq!(days("7"), Ok(vec![7]));
+        assert_eq!(days("3-6"), Ok(vec![3, 4, 5, 6]));
+        assert_eq!(days("24-30"), Ok(vec![24, 25]));
+        assert_eq!(days("all").map(|v| v.len()), Ok(25));
+
+        assert!(days("26").is_err());
+        assert!(days("6-3").is_err());
+        assert!(days("blah").is_err());
+        assert!(days("1-blah").is_err());
+        assert_eq!(
+            select(2014, "all").err(),
+            Some(String::from("No solutions found for 2014"))
+        );
+    }
+
+    #[test]
+    fn select_years_and_days() {
+        let selected = |args: &[&str]| {
+            select_args(args).map(|v| v.iter().map(|s| (s.year(), s.day())).collect::<Vec<_>>())
+        };
+        let (year, day) = (latest().year(), latest().day());
+
+        assert_eq!(selected(&["2023", "7"]), Ok(vec![(2023, 7)]));
+        assert_eq!(selected(&["2023", "3-4"]), Ok(vec![(2023, 3), (2023, 4)]));
+        assert_eq!(selected(&["2023"]).map(|v| v.len()), Ok(25));
+        assert_eq!(selected(&[&day.to_string()]), Ok(vec![(year, day)]));
+        assert_eq!(selected(&[]), Ok(vec![(year, day)]));
+        assert_eq!(selected(&["all"]).map(|v| v.len()), Ok(SOLVERS.len()));
+
+        assert!(selected(&["2014"]).is_err());
+        assert!(selected(&["23", "7"]).is_err());
+        assert!(selected(&["2023", "7", "8"]).is_err());
+    }
+}
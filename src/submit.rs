@@ -0,0 +1,627 @@
+use crate::fetch::{since_epoch, RateLimiter, USER_AGENT};
+use std::fmt::{Display, Formatter};
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// The settings for submitting answers.
+pub struct SubmitConfig {
+    /// The address of the site, without a trailing slash.
+    pub base_url: String,
+    /// The session token identifying whose answers are being submitted.
+    pub session: Option<String>,
+    /// The rate limiter spacing out requests to the site.
+    pub limiter: RateLimiter,
+}
+
+/// How the site judged a submitted answer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// The answer was submitted too soon after the last one, so it wasn't judged.
+    TooSoon,
+    /// The part has already been solved, so the answer wasn't judged.
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// Returns whether the answer was judged to be wrong.
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Verdict::Right => "right",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::TooSoon => "too soon",
+            Verdict::AlreadySolved => "already solved",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "right" => Ok(Verdict::Right),
+            "wrong" => Ok(Verdict::Wrong),
+            "too high" => Ok(Verdict::TooHigh),
+            "too low" => Ok(Verdict::TooLow),
+            "too soon" => Ok(Verdict::TooSoon),
+            "already solved" => Ok(Verdict::AlreadySolved),
+            _ => Err(format!("unknown verdict '{s}'")),
+        }
+    }
+}
+
+/// The site's response to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub verdict: Verdict,
+    /// How long the site asks to wait before the next answer is submitted.
+    pub wait: Duration,
+    /// The site's own description of the verdict, as plain text.
+    pub message: String,
+}
+
+/// A submitted answer, as recorded in the history file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    /// When the answer was submitted, in seconds since the Unix epoch.
+    pub time: u64,
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// How long the site asked to wait before the next answer, in seconds.
+    pub wait: u64,
+}
+
+impl Display for Attempt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            self.time, self.year, self.day, self.part, self.verdict, self.wait, self.answer
+        )
+    }
+}
+
+impl FromStr for Attempt {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<_> = s.splitn(7, '\t').collect();
+        let [time, year, day, part, verdict, wait, answer] = fields[..] else {
+            return Err(String::from("expected seven tab separated fields"));
+        };
+        let number = |field: &str| {
+            field
+                .parse()
+                .map_err(|_| format!("invalid number '{field}'"))
+        };
+
+        Ok(Attempt {
+            time: number(time)?,
+            year: number(year)? as u32,
+            day: number(day)? as u32,
+            part: number(part)? as u32,
+            answer: String::from(answer),
+            verdict: verdict.parse()?,
+            wait: number(wait)?,
+        })
+    }
+}
+
+/// Every answer submitted so far, kept in a file with one attempt per line so that answers
+/// known to be wrong are never submitted again.
+pub struct History {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Loads the history from the given file. A missing file is treated as an empty history.
+    ///
+    /// # Arguments
+    ///
+    /// 'path' - The history file.
+    pub fn load<P: Into<PathBuf>>(path: P) -> Result<History, String> {
+        let path = path.into();
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(format!("Unable to read {}: {e}", path.display())),
+        };
+
+        let attempts = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(index, line)| {
+                line.parse()
+                    .map_err(|msg| format!("{}: line {}: {msg}", path.display(), index + 1))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(History { path, attempts })
+    }
+
+    /// Returns the attempts at the given part of a day, oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// 'year' - The year of the calendar.
+    ///
+    /// 'day' - The day of the calendar.
+    ///
+    /// 'part' - The part of the puzzle (1 or 2).
+    pub fn attempts(&self, year: u32, day: u32, part: u32) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |a| a.year == year && a.day == day && a.part == part)
+    }
+
+    /// Checks whether an answer is worth submitting, given the earlier attempts. Returns the
+    /// reason if it isn't: the part is already solved, the answer is already known to be wrong
+    /// or lies outside the range left by the earlier too high and too low answers, or the site
+    /// asked to wait longer before the next answer.
+    ///
+    /// # Arguments
+    ///
+    /// 'year' - The year of the calendar.
+    ///
+    /// 'day' - The day of the calendar.
+    ///
+    /// 'part' - The part of the puzzle (1 or 2).
+    ///
+    /// 'answer' - The answer to submit.
+    ///
+    /// 'now' - The current time, in seconds since the Unix epoch.
+    pub fn check(
+        &self,
+        year: u32,
+        day: u32,
+        part: u32,
+        answer: &str,
+        now: u64,
+    ) -> Result<(), String> {
+        let number = answer.parse::<i128>().ok();
+
+        for attempt in self.attempts(year, day, part) {
+            let earlier = attempt.answer.parse::<i128>().ok();
+
+            match attempt.verdict {
+                Verdict::Right => {
+                    return Err(format!(
+                        "Part {part} of day {day} of {year} was already solved with {}",
+                        attempt.answer
+                    ))
+                }
+                verdict if verdict.is_wrong() && attempt.answer == answer => {
+                    return Err(format!("{answer} was already submitted and was {verdict}"))
+                }
+                Verdict::TooHigh if number.zip(earlier).is_some_and(|(n, e)| n >= e) => {
+                    return Err(format!(
+                        "{answer} can't be right, {} was already too high",
+                        attempt.answer
+                    ))
+                }
+                Verdict::TooLow if number.zip(earlier).is_some_and(|(n, e)| n <= e) => {
+                    return Err(format!(
+                        "{answer} can't be right, {} was already too low",
+                        attempt.answer
+                    ))
+                }
+                _ => {}
+            }
+        }
+
+        let ready = self.attempts.iter().map(|a| a.time + a.wait).max();
+        match ready {
+            Some(ready) if ready > now => Err(format!(
+                "The site asked to wait another {}s before submitting again",
+                ready - now
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Adds an attempt to the history and appends it to the history file.
+    ///
+    /// # Arguments
+    ///
+    /// 'attempt' - The attempt to record.
+    pub fn record(&mut self, attempt: Attempt) -> Result<(), String> {
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| writeln!(file, "{attempt}"))
+            .map_err(|e| format!("Unable to write {}: {e}", self.path.display()))?;
+
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+/// Returns the URL that answers for the given day are posted to.
+///
+/// # Arguments
+///
+/// 'base_url' - The address of the site.
+///
+/// 'year' - The year of the calendar.
+///
+/// 'day' - The day of the calendar.
+pub fn answer_url(base_url: &str, year: u32, day: u32) -> String {
+    format!("{}/{year}/day/{day}/answer", base_url.trim_end_matches('/'))
+}
+
+/// Returns the plain text of the page's main article, which is where the site explains its
+/// verdict, or of the whole page if there is no article.
+///
+/// # Arguments
+///
+/// 'page' - The HTML of the page.
+fn article_text(page: &str) -> String {
+    // The article still starts inside its opening tag, as only "<article" has been cut off
+    let (article, mut in_tag) = page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or((page, false), |(article, _)| (article, true));
+
+    let mut text = String::new();
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Returns how long the site's message asks to wait before the next answer, reading either
+/// "You have 1m 5s left to wait" or "Please wait 5 minutes before trying again".
+///
+/// # Arguments
+///
+/// 'message' - The site's message, as plain text.
+fn parse_wait(message: &str) -> Duration {
+    let message = message.to_lowercase();
+
+    if let Some((before, _)) = message.split_once(" left to wait") {
+        let left = before.rsplit_once("you have ").map_or("", |(_, left)| left);
+        let seconds = left.split_whitespace().map(|amount| {
+            let number = amount.trim_end_matches(|c: char| !c.is_ascii_digit());
+            let unit = &amount[number.len()..];
+            let number = number.parse::<u64>().unwrap_or(0);
+            match unit {
+                "h" => number * 3600,
+                "m" => number * 60,
+                _ => number,
+            }
+        });
+        return Duration::from_secs(seconds.sum());
+    }
+
+    if let Some((_, rest)) = message.split_once("please wait ") {
+        let mut words = rest.split_whitespace();
+        let number = match words.next() {
+            Some("one") => 1,
+            Some(number) => number.parse().unwrap_or(0),
+            None => 0,
+        };
+        return match words.next() {
+            Some(unit) if unit.starts_with("minute") => Duration::from_secs(number * 60),
+            Some(unit) if unit.starts_with("second") => Duration::from_secs(number),
+            _ => Duration::ZERO,
+        };
+    }
+
+    Duration::ZERO
+}
+
+/// Reads the site's verdict from the page it returns for a submitted answer.
+///
+/// # Arguments
+///
+/// 'page' - The HTML of the page.
+pub fn parse_response(page: &str) -> Result<Response, String> {
+    let message = article_text(page);
+
+    let verdict = if message.contains("That's the right answer") {
+        Verdict::Right
+    } else if message.contains("That's not the right answer") {
+        if message.contains("answer is too high") {
+            Verdict::TooHigh
+        } else if message.contains("answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else if message.contains("You gave an answer too recently") {
+        Verdict::TooSoon
+    } else if message.contains("You don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else {
+        return Err(format!("Unrecognised response: {message}"));
+    };
+
+    Ok(Response {
+        verdict,
+        wait: parse_wait(&message),
+        message,
+    })
+}
+
+/// Submits an answer for a part of a day and records the attempt in the history, unless the
+/// history shows that it isn't worth submitting.
+///
+/// # Arguments
+///
+/// 'year' - The year of the calendar.
+///
+/// 'day' - The day of the calendar.
+///
+/// 'part' - The part of the puzzle (1 or 2).
+///
+/// 'answer' - The answer to submit.
+///
+/// 'config' - The settings for submitting answers.
+///
+/// 'history' - The answers submitted so far.
+pub fn submit_answer(
+    year: u32,
+    day: u32,
+    part: u32,
+    answer: &str,
+    config: &SubmitConfig,
+    history: &mut History,
+) -> Result<Response, String> {
+    if !matches!(part, 1 | 2) {
+        return Err(format!("Invalid part '{part}'"));
+    }
+    if answer.is_empty() || answer.contains(['\t', '\n']) {
+        return Err(format!("Invalid answer '{answer}'"));
+    }
+
+    history.check(year, day, part, answer, since_epoch().as_secs())?;
+    let session = config
+        .session
+        .as_deref()
+        .ok_or("A session token is needed to submit answers")?;
+
+    config.limiter.wait()?;
+    let url = answer_url(&config.base_url, year, day);
+    let page = ureq::post(&url)
+        .set("Cookie", &format!("session={session}"))
+        .set("User-Agent", USER_AGENT)
+        .timeout(Duration::from_secs(30))
+        .send_form(&[("level", &part.to_string()), ("answer", answer)])
+        .map_err(|e| format!("Unable to submit to {url}: {e}"))?
+        .into_string()
+        .map_err(|e| format!("Unable to read the response from {url}: {e}"))?;
+
+    let response = parse_response(&page)?;
+    history.record(Attempt {
+        time: since_epoch().as_secs(),
+        year,
+        day,
+        part,
+        answer: String::from(answer),
+        verdict: response.verdict,
+        wait: response.wait.as_secs(),
+    })?;
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::submit::*;
+    use std::io::{BufRead, BufReader, Read};
+    use std::net::TcpListener;
+    use std::path::Path;
+    use std::thread;
+
+    /// Starts a mock of the site that answers a single request with a page holding the given
+    /// message. Returns its address and a handle giving the request line and form it received.
+    fn serve(message: &'static str) -> (String, thread::JoinHandle<(String, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let headers: Vec<String> = (&mut reader)
+                .lines()
+                .map(Result::unwrap)
+                .take_while(|line| !line.is_empty())
+                .collect();
+
+            let length = headers
+                .iter()
+                .find_map(|h| {
+                    h.to_lowercase()
+                        .strip_prefix("content-length: ")?
+                        .parse()
+                        .ok()
+                })
+                .unwrap_or(0);
+            let mut form = vec![0; length];
+            reader.read_exact(&mut form).unwrap();
+
+            let body = format!("<html><main><article><p>{message}</p></article></main></html>");
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            (headers[0].clone(), String::from_utf8(form).unwrap())
+        });
+
+        (url, handle)
+    }
+
+    /// Returns the path of a fresh file in the temporary directory.
+    fn temp_file(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("aoc-{name}-{}.txt", std::process::id()));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    /// Returns the settings for submitting to the given site.
+    fn config(base_url: &str, stamp: &Path) -> SubmitConfig {
+        SubmitConfig {
+            base_url: base_url.to_string(),
+            session: Some(String::from("abc123")),
+            limiter: RateLimiter::new(stamp, Duration::ZERO),
+        }
+    }
+
+    /// Returns an attempt at part one of day 1 of 2023 made at the given time.
+    fn attempt(time: u64, answer: &str, verdict: Verdict, wait: u64) -> Attempt {
+        Attempt {
+            time,
+            year: 2023,
+            day: 1,
+            part: 1,
+            answer: String::from(answer),
+            verdict,
+            wait,
+        }
+    }
+
+    #[test]
+    fn parse_responses() {
+        let cases = [
+            ("<article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article>", Verdict::Right, 0),
+            ("<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again.</p></article>", Verdict::TooHigh, 60),
+            ("<article><p>That's not the right answer; your answer is too low. Because you have guessed incorrectly 5 times on this puzzle, please wait 5 minutes before trying again.</p></article>", Verdict::TooLow, 300),
+            ("<article><p>That's not the right answer. If you're stuck, please wait one minute before trying again.</p></article>", Verdict::Wrong, 60),
+            ("<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.</p></article>", Verdict::TooSoon, 65),
+            ("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>", Verdict::AlreadySolved, 0),
+        ];
+
+        for (page, verdict, wait) in cases {
+            let response = parse_response(page).unwrap();
+            assert_eq!(response.verdict, verdict, "{page}");
+            assert_eq!(response.wait, Duration::from_secs(wait), "{page}");
+        }
+
+        assert_eq!(
+            parse_response(
+                "<article class=\"day-desc\"><p>That's the <b>right</b> answer!</p></article>"
+            )
+            .unwrap()
+            .message,
+            "That's the right answer!"
+        );
+        assert_eq!(
+            parse_response("<p>Something else</p>"),
+            Err(String::from("Unrecognised response: Something else"))
+        );
+    }
+
+    #[test]
+    fn check_against_history() {
+        let path = temp_file("history-check");
+        let mut history = History::load(&path).unwrap();
+        history
+            .record(attempt(100, "500", Verdict::TooHigh, 60))
+            .unwrap();
+        history
+            .record(attempt(200, "100", Verdict::TooLow, 60))
+            .unwrap();
+        history
+            .record(attempt(300, "abc", Verdict::Wrong, 60))
+            .unwrap();
+
+        assert_eq!(history.check(2023, 1, 1, "250", 400), Ok(()));
+        assert_eq!(history.check(2023, 1, 2, "500", 400), Ok(()));
+        assert_eq!(history.check(2022, 1, 1, "abc", 400), Ok(()));
+        assert_eq!(
+            history.check(2023, 1, 1, "abc", 400),
+            Err(String::from("abc was already submitted and was wrong"))
+        );
+        assert_eq!(
+            history.check(2023, 1, 1, "600", 400),
+            Err(String::from("600 can't be right, 500 was already too high"))
+        );
+        assert_eq!(
+            history.check(2023, 1, 1, "100", 400),
+            Err(String::from("100 was already submitted and was too low"))
+        );
+        assert_eq!(
+            history.check(2023, 1, 1, "99", 400),
+            Err(String::from("99 can't be right, 100 was already too low"))
+        );
+        assert_eq!(
+            history.check(2023, 1, 1, "250", 330),
+            Err(String::from(
+                "The site asked to wait another 30s before submitting again"
+            ))
+        );
+
+        history
+            .record(attempt(400, "250", Verdict::Right, 0))
+            .unwrap();
+        assert_eq!(
+            history.check(2023, 1, 1, "260", 500),
+            Err(String::from(
+                "Part 1 of day 1 of 2023 was already solved with 250"
+            ))
+        );
+
+        let reloaded = History::load(&path).unwrap();
+        assert_eq!(reloaded.attempts, history.attempts);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn submit_to_mock_site() {
+        let path = temp_file("history-submit");
+        let stamp = temp_file("history-stamp");
+        let mut history = History::load(&path).unwrap();
+
+        let (url, server) = serve("That's not the right answer; your answer is too low.");
+        let response = submit_answer(2023, 3, 2, "4361", &config(&url, &stamp), &mut history);
+        assert_eq!(response.unwrap().verdict, Verdict::TooLow);
+
+        let (request, form) = server.join().unwrap();
+        assert_eq!(request, "POST /2023/day/3/answer HTTP/1.1");
+        assert_eq!(form, "level=2&answer=4361");
+        assert_eq!(history.attempts(2023, 3, 2).count(), 1);
+
+        // The mock has stopped listening, so these only work because nothing is submitted
+        assert_eq!(
+            submit_answer(2023, 3, 2, "4361", &config(&url, &stamp), &mut history),
+            Err(String::from("4361 was already submitted and was too low"))
+        );
+        assert_eq!(
+            submit_answer(2023, 3, 3, "4361", &config(&url, &stamp), &mut history),
+            Err(String::from("Invalid part '3'"))
+        );
+
+        fs::remove_file(path).unwrap();
+        fs::remove_file(stamp).unwrap();
+    }
+}
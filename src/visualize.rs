@@ -0,0 +1,333 @@
+use crate::error::SolveError;
+use crate::grid::Grid;
+use crate::year2023::{day10, day16, day17, day21, day23};
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+/// The colors used to pick out the cells of a frame.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Color {
+    Plain,
+    Dim,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+}
+
+impl Color {
+    /// Returns the ANSI escape sequence that switches the terminal to this color.
+    fn escape(self) -> &'static str {
+        match self {
+            Color::Plain => "\x1b[0m",
+            Color::Dim => "\x1b[0;2m",
+            Color::Red => "\x1b[0;1;31m",
+            Color::Green => "\x1b[0;32m",
+            Color::Yellow => "\x1b[0;1;33m",
+            Color::Blue => "\x1b[0;34m",
+        }
+    }
+}
+
+/// A picture of a puzzle's map at one step of solving it, with a colored character for each cell
+/// and a caption describing the step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub caption: String,
+    pub cells: Grid<(char, Color)>,
+}
+
+impl Frame {
+    /// Creates a frame by drawing each cell of a map.
+    ///
+    /// # Arguments
+    ///
+    /// 'caption' - The description of the step shown.
+    ///
+    /// 'map' - The map to draw.
+    ///
+    /// 'draw' - The function giving the character and color for a cell from its position and
+    /// value.
+    pub fn new<S, T, F>(caption: S, map: &Grid<T>, mut draw: F) -> Frame
+    where
+        S: Into<String>,
+        F: FnMut((usize, usize), &T) -> (char, Color),
+    {
+        Frame {
+            caption: caption.into(),
+            cells: Grid::from_fn(map.width(), map.height(), |pos| draw(pos, &map[pos])),
+        }
+    }
+
+    /// Changes the color of a cell, keeping its character. Positions outside the frame are
+    /// ignored.
+    ///
+    /// # Arguments
+    ///
+    /// 'pos' - The position of the cell.
+    ///
+    /// 'color' - The new color.
+    pub fn paint(&mut self, pos: (usize, usize), color: Color) {
+        if let Some(cell) = self.cells.get_mut(pos) {
+            cell.1 = color;
+        }
+    }
+
+    /// Returns the frame as plain text, with the caption on the first line.
+    pub fn to_plain(&self) -> String {
+        let rows = self.cells.rows().map(|row| row.iter().map(|(c, _)| *c));
+        let rows: Vec<String> = rows.map(Iterator::collect).collect();
+
+        format!("{}\n{}\n", self.caption, rows.join("\n"))
+    }
+
+    /// Returns the frame as text colored with ANSI escape sequences, with the caption on the
+    /// first line.
+    pub fn to_ansi(&self) -> String {
+        let mut text = format!("{}\n", self.caption);
+
+        for row in self.cells.rows() {
+            let mut color = Color::Plain;
+
+            for (c, cell_color) in row {
+                if *cell_color != color {
+                    text += cell_color.escape();
+                    color = *cell_color;
+                }
+                text.push(*c);
+            }
+
+            text += Color::Plain.escape();
+            text.push('\n');
+        }
+
+        text
+    }
+}
+
+/// The settings for showing the frames of a visualization.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VisualizeOptions {
+    /// Whether to draw the frames in the terminal.
+    pub terminal: bool,
+    /// How long to pause after drawing each frame in the terminal.
+    pub delay: Duration,
+    /// Only every this many steps is shown, to speed up long visualizations.
+    pub every: usize,
+    /// A directory to write each frame to as a plain text file.
+    pub frames: Option<PathBuf>,
+}
+
+/// Shows the frames of a visualization as the steps of a solution are taken, throttling them and
+/// saving them to files as the options ask.
+pub struct Visualizer {
+    options: VisualizeOptions,
+    steps: usize,
+    shown: usize,
+    last: Option<Frame>,
+}
+
+impl Visualizer {
+    /// Creates a visualizer that hasn't shown any frames yet.
+    ///
+    /// # Arguments
+    ///
+    /// 'options' - The settings for showing the frames.
+    pub fn new(options: VisualizeOptions) -> Visualizer {
+        Visualizer {
+            options,
+            steps: 0,
+            shown: 0,
+            last: None,
+        }
+    }
+
+    /// Records a step of the solution, showing its frame unless it is skipped by the 'every'
+    /// option. The frame is only drawn when it is going to be shown, as drawing every step of a
+    /// large map can take far longer than solving it.
+    ///
+    /// # Arguments
+    ///
+    /// 'draw' - The function that draws the frame for the step.
+    pub fn step<F>(&mut self, draw: F) -> Result<(), SolveError>
+    where
+        F: FnOnce() -> Frame,
+    {
+        self.steps += 1;
+
+        if (self.steps - 1).is_multiple_of(self.options.every.max(1)) {
+            self.show(draw())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Shows a frame whatever the 'every' option is, for results that should always be seen.
+    ///
+    /// # Arguments
+    ///
+    /// 'frame' - The frame to show.
+    pub fn show(&mut self, frame: Frame) -> Result<(), SolveError> {
+        self.shown += 1;
+
+        if self.options.terminal {
+            let mut stdout = io::stdout().lock();
+            // Move to the top left and clear the screen before drawing over the last frame
+            write!(stdout, "\x1b[H\x1b[2J{}", frame.to_ansi())
+                .and_then(|_| stdout.flush())
+                .map_err(|e| SolveError::new(format!("Unable to draw frame: {e}")))?;
+            thread::sleep(self.options.delay);
+        }
+
+        if let Some(dir) = &self.options.frames {
+            let path = dir.join(format!("frame{:05}.txt", self.shown));
+            fs::create_dir_all(dir)
+                .and_then(|_| fs::write(&path, frame.to_plain()))
+                .map_err(|e| SolveError::new(format!("Unable to write {}: {e}", path.display())))?;
+        }
+
+        self.last = Some(frame);
+        Ok(())
+    }
+
+    /// Returns the number of frames shown so far.
+    pub fn frames_shown(&self) -> usize {
+        self.shown
+    }
+
+    /// Returns the last frame shown, if any.
+    pub fn last_frame(&self) -> Option<&Frame> {
+        self.last.as_ref()
+    }
+}
+
+/// The function that steps through a day's solution, showing frames as it goes.
+type Visualization = fn(&str, &mut Visualizer) -> Result<(), SolveError>;
+
+/// The years and days that can be visualized, along with the function that does so.
+const VISUALIZATIONS: [(u32, u32, Visualization); 5] = [
+    (2023, 10, day10::visualize),
+    (2023, 16, day16::visualize),
+    (2023, 17, day17::visualize),
+    (2023, 21, day21::visualize),
+    (2023, 23, day23::visualize),
+];
+
+/// Returns the years and days that can be visualized, in calendar order.
+pub fn days() -> impl Iterator<Item = (u32, u32)> {
+    VISUALIZATIONS.iter().map(|(year, day, _)| (*year, *day))
+}
+
+/// Steps through a day's solution for the given input, showing frames of it as it goes. Returns
+/// None if the day can't be visualized.
+///
+/// # Arguments
+///
+/// 'year' - The year of the calendar.
+///
+/// 'day' - The day of the calendar.
+///
+/// 'input' - The puzzle input.
+///
+/// 'visualizer' - Where to show the frames.
+pub fn visualize(
+    year: u32,
+    day: u32,
+    input: &str,
+    visualizer: &mut Visualizer,
+) -> Option<Result<(), SolveError>> {
+    let (_, _, visualization) = VISUALIZATIONS
+        .iter()
+        .find(|(y, d, _)| *y == year && *d == day)?;
+    Some(visualization(input, visualizer).map_err(|e| e.with_day(day)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::visualize::*;
+
+    /// Returns a visualizer that keeps its frames to itself.
+    fn quiet(every: usize) -> Visualizer {
+        Visualizer::new(VisualizeOptions {
+            terminal: false,
+            delay: Duration::ZERO,
+            every,
+            frames: None,
+        })
+    }
+
+    #[test]
+    fn draw_frames() {
+        let map = Grid::parse("#.\n.#", "a cell", Some).unwrap();
+        let mut frame = Frame::new("Test", &map, |_, c| (*c, Color::Plain));
+        frame.paint((1, 1), Color::Red);
+        frame.paint((5, 5), Color::Red);
+
+        assert_eq!(frame.to_plain(), "Test\n#.\n.#\n");
+        assert_eq!(frame.to_ansi(), "Test\n#.\x1b[0m\n.\x1b[0;1;31m#\x1b[0m\n");
+    }
+
+    #[test]
+    fn throttle_steps() {
+        let map = Grid::new(1, 1, '.');
+        let mut visualizer = quiet(3);
+
+        for step in 0..10 {
+            let frame = || Frame::new(format!("Step {step}"), &map, |_, c| (*c, Color::Plain));
+            visualizer.step(frame).unwrap();
+        }
+
+        assert_eq!(visualizer.frames_shown(), 4);
+        assert_eq!(visualizer.last_frame().unwrap().caption, "Step 9");
+    }
+
+    #[test]
+    fn dump_frames_to_files() {
+        let dir = std::env::temp_dir().join(format!("aoc-frames-{}", std::process::id()));
+        let mut visualizer = Visualizer::new(VisualizeOptions {
+            terminal: false,
+            delay: Duration::ZERO,
+            every: 1,
+            frames: Some(dir.clone()),
+        });
+
+        let map = Grid::new(2, 1, 'x');
+        for caption in ["One", "Two"] {
+            let frame = Frame::new(caption, &map, |_, c| (*c, Color::Green));
+            visualizer.show(frame).unwrap();
+        }
+
+        assert_eq!(
+            fs::read_to_string(dir.join("frame00002.txt")).unwrap(),
+            "Two\nxx\n"
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn visualize_the_samples() {
+        let samples = [
+            (10, "..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ..."),
+            (16, ".|...\\....\n|.-.\\.....\n.....|-...\n........|.\n..........\n.........\\\n..../.\\\\..\n.-.-/..|..\n.|....-|.\\\n..//.|...."),
+            (17, "24134\n32154\n32552\n34465\n45466"),
+            (21, "...\n.S.\n..."),
+            (23, "#.###\n#...#\n###.#"),
+        ];
+
+        for (day, input) in samples {
+            let mut visualizer = quiet(1);
+            assert_eq!(visualize(2023, day, input, &mut visualizer), Some(Ok(())));
+            assert!(visualizer.frames_shown() > 1, "day {day}");
+        }
+
+        assert!(visualize(2023, 1, "", &mut quiet(1)).is_none());
+        assert!(visualize(2022, 10, "", &mut quiet(1)).is_none());
+        assert_eq!(
+            days().collect::<Vec<_>>(),
+            vec![(2023, 10), (2023, 16), (2023, 17), (2023, 21), (2023, 23)]
+        );
+    }
+}
@@ -0,0 +1,354 @@
+use crate::json::JsonObject;
+use crate::runner::format_nanos;
+use crate::scaffold::{module_name, year_module_name};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime};
+
+/// How often the watched files are checked for changes, unless another interval is given.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(500);
+
+/// What is known about a file when it is checked: when it was last modified and how long it is,
+/// or nothing if it doesn't exist.
+type Stamp = Option<(SystemTime, u64)>;
+
+/// Notices changes to a set of files by checking them whenever asked, rather than relying on
+/// notifications from the platform.
+pub struct Watcher {
+    files: Vec<(PathBuf, Stamp)>,
+}
+
+impl Watcher {
+    /// Creates a watcher for the given files, taking their current state as unchanged. Files
+    /// that don't exist yet are watched for being created.
+    ///
+    /// # Arguments
+    ///
+    /// 'paths' - The files to watch.
+    pub fn new<I, P>(paths: I) -> Watcher
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<PathBuf>,
+    {
+        let files = paths
+            .into_iter()
+            .map(|path| {
+                let path = path.into();
+                let stamp = stamp(&path);
+                (path, stamp)
+            })
+            .collect();
+
+        Watcher { files }
+    }
+
+    /// Returns the files that have been modified, created or removed since they were last
+    /// checked.
+    pub fn changed(&mut self) -> Vec<PathBuf> {
+        let mut changed = vec![];
+
+        for (path, last) in &mut self.files {
+            let current = stamp(path);
+            if current != *last {
+                *last = current;
+                changed.push(path.clone());
+            }
+        }
+
+        changed
+    }
+}
+
+/// Returns the current state of a file.
+///
+/// # Arguments
+///
+/// 'path' - The file to check.
+fn stamp(path: &Path) -> Stamp {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// Returns the path of the source file for the given day, such as "src/year2023/day05.rs".
+///
+/// # Arguments
+///
+/// 'year' - The year of the calendar.
+///
+/// 'day' - The day of the calendar.
+pub fn source_path(year: u32, day: u32) -> PathBuf {
+    Path::new("src")
+        .join(year_module_name(year))
+        .join(format!("{}.rs", module_name(day)))
+}
+
+/// Returns the command that runs the sample tests for the given day.
+///
+/// # Arguments
+///
+/// 'year' - The year of the calendar.
+///
+/// 'day' - The day of the calendar.
+pub fn test_command(year: u32, day: u32) -> Command {
+    let mut command = cargo();
+    command.args(["test", "--quiet", "--lib"]).arg(format!(
+        "{}::{}::",
+        year_module_name(year),
+        module_name(day)
+    ));
+    command
+}
+
+/// Returns the command that rebuilds the executable and runs the given day, printing its result
+/// as JSON. The executable is built the same way as the one running the command, so that the
+/// timings can be compared with earlier runs.
+///
+/// # Arguments
+///
+/// 'year' - The year of the calendar.
+///
+/// 'day' - The day of the calendar.
+///
+/// 'input' - A custom input file to use instead of the day's input file.
+///
+/// 'repeat' - The number of times to run the day.
+pub fn run_command(year: u32, day: u32, input: Option<&str>, repeat: usize) -> Command {
+    let mut command = cargo();
+    command.args(["run", "--quiet"]);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    if cfg!(feature = "parallel") {
+        command.args(["--features", "parallel"]);
+    }
+
+    command.arg("--").arg(year.to_string()).arg(day.to_string());
+    command.args(["--format", "json", "--repeat", &repeat.to_string()]);
+    if let Some(input) = input {
+        command.args(["--input", input]);
+    }
+    command
+}
+
+/// Returns a command running the same cargo that built this executable, if it was started by
+/// cargo, or whichever cargo is on the path otherwise.
+fn cargo() -> Command {
+    Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()))
+}
+
+/// The answers and median timings from a run of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    pub part_one: String,
+    pub part_two: String,
+    pub read_time: Duration,
+    pub part_one_time: Duration,
+    pub part_two_time: Duration,
+}
+
+impl Snapshot {
+    /// Reads a snapshot from the JSON printed for a day by the runner, returning the error that
+    /// was printed instead if the day couldn't be run.
+    ///
+    /// # Arguments
+    ///
+    /// 'line' - The line of JSON printed for the day.
+    pub fn parse(line: &str) -> Result<Snapshot, String> {
+        let json = JsonObject::parse(line)?;
+        if let Some(error) = json.get("error") {
+            return Err(error);
+        }
+
+        let field = |key| {
+            json.get(key)
+                .ok_or(format!("Missing \"{key}\" in the result"))
+        };
+        let time = |key| {
+            field(key)?
+                .parse()
+                .map(Duration::from_nanos)
+                .map_err(|_| format!("Invalid \"{key}\" in the result"))
+        };
+
+        Ok(Snapshot {
+            part_one: field("part_one")?,
+            part_two: field("part_two")?,
+            read_time: time("read_ns")?,
+            part_one_time: time("part_one_ns")?,
+            part_two_time: time("part_two_ns")?,
+        })
+    }
+
+    /// Returns the lines describing this snapshot, noting any answer that differs from an
+    /// earlier snapshot and how each timing compares with it.
+    ///
+    /// # Arguments
+    ///
+    /// 'previous' - The snapshot from the previous run, if there was one.
+    pub fn report(&self, previous: Option<&Snapshot>) -> Vec<String> {
+        let answer = |name, current: &String, previous: Option<&String>| match previous {
+            Some(previous) if previous != current => {
+                format!("{name} output: {current} (was {previous})")
+            }
+            _ => format!("{name} output: {current}"),
+        };
+        let time = |name, current, previous: Option<Duration>| match previous {
+            Some(previous) => format!("{name} time: {}", format_change(previous, current)),
+            None => format!("{name} time: {} ns", format_nanos(current)),
+        };
+
+        vec![
+            answer("Part one", &self.part_one, previous.map(|p| &p.part_one)),
+            answer("Part two", &self.part_two, previous.map(|p| &p.part_two)),
+            time("Input read", self.read_time, previous.map(|p| p.read_time)),
+            time(
+                "Part one",
+                self.part_one_time,
+                previous.map(|p| p.part_one_time),
+            ),
+            time(
+                "Part two",
+                self.part_two_time,
+                previous.map(|p| p.part_two_time),
+            ),
+        ]
+    }
+}
+
+/// Formats a duration along with how much it changed from an earlier one, such as
+/// "1,100 ns (+100 ns, +10.0%)".
+///
+/// # Arguments
+///
+/// 'previous' - The earlier duration.
+///
+/// 'current' - The new duration.
+pub fn format_change(previous: Duration, current: Duration) -> String {
+    let (sign, difference) = if current >= previous {
+        ('+', current - previous)
+    } else {
+        ('-', previous - current)
+    };
+
+    if previous.is_zero() {
+        format!(
+            "{} ns ({sign}{} ns)",
+            format_nanos(current),
+            format_nanos(difference)
+        )
+    } else {
+        let percent = difference.as_secs_f64() / previous.as_secs_f64() * 100.0;
+        format!(
+            "{} ns ({sign}{} ns, {sign}{percent:.1}%)",
+            format_nanos(current),
+            format_nanos(difference)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::runner::{self, InputSource};
+    use crate::solver;
+    use crate::watch::*;
+
+    #[test]
+    fn notice_changed_files() {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let (input, other) = (dir.join("day01.txt"), dir.join("day02.txt"));
+        fs::write(&input, "1abc2\n").unwrap();
+
+        let mut watcher = Watcher::new([&input, &other]);
+        assert!(watcher.changed().is_empty());
+
+        fs::write(&input, "1abc2\npqr3stu8vwx\n").unwrap();
+        assert_eq!(watcher.changed(), vec![input.clone()]);
+        assert!(watcher.changed().is_empty());
+
+        fs::write(&other, "").unwrap();
+        fs::remove_file(&input).unwrap();
+        assert_eq!(watcher.changed(), vec![input, other]);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn compare_runs() {
+        let first = Snapshot::parse(
+            "{\"year\":2023,\"day\":9,\"part_one\":\"114\",\"part_two\":\"2\",\"read_ns\":1000,\"part_one_ns\":2000,\"part_two_ns\":0,\"runs\":1}",
+        )
+        .unwrap();
+        let second = Snapshot {
+            part_one: String::from("115"),
+            part_one_time: Duration::from_nanos(1500),
+            part_two_time: Duration::from_nanos(30),
+            ..first.clone()
+        };
+
+        assert_eq!(
+            second.report(Some(&first)),
+            vec![
+                "Part one output: 115 (was 114)",
+                "Part two output: 2",
+                "Input read time: 1,000 ns (+0 ns, +0.0%)",
+                "Part one time: 1,500 ns (-500 ns, -25.0%)",
+                "Part two time: 30 ns (+30 ns)"
+            ]
+        );
+        assert_eq!(first.report(None)[3], "Part one time: 2,000 ns");
+        assert_eq!(
+            Snapshot::parse("{\"year\":2023,\"day\":9,\"line\":2,\"error\":\"bad\"}"),
+            Err(String::from("bad"))
+        );
+    }
+
+    #[test]
+    fn read_the_runner_output() {
+        let path = env::temp_dir().join(format!("aoc-watch-run-{}.txt", std::process::id()));
+        fs::write(&path, "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45").unwrap();
+        let source = InputSource::File(path.to_str().unwrap().to_string());
+        let solver = solver::find(2023, 9).unwrap();
+
+        let result = runner::run(solver, &source, 1).unwrap();
+        let snapshot = Snapshot::parse(&runner::to_json(&result).to_string()).unwrap();
+        assert_eq!(
+            (snapshot.part_one.as_str(), snapshot.part_two.as_str()),
+            ("114", "2")
+        );
+        assert_eq!(snapshot.part_one_time, result.part_one_time.median());
+
+        fs::write(&path, "0 3 6\n1 \"x\\ 6").unwrap();
+        let Err(error) = runner::run(solver, &source, 1) else {
+            panic!("A malformed input should be an error");
+        };
+        assert_eq!(
+            Snapshot::parse(&runner::error_to_json(2023, 9, &error).to_string()),
+            Err(error.message)
+        );
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn build_commands() {
+        let test = test_command(2023, 5);
+        let args: Vec<_> = test.get_args().collect();
+        assert_eq!(args, ["test", "--quiet", "--lib", "year2023::day05::"]);
+
+        let run = run_command(2023, 5, Some("other.txt"), 3);
+        let args: Vec<_> = run.get_args().map(|arg| arg.to_str().unwrap()).collect();
+        assert!(args.ends_with(&[
+            "--",
+            "2023",
+            "5",
+            "--format",
+            "json",
+            "--repeat",
+            "3",
+            "--input",
+            "other.txt"
+        ]));
+        assert_eq!(source_path(2023, 5), Path::new("src/year2023/day05.rs"));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::error::{parse_lines, ParseError, SolveError};
 use std::cmp::Ordering;
 
 /// Returns the value of the first digit found in the given string.
@@ -5,7 +6,7 @@ use std::cmp::Ordering;
 /// # Arguments
 ///
 /// 'text' - The string to search for the first digit.
-fn get_first_digit(text: &str) -> Option<u32> {
+pub fn get_first_digit(text: &str) -> Option<u32> {
     text.chars().filter_map(|c| c.to_digit(10)).next()
 }
 
@@ -14,7 +15,7 @@ fn get_first_digit(text: &str) -> Option<u32> {
 /// # Arguments
 ///
 /// 'text' - The string to search for the last digit.
-fn get_last_digit(text: &str) -> Option<u32> {
+pub fn get_last_digit(text: &str) -> Option<u32> {
     text.chars().rev().filter_map(|c| c.to_digit(10)).next()
 }
 
@@ -62,7 +63,7 @@ where
 /// # Arguments
 ///
 /// 'text' - The string to search for the first digit or digit name.
-fn get_first_digit_or_name(text: &str) -> Option<u32> {
+pub fn get_first_digit_or_name(text: &str) -> Option<u32> {
     get_sorted_digit_or_name(text, |(_, a), (_, b)| a.cmp(b))
 }
 
@@ -72,36 +73,37 @@ fn get_first_digit_or_name(text: &str) -> Option<u32> {
 /// # Arguments
 ///
 /// 'text' - The string to search for the last digit or digit name.
-fn get_last_digit_or_name(text: &str) -> Option<u32> {
+pub fn get_last_digit_or_name(text: &str) -> Option<u32> {
     get_sorted_digit_or_name(text, |(_, b), (_, a)| a.cmp(b))
 }
 
-fn process_input<F, G>(input: &str, first: F, last: G) -> u32
+fn process_input<F, G>(input: &str, first: F, last: G) -> Result<u32, SolveError>
 where
     F: Fn(&str) -> Option<u32>,
     G: Fn(&str) -> Option<u32>,
 {
-    input
-        .lines()
-        .map(|line| (first(line).unwrap(), last(line).unwrap()))
-        .map(|(f, l)| 10 * f + l)
-        .sum()
+    let values = parse_lines(input, |line| match (first(line), last(line)) {
+        (Some(f), Some(l)) => Ok(10 * f + l),
+        _ => Err(ParseError::new(line, line, "a calibration digit")),
+    })?;
+
+    Ok(values.iter().sum())
 }
 
 /// Returns the sum of the calibration values found in each line of the input.
-pub fn part_one(input: &str) -> u32 {
+pub fn part_one(input: &str) -> Result<u32, SolveError> {
     process_input(input, get_first_digit, get_last_digit)
 }
 
 /// Returns the sum of the calibration values found in each line of the input, taking into account
 /// that the calibration values may be spelled out by name rather than using digit characters.
-pub fn part_two(input: &str) -> u32 {
+pub fn part_two(input: &str) -> Result<u32, SolveError> {
     process_input(input, get_first_digit_or_name, get_last_digit_or_name)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day01::*;
+    use crate::year2023::day01::*;
 
     #[test]
     fn can_retrieve_first_digit() {
@@ -155,7 +157,7 @@ pqr3stu8vwx
 a1b2c3d4e5f
 treb7uchet"
             ),
-            142
+            Ok(142)
         );
     }
 
@@ -171,7 +173,15 @@ xtwone3four
 zoneight234
 7pqrstsixteen"
             ),
-            281
+            Ok(281)
+        );
+    }
+
+    #[test]
+    fn line_without_digits_is_an_error() {
+        assert_eq!(
+            part_one("1abc2\npqrstu").unwrap_err().to_string(),
+            "line 2, column 1: expected a calibration digit but found 'pqrstu'"
         );
     }
 }
@@ -1,11 +1,12 @@
+use crate::error::{parse_lines, parse_value, split_value, strip_value, ParseError, SolveError};
 use std::str::FromStr;
 
 /// Representation of counts of cube by color that are pulled from the bag.
 #[derive(Debug, PartialEq)]
-struct ColorCount {
-    red: u32,
-    green: u32,
-    blue: u32,
+pub struct ColorCount {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
 }
 
 impl ColorCount {
@@ -29,7 +30,7 @@ impl ColorCount {
     ///
     /// 'other' - the dice count to check to see if it could have been pulled from a bag containing
     /// this dice count.
-    fn is_possible(&self, other: &ColorCount) -> bool {
+    pub fn is_possible(&self, other: &ColorCount) -> bool {
         other.red <= self.red && other.green <= self.green && other.blue <= self.blue
     }
 
@@ -39,7 +40,7 @@ impl ColorCount {
     /// # Arguments
     ///
     /// 'results' - the collection of color counts for which to calculate the power.
-    fn calculate_power(color_counts: Vec<ColorCount>) -> u32 {
+    pub fn calculate_power(color_counts: Vec<ColorCount>) -> u32 {
         let mut max_counts = ColorCount {
             red: 0,
             green: 0,
@@ -62,11 +63,8 @@ impl ColorCount {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-struct ColorCountParseError {}
-
 impl FromStr for ColorCount {
-    type Err = ColorCountParseError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut red = 0;
@@ -76,8 +74,13 @@ impl FromStr for ColorCount {
         let parts = s.split(',');
         for part in parts {
             let mut values = part.split_whitespace();
-            let count: u32 = values.next().unwrap().parse().unwrap();
-            let color = values.next().unwrap();
+            let count: u32 = values
+                .next()
+                .map(|v| parse_value(s, v, "a cube count"))
+                .unwrap_or_else(|| Err(ParseError::new(s, &part[part.len()..], "a cube count")))?;
+            let color = values
+                .next()
+                .ok_or_else(|| ParseError::new(s, &part[part.len()..], "a color"))?;
 
             match color {
                 "red" => {
@@ -90,7 +93,7 @@ impl FromStr for ColorCount {
                     blue += count;
                 }
                 _ => {
-                    return Err(ColorCountParseError {});
+                    return Err(ParseError::new(s, color, "'red', 'green' or 'blue'"));
                 }
             }
         }
@@ -99,42 +102,35 @@ impl FromStr for ColorCount {
     }
 }
 
-/// Returns the ID if the game described in the given input, or None if it could not be found.
+/// Represents a game result, including its identifier and color counts from each cube pull.
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    pub id: u32,
+    pub results: Vec<ColorCount>,
+}
+
+/// Returns the ID of the game described in the given input.
 ///
 /// # Argument
 ///
 /// 'text' - The game results from which to extract the ID.
-fn get_game_id(text: &str) -> Option<u32> {
-    let text = text.strip_prefix("Game ")?;
-    let index = text.find(':')?;
-    text[..index].parse().ok()
-}
-
-/// Represents a game result, including its identifier and color counts from each cube pull.
-#[derive(Debug, PartialEq)]
-struct Game {
-    id: u32,
-    results: Vec<ColorCount>,
+fn get_game_id(text: &str) -> Result<u32, ParseError> {
+    let rest = strip_value(text, text, "Game ")?;
+    let (id, _) = split_value(text, rest, ":")?;
+    parse_value(text, id, "a game ID")
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
-struct GameParseError {}
-
 impl FromStr for Game {
-    type Err = GameParseError;
+    type Err = ParseError;
 
     fn from_str(text: &str) -> Result<Self, Self::Err> {
-        let id = get_game_id(text).ok_or(GameParseError {})?;
-
-        let text = text
-            .strip_prefix(&format!("Game {id}: "))
-            .ok_or(GameParseError {})?;
+        let id = get_game_id(text)?;
+        let (_, rest) = split_value(text, text, ": ")?;
 
-        let results = text.split(';');
+        let results = rest.split(';');
         let results: Vec<_> = results
-            .map(ColorCount::from_str)
-            .map(Result::unwrap)
-            .collect();
+            .map(|result| ColorCount::from_str(result).map_err(|e| e.within(text, result)))
+            .collect::<Result<_, _>>()?;
 
         Ok(Game { id, results })
     }
@@ -146,24 +142,24 @@ impl FromStr for Game {
 /// # Arguments
 ///
 /// 'input' - The input text containing the results of all the games.
-pub fn part_one(input: &str) -> u32 {
+pub fn part_one(input: &str) -> Result<u32, SolveError> {
     let max_cubes = ColorCount {
         red: 12,
         green: 13,
         blue: 14,
     };
 
-    input
-        .lines()
-        .map(Game::from_str)
-        .map(Result::unwrap)
+    let games = parse_lines(input, Game::from_str)?;
+
+    Ok(games
+        .iter()
         .filter(|game| {
             game.results
                 .iter()
                 .all(|result| max_cubes.is_possible(result))
         })
         .map(|game| game.id)
-        .sum()
+        .sum())
 }
 
 /// Calculates the sum of the power of all the results from all of the games played.
@@ -172,18 +168,18 @@ pub fn part_one(input: &str) -> u32 {
 ///
 ///
 /// 'input' - The input text containing the results of all the games.
-pub fn part_two(input: &str) -> u32 {
-    input
-        .lines()
-        .map(Game::from_str)
-        .map(Result::unwrap)
+pub fn part_two(input: &str) -> Result<u32, SolveError> {
+    let games = parse_lines(input, Game::from_str)?;
+
+    Ok(games
+        .into_iter()
         .map(|game| ColorCount::calculate_power(game.results))
-        .sum()
+        .sum())
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day02::*;
+    use crate::year2023::day02::*;
 
     #[test]
     fn parsing_color_counts() {
@@ -246,13 +242,16 @@ mod tests {
     fn retrieving_the_game_id() {
         assert_eq!(
             get_game_id("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"),
-            Some(1)
+            Ok(1)
         );
         assert_eq!(
             get_game_id("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red"),
-            Some(3)
+            Ok(3)
+        );
+        assert_eq!(
+            get_game_id("Some random string").unwrap_err().to_string(),
+            "line 1, column 1: expected 'Game ' but found 'Some '"
         );
-        assert_eq!(get_game_id("Some random string"), None);
     }
 
     #[test]
@@ -284,6 +283,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn malformed_games_are_an_error() {
+        assert_eq!(
+            part_one("Game 1: 3 blue, 4 red\nGame 2: 1 blue, 2 grey")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 19: expected 'red', 'green' or 'blue' but found 'grey'"
+        );
+        assert_eq!(
+            part_one("Game x: 3 blue").unwrap_err().to_string(),
+            "line 1, column 6: expected a game ID but found 'x'"
+        );
+        assert_eq!(
+            part_two("Game 1: 3 blue; 4").unwrap_err().to_string(),
+            "line 1, column 18: expected a color but found nothing"
+        );
+    }
+
     #[test]
     fn part_one_correct() {
         assert_eq!(
@@ -294,7 +311,7 @@ Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
 Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
 Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"
             ),
-            8
+            Ok(8)
         );
     }
 
@@ -308,7 +325,7 @@ Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
 Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
 Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"
             ),
-            2286
+            Ok(2286)
         );
     }
 }
@@ -1,10 +1,12 @@
+use crate::error::{ParseError, SolveError};
+use crate::grid::Grid;
 use std::str::FromStr;
 
 /// A symbol located at a indexed location
 #[derive(Debug, PartialEq)]
-struct Symbol {
-    value: char,
-    index: usize,
+pub struct Symbol {
+    pub value: char,
+    pub index: usize,
 }
 
 impl Symbol {
@@ -16,10 +18,10 @@ impl Symbol {
 
 /// A number at an indexed location
 #[derive(Debug, PartialEq)]
-struct Number {
-    value: u32,
-    start: usize,
-    end: usize,
+pub struct Number {
+    pub value: u32,
+    pub start: usize,
+    pub end: usize,
 }
 
 impl Number {
@@ -31,22 +33,45 @@ impl Number {
 
 /// A single row from the schematic containing all numbers and symbols and their locations.
 #[derive(Debug, PartialEq)]
-struct Row {
-    numbers: Vec<Number>,
-    symbols: Vec<Symbol>,
+pub struct Row {
+    pub numbers: Vec<Number>,
+    pub symbols: Vec<Symbol>,
 }
 
 impl FromStr for Row {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = parse_schematic(s)?;
+        Row::try_from(grid.row(0))
+    }
+}
+
+impl TryFrom<&[char]> for Row {
+    type Error = ParseError;
+
+    fn try_from(value: &[char]) -> Result<Self, Self::Error> {
+        let text: String = value.iter().collect();
+
         Ok(Row {
-            numbers: get_indexed_numbers(s),
-            symbols: get_symbol_indices(s),
+            numbers: get_indexed_numbers(&text)?,
+            symbols: get_symbol_indices(&text),
         })
     }
 }
 
+/// Parses the schematic into a grid of characters, each of which must be a digit, '.' or a
+/// symbol.
+///
+/// # Arguments
+///
+/// 'schematic' - The schematic to parse.
+pub fn parse_schematic(schematic: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(schematic, "a digit, '.' or a symbol", |c| {
+        (c.is_ascii_digit() || c.is_ascii_punctuation()).then_some(c)
+    })
+}
+
 /// Returns the locations and values of all symbols in the row.
 ///
 /// #Argument
@@ -55,27 +80,38 @@ impl FromStr for Row {
 fn get_symbol_indices(text: &str) -> Vec<Symbol> {
     text.chars()
         .enumerate()
-        .filter(|(_, c)| !c.is_numeric() && *c != '.')
+        .filter(|(_, c)| !c.is_ascii_digit() && *c != '.')
         .map(|(i, c)| Symbol::new(c, i))
         .collect()
 }
 
-/// Returns the locations and values of all numbers in the row.
+/// Returns the locations and values of all numbers in the row, or an error for a number too
+/// large to hold.
 ///
 /// #Argument
 ///
 /// 'text' - The schematic row to parse.
-fn get_indexed_numbers(text: &str) -> Vec<Number> {
+fn get_indexed_numbers(text: &str) -> Result<Vec<Number>, ParseError> {
     let mut result = vec![];
-    let mut total = 0;
+    let mut total: u32 = 0;
     let mut start = usize::MAX;
+    let mut start_byte = 0;
 
-    for (i, c) in text.chars().enumerate() {
-        if c.is_numeric() {
-            total = 10 * total + c.to_digit(10).unwrap();
+    for (i, (byte, c)) in text.char_indices().enumerate() {
+        if let Some(digit) = c.to_digit(10) {
             if start == usize::MAX {
-                start = i
+                start = i;
+                start_byte = byte;
             }
+            total = total
+                .checked_mul(10)
+                .and_then(|total| total.checked_add(digit))
+                .ok_or_else(|| {
+                    let digits = &text[start_byte..];
+                    let end = digits.find(|c: char| !c.is_ascii_digit());
+                    let digits = &digits[..end.unwrap_or(digits.len())];
+                    ParseError::new(text, digits, "a number that fits in 32 bits")
+                })?;
         } else if start < usize::MAX {
             result.push(Number::new(total, start, i));
             start = usize::MAX;
@@ -88,7 +124,7 @@ fn get_indexed_numbers(text: &str) -> Vec<Number> {
         result.push(Number::new(total, start, text.len()));
     }
 
-    result
+    Ok(result)
 }
 
 /// Calculates the sum of all numbers in the middle row that have symbols adjacent to them in
@@ -99,7 +135,7 @@ fn get_indexed_numbers(text: &str) -> Vec<Number> {
 /// 'a' - The row above row 'b'.
 /// 'b' - The row with the numbers to identify any sum.
 /// 'c' - The row below row 'b'.
-fn calculate_sum(a: &Row, b: &Row, c: &Row) -> u32 {
+fn calculate_sum(a: &Row, b: &Row, c: &Row) -> u64 {
     let all_symbols: Vec<_> = a
         .symbols
         .iter()
@@ -115,19 +151,19 @@ fn calculate_sum(a: &Row, b: &Row, c: &Row) -> u32 {
                 .filter(|s| n.start <= s.index + 1)
                 .any(|s| n.end >= s.index)
         })
-        .map(|n| n.value)
+        .map(|n| u64::from(n.value))
         .sum()
 }
 
 /// Calculates the gear ratio for all '*'s in row 'b' using the adjacent numbers in all
-/// three provided rows.
+/// three provided rows, or None if the total is too large to hold.
 ///
 /// #Argument
 ///
 /// 'a' - The row above row 'b'.
 /// 'b' - The row with the '*' symbols to locate and calculate.
 /// 'c' - The row below row 'b'.
-fn calculate_product(a: &Row, b: &Row, c: &Row) -> u32 {
+fn calculate_product(a: &Row, b: &Row, c: &Row) -> Option<u64> {
     let all_numbers: Vec<_> = a
         .numbers
         .iter()
@@ -147,8 +183,8 @@ fn calculate_product(a: &Row, b: &Row, c: &Row) -> u32 {
             result
         })
         .filter(|n| n.len() == 2)
-        .map(|n| n[0].value * n[1].value)
-        .sum()
+        .map(|n| u64::from(n[0].value) * u64::from(n[1].value))
+        .try_fold(0, u64::checked_add)
 }
 
 /// Scans the entire schematic and calculates a value based upon it based on the provided
@@ -158,20 +194,24 @@ fn calculate_product(a: &Row, b: &Row, c: &Row) -> u32 {
 ///
 /// 'schematic' - The schematic to scan.
 ///
-/// 'func' - The calculation function to be used to produce a value from the schematic.
-fn scan_schematic<F>(schematic: &str, func: F) -> u32
+/// 'func' - The calculation function to be used to produce a value from the schematic, giving
+/// None if the value is too large to hold.
+fn scan_schematic<F>(schematic: &str, func: F) -> Result<u64, SolveError>
 where
-    F: Fn(&Row, &Row, &Row) -> u32,
+    F: Fn(&Row, &Row, &Row) -> Option<u64>,
 {
-    let parsed: Vec<_> = schematic
-        .lines()
-        .map(Row::from_str)
-        .map(Result::unwrap)
-        .collect();
+    let parsed = parse_schematic(schematic)?
+        .rows()
+        .enumerate()
+        .map(|(i, row)| Row::try_from(row).map_err(|e| SolveError::from(e).offset_line(i)))
+        .collect::<Result<Vec<_>, _>>()?;
 
-    let empty_row = "".parse().unwrap();
+    let empty_row = Row {
+        numbers: vec![],
+        symbols: vec![],
+    };
 
-    let mut total = 0;
+    let mut total: u64 = 0;
 
     for (i, r) in parsed.iter().enumerate() {
         let last = if i == 0 { &empty_row } else { &parsed[i - 1] };
@@ -181,10 +221,12 @@ where
             &parsed[i + 1]
         };
 
-        total += func(last, r, next);
+        total = func(last, r, next)
+            .and_then(|value| total.checked_add(value))
+            .ok_or_else(|| SolveError::at_line(i + 1, "The total is too large"))?;
     }
 
-    total
+    Ok(total)
 }
 
 /// Calculates the sum of all numbers in the schematic that have an adjacent symbol.
@@ -192,8 +234,8 @@ where
 /// #Argument
 ///
 /// 'input' - The input schematic.
-pub fn part_one(input: &str) -> u32 {
-    scan_schematic(input, calculate_sum)
+pub fn part_one(input: &str) -> Result<u64, SolveError> {
+    scan_schematic(input, |a, b, c| Some(calculate_sum(a, b, c)))
 }
 
 /// Calculates the product of all gears in the schematic that have two adjacent numbers.
@@ -201,13 +243,13 @@ pub fn part_one(input: &str) -> u32 {
 /// #Argument
 ///
 /// 'input' - The input schematic.
-pub fn part_two(input: &str) -> u32 {
+pub fn part_two(input: &str) -> Result<u64, SolveError> {
     scan_schematic(input, calculate_product)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day03::*;
+    use crate::year2023::day03::*;
 
     #[test]
     fn find_symbols() {
@@ -229,15 +271,15 @@ mod tests {
 
     #[test]
     fn find_numbers() {
-        assert_eq!(get_indexed_numbers("...*......"), vec![]);
+        assert_eq!(get_indexed_numbers("...*......"), Ok(vec![]));
         assert_eq!(
             get_indexed_numbers("..35..633."),
-            vec![Number::new(35, 2, 4), Number::new(633, 6, 9)]
+            Ok(vec![Number::new(35, 2, 4), Number::new(633, 6, 9)])
         );
-        assert_eq!(get_indexed_numbers("...$.*...."), vec![]);
+        assert_eq!(get_indexed_numbers("...$.*...."), Ok(vec![]));
         assert_eq!(
             get_indexed_numbers("35.....633"),
-            vec![Number::new(35, 0, 2), Number::new(633, 7, 10)]
+            Ok(vec![Number::new(35, 0, 2), Number::new(633, 7, 10)])
         );
     }
 
@@ -727,7 +769,7 @@ mod tests {
                 &Row::from_str("......#...").unwrap(),
                 &Row::from_str("617*......").unwrap(),
             ),
-            0
+            Some(0)
         );
         assert_eq!(
             calculate_product(
@@ -735,7 +777,7 @@ mod tests {
                 &Row::from_str("...*......").unwrap(),
                 &Row::from_str("..35..633.").unwrap(),
             ),
-            16345
+            Some(16345)
         );
         assert_eq!(
             calculate_product(
@@ -743,7 +785,7 @@ mod tests {
                 &Row::from_str("...$.*....").unwrap(),
                 &Row::from_str(".664.598..").unwrap(),
             ),
-            451490
+            Some(451490)
         );
     }
 
@@ -762,7 +804,7 @@ mod tests {
 ...$.*....
 .664.598.."
             ),
-            4361
+            Ok(4361)
         );
     }
 
@@ -781,7 +823,32 @@ mod tests {
 ...$.*....
 .664.598.."
             ),
-            467835
+            Ok(467835)
+        );
+    }
+
+    #[test]
+    fn malformed_schematics_are_an_error() {
+        assert_eq!(
+            part_one("467..114..\n...*..x...").unwrap_err().to_string(),
+            "line 2, column 7: expected a digit, '.' or a symbol but found 'x'"
+        );
+        assert_eq!(
+            part_one("467..114........\n...*.99999999999")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 6: expected a number that fits in 32 bits but found '99999999999'"
+        );
+        assert_eq!(
+            part_two(
+                "4294967295.
+*..........
+4294967295.
+...........
+4294967295*
+4294967295."
+            ),
+            Err(SolveError::at_line(5, "The total is too large"))
         );
     }
 }
@@ -1,56 +1,32 @@
+use crate::error::{parse_lines, parse_value, split_value, strip_value, ParseError, SolveError};
 use std::collections::HashSet;
 use std::str::FromStr;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct Card {
-    id: u32,
-    winning: Vec<u32>,
-    numbers: Vec<u32>,
-}
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-struct CardParseError {
-    message: &'static str
-}
-
-impl CardParseError {
-    fn msg(message: &'static str) -> CardParseError {
-        CardParseError { message }
-    }
+pub struct Card {
+    pub id: u32,
+    pub winning: Vec<u32>,
+    pub numbers: Vec<u32>,
 }
 
 impl FromStr for Card {
-    type Err = CardParseError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let card = s.strip_prefix("Card ").ok_or(CardParseError::msg("Missing 'Card' prefix"))?;
-
-        let mut card_parts = card.split(':');
-        let id: u32 = card_parts
-            .next()
-            .ok_or(CardParseError::msg("Failed find card ID"))?
-            .trim()
-            .parse()
-            .map_err(|_| CardParseError::msg("Failed to parse card ID"))?;
-
-        let card = card_parts.next().ok_or(CardParseError::msg("Missing number sets on card"))?;
-
-        let mut card_parts = card.split('|');
-        let winning: Result<Vec<_>, _> = card_parts
-            .next()
-            .ok_or(CardParseError::msg("Failed to find first group of card numbers"))?
-            .split_whitespace()
-            .map(u32::from_str)
-            .collect();
-        let winning = winning.map_err(|_| CardParseError::msg("Failed to parse number from first group"))?;
+        let card = strip_value(s, s, "Card ")?;
 
-        let numbers: Result<Vec<_>, _> = card_parts
-            .next()
-            .ok_or(CardParseError::msg("Failed to find second group of card numbers"))?
+        let (id, card) = split_value(s, card, ":")?;
+        let id: u32 = parse_value(s, id.trim(), "a card ID")?;
+
+        let (winning, numbers) = split_value(s, card, "|")?;
+        let winning = winning
+            .split_whitespace()
+            .map(|n| parse_value(s, n, "a winning number"))
+            .collect::<Result<Vec<_>, _>>()?;
+        let numbers = numbers
             .split_whitespace()
-            .map(u32::from_str)
-            .collect();
-        let numbers = numbers.map_err(|_| CardParseError::msg("Failed to parse card number from second group"))?;
+            .map(|n| parse_value(s, n, "a card number"))
+            .collect::<Result<Vec<_>, _>>()?;
 
         Ok(Card {
             id,
@@ -61,16 +37,17 @@ impl FromStr for Card {
 }
 
 impl Card {
-    fn score(&self) -> u32 {
+    /// Returns the score of the card, or None if it has too many matches for the score to fit.
+    pub fn score(&self) -> Option<u32> {
         let matches = self.matches();
         if matches == 0 {
-            0
+            Some(0)
         } else {
-            1 << (self.matches() - 1)
+            1u32.checked_shl(u32::try_from(matches - 1).ok()?)
         }
     }
 
-    fn matches(&self) -> usize {
+    pub fn matches(&self) -> usize {
         let mut winning: HashSet<&u32> = self.winning.iter().collect();
         winning.retain(|v| self.numbers.contains(v));
 
@@ -83,13 +60,20 @@ impl Card {
 /// #Argument
 ///
 /// 'input' - The input.
-pub fn part_one(input: &str) -> u32 {
-    input
-        .lines()
-        .map(Card::from_str)
-        .map(Result::unwrap)
-        .map(|c| c.score())
-        .sum()
+pub fn part_one(input: &str) -> Result<u32, SolveError> {
+    let cards = parse_lines(input, Card::from_str)?;
+
+    cards
+        .iter()
+        .enumerate()
+        .try_fold(0u32, |total, (index, card)| {
+            card.score()
+                .and_then(|score| total.checked_add(score))
+                .ok_or(SolveError::at_line(
+                    index + 1,
+                    "The total score is too large",
+                ))
+        })
 }
 
 ///
@@ -97,12 +81,8 @@ pub fn part_one(input: &str) -> u32 {
 /// #Argument
 ///
 /// 'input' - The input.
-pub fn part_two(input: &str) -> u32 {
-    let cards: Vec<_> = input
-        .lines()
-        .map(Card::from_str)
-        .map(Result::unwrap)
-        .collect();
+pub fn part_two(input: &str) -> Result<u32, SolveError> {
+    let cards = parse_lines(input, Card::from_str)?;
 
     let mut counts: Vec<u32> = vec![1; cards.len()];
 
@@ -110,19 +90,35 @@ pub fn part_two(input: &str) -> u32 {
         let current_count = counts[index];
 
         let matches = card.matches();
-        for _ in 0..current_count {
-            for i in 0..matches {
-                counts[index + i + 1] += 1;
-            }
+        if index + matches >= counts.len() {
+            return Err(SolveError::at_line(
+                index + 1,
+                format!(
+                    "Card {} wins copies of cards past the end of the table",
+                    card.id
+                ),
+            ));
+        }
+        for i in 0..matches {
+            counts[index + i + 1] =
+                counts[index + i + 1]
+                    .checked_add(current_count)
+                    .ok_or(SolveError::at_line(
+                        index + 1,
+                        "Too many copies of the cards",
+                    ))?;
         }
     }
 
-    counts.iter().sum()
+    counts
+        .iter()
+        .try_fold(0u32, |total, count| total.checked_add(*count))
+        .ok_or(SolveError::new("Too many copies of the cards"))
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day04::*;
+    use crate::year2023::day04::*;
 
     #[test]
     fn parse_card() {
@@ -162,37 +158,37 @@ mod tests {
             Card::from_str("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53")
                 .unwrap()
                 .score(),
-            8
+            Some(8)
         );
         assert_eq!(
             Card::from_str("Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19")
                 .unwrap()
                 .score(),
-            2
+            Some(2)
         );
         assert_eq!(
             Card::from_str("Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1")
                 .unwrap()
                 .score(),
-            2
+            Some(2)
         );
         assert_eq!(
             Card::from_str("Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83")
                 .unwrap()
                 .score(),
-            1
+            Some(1)
         );
         assert_eq!(
             Card::from_str("Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36")
                 .unwrap()
                 .score(),
-            0
+            Some(0)
         );
         assert_eq!(
             Card::from_str("Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11")
                 .unwrap()
                 .score(),
-            0
+            Some(0)
         );
     }
 
@@ -207,7 +203,7 @@ Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
 Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
 Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"
             ),
-            13
+            Ok(13)
         );
     }
 
@@ -222,7 +218,39 @@ Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
 Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
 Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"
             ),
-            30
+            Ok(30)
+        );
+    }
+
+    #[test]
+    fn malformed_card_is_an_error() {
+        assert_eq!(
+            part_one("Card 1: 41 48 | 83 86\nCard 2: 13 32 61 30")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 20: expected '|' but found nothing"
+        );
+        assert_eq!(
+            part_two("Card 1: 41 48 | 83 8x").unwrap_err().to_string(),
+            "line 1, column 20: expected a card number but found '8x'"
+        );
+    }
+
+    #[test]
+    fn unreachable_copies_are_an_error() {
+        assert_eq!(
+            part_two("Card 1: 41 48 83 | 83 48 17\nCard 2: 13 32 20 | 61 30 68")
+                .unwrap_err()
+                .to_string(),
+            "line 1: Card 1 wins copies of cards past the end of the table"
+        );
+
+        let numbers: Vec<String> = (1..=40).map(|n| n.to_string()).collect();
+        let card = format!("Card 1: {} | {}", numbers.join(" "), numbers.join(" "));
+        assert_eq!(Card::from_str(&card).unwrap().score(), None);
+        assert_eq!(
+            part_one(&card).unwrap_err().to_string(),
+            "line 1: The total score is too large"
         );
     }
 }
@@ -0,0 +1,500 @@
+use crate::error::{parse_value, strip_value, ParseError, SolveError};
+use crate::interval::{merge, Interval};
+use std::str::FromStr;
+
+/// Parses one of the numbers in the almanac, none of which can be negative.
+///
+/// # Arguments
+///
+/// 'text' - The text being parsed.
+///
+/// 'value' - The slice of the text holding the number.
+///
+/// 'expected' - A description of the number.
+fn parse_number(text: &str, value: &str, expected: &str) -> Result<i64, ParseError> {
+    let number = parse_value::<i64>(text, value, expected)?;
+    if number < 0 {
+        return Err(ParseError::new(text, value, expected));
+    }
+    Ok(number)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeedList {
+    pub seeds: Vec<i64>,
+}
+
+impl FromStr for SeedList {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let seeds = strip_value(s, s, "seeds: ")?;
+        let result: Result<Vec<_>, _> = seeds
+            .split_whitespace()
+            .map(|n| parse_number(s, n, "a seed number"))
+            .collect();
+
+        Ok(SeedList { seeds: result? })
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialMapper {
+    pub start: i64,
+    pub end: i64,
+    pub diff: i64,
+}
+
+impl FromStr for PartialMapper {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+
+        let mut next_value = |expected: &str| {
+            parts
+                .next()
+                .ok_or_else(|| ParseError::missing(s, expected))
+                .and_then(|value| parse_number(s, value, expected).map(|n| (value, n)))
+        };
+
+        let (_, min_output) = next_value("a destination range start")?;
+        let (_, min_input) = next_value("a source range start")?;
+        let (range_text, range) = next_value("a range length")?;
+
+        // As long as both ranges end where an i64 can reach, so does any value mapped by them
+        let end = min_input.checked_add(range);
+        if end.is_none() || min_output.checked_add(range).is_none() {
+            return Err(ParseError::new(s, range_text, "a shorter range length"));
+        }
+
+        Ok(PartialMapper {
+            start: min_input,
+            end: min_input + range,
+            diff: min_output - min_input,
+        })
+    }
+}
+
+impl PartialMapper {
+    /// Returns the range of inputs the partial applies to.
+    pub fn source(&self) -> Interval {
+        Interval::new(self.start, self.end)
+    }
+
+    pub fn eval(&self, input: i64) -> Option<i64> {
+        if input < self.start || input >= self.end {
+            None
+        } else {
+            Some(input + self.diff)
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Mapper {
+    pub name: String,
+    pub partials: Vec<PartialMapper>,
+}
+
+impl FromStr for Mapper {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+
+        let name = lines
+            .next()
+            .ok_or_else(|| ParseError::missing(s, "a map name"))?;
+        let name = name
+            .strip_suffix(" map:")
+            .ok_or_else(|| ParseError::new(s, name, "a map name ending ' map:'"))?;
+        let name = String::from(name);
+
+        let partials: Result<Vec<_>, _> = lines
+            .map(|line| PartialMapper::from_str(line).map_err(|e| e.within(s, line)))
+            .collect();
+        let mut partials = partials?;
+
+        partials.sort_by(|a, b| a.start.cmp(&b.start));
+
+        Ok(Mapper { name, partials })
+    }
+}
+
+impl Mapper {
+    pub fn eval(&self, input: i64) -> i64 {
+        self.partials
+            .iter()
+            .filter_map(|p| p.eval(input))
+            .next()
+            .unwrap_or(input)
+    }
+
+    pub fn eval_ranges(&self, input: &[Interval]) -> Vec<Interval> {
+        let mut result = vec![];
+
+        for range in input {
+            let mut unmapped = vec![*range];
+
+            for m in &self.partials {
+                let source = m.source();
+                let mut remaining = vec![];
+
+                for r in unmapped {
+                    // Map the part of the range covered by the partial, and leave the rest for
+                    // the other partials:
+                    if let Some(covered) = r.intersect(&source) {
+                        result.push(covered.shift(m.diff));
+                    }
+                    remaining.extend(r.subtract(&source));
+                }
+
+                unmapped = remaining;
+            }
+
+            // Anything not covered by a partial remains unchanged:
+            result.extend(unmapped);
+        }
+
+        // Applying the partials probably resulted in our output ranges being out of order, and
+        // some of them may now overlap, so merge them back into a sorted list:
+        merge(&result)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////////////////////////////////
+
+/// The number of maps needed to get from a seed to its location.
+const MAP_COUNT: usize = 7;
+
+/// Parses the almanac into the list of seeds and the maps to apply to them, in order.
+///
+/// # Arguments
+///
+/// 'input' - The almanac text.
+pub fn parse_almanac(input: &str) -> Result<(SeedList, Vec<Mapper>), SolveError> {
+    let mut seeds = None;
+    let mut mappers = vec![];
+
+    for section in input.split("\n\n") {
+        if seeds.is_none() {
+            seeds = Some(SeedList::from_str(section).map_err(|e| e.within(input, section))?);
+        } else {
+            mappers.push(Mapper::from_str(section).map_err(|e| e.within(input, section))?);
+        }
+    }
+
+    if mappers.len() != MAP_COUNT {
+        return Err(SolveError::new(format!(
+            "Expected {MAP_COUNT} maps but found {}",
+            mappers.len()
+        )));
+    }
+
+    Ok((seeds.unwrap(), mappers))
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_one(input: &str) -> Result<i64, SolveError> {
+    let (seeds, mappers) = parse_almanac(input)?;
+
+    seeds
+        .seeds
+        .iter()
+        .map(|s| mappers.iter().fold(*s, |value, mapper| mapper.eval(value)))
+        .min()
+        .ok_or(SolveError::at_line(1, "No seeds listed"))
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_two(input: &str) -> Result<i64, SolveError> {
+    let (seeds, mappers) = parse_almanac(input)?;
+
+    if seeds.seeds.is_empty() || seeds.seeds.len() % 2 != 0 {
+        return Err(SolveError::at_line(
+            1,
+            "Seeds must be listed in start/length pairs",
+        ));
+    }
+
+    let seeds = seeds
+        .seeds
+        .chunks(2)
+        .map(|chunk| {
+            let end = chunk[0].checked_add(chunk[1]);
+            let end = end.ok_or_else(|| SolveError::at_line(1, "A seed range is too long"))?;
+            Ok(Interval::new(chunk[0], end))
+        })
+        .collect::<Result<Vec<_>, SolveError>>()?;
+
+    // Make sure our input ranges are in order:
+    let seeds = merge(&seeds);
+
+    // Apply each of the mappers in sequence to the input ranges:
+    let seeds = mappers
+        .iter()
+        .fold(seeds, |seeds, mapper| mapper.eval_ranges(&seeds));
+
+    // Since the output ranges are sorted, the minimum value is simply the start of the first
+    // output range:
+    seeds
+        .first()
+        .map(|seed| seed.start)
+        .ok_or(SolveError::new("No seeds map to a location"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2023::day05::*;
+
+    #[test]
+    fn parse_seed_list() {
+        assert_eq!(
+            SeedList::from_str("seeds: 79 14 55 13"),
+            Ok(SeedList {
+                seeds: vec![79, 14, 55, 13]
+            })
+        );
+
+        assert!(SeedList::from_str("blah").is_err());
+        assert!(SeedList::from_str("seeds: 79 14 blah 13").is_err());
+    }
+
+    #[test]
+    fn parse_partial_maps() {
+        assert!(PartialMapper::from_str("50 98 2").is_ok());
+        assert!(PartialMapper::from_str("blah").is_err());
+        assert!(PartialMapper::from_str("50 blah 2").is_err());
+        assert!(PartialMapper::from_str("50 2").is_err());
+    }
+
+    #[test]
+    fn evaluate_partial_map() {
+        let map = PartialMapper::from_str("50 98 2").unwrap();
+
+        assert_eq!(map.eval(97), None);
+        assert_eq!(map.eval(98), Some(50));
+        assert_eq!(map.eval(99), Some(51));
+        assert_eq!(map.eval(100), None);
+    }
+
+    #[test]
+    fn parse_mappers() {
+        assert!(Mapper::from_str(
+            "seed-to-soil map:
+50 98 2
+52 50 48"
+        )
+        .is_ok());
+
+        assert!(Mapper::from_str("blah").is_err());
+    }
+
+    #[test]
+    fn evaluate_mapper() {
+        let mapper = Mapper::from_str(
+            "seed-to-soil map:
+50 98 2
+52 50 48",
+        )
+        .unwrap();
+
+        assert_eq!(mapper.eval(0), 0);
+        assert_eq!(mapper.eval(1), 1);
+
+        assert_eq!(mapper.eval(48), 48);
+        assert_eq!(mapper.eval(49), 49);
+        assert_eq!(mapper.eval(50), 52);
+        assert_eq!(mapper.eval(51), 53);
+
+        assert_eq!(mapper.eval(96), 98);
+        assert_eq!(mapper.eval(97), 99);
+        assert_eq!(mapper.eval(98), 50);
+        assert_eq!(mapper.eval(99), 51);
+    }
+
+    #[test]
+    fn eval_ranges() {
+        let mapper = Mapper::from_str(
+            "seed-to-soil map:
+50 98 2
+52 50 48",
+        )
+        .unwrap();
+
+        assert_eq!(mapper.eval_ranges(&[]), vec![]);
+        assert_eq!(
+            mapper.eval_ranges(&[Interval::new(0, 5)]),
+            vec![Interval::new(0, 5)]
+        );
+        assert_eq!(
+            mapper.eval_ranges(&[Interval::new(98, 99)]),
+            vec![Interval::new(50, 51)]
+        );
+        assert_eq!(
+            mapper.eval_ranges(&[Interval::new(45, 100)]),
+            vec![Interval::new(45, 100)]
+        );
+        assert_eq!(
+            mapper.eval_ranges(&[Interval::new(97, 99)]),
+            vec![Interval::new(50, 51), Interval::new(99, 100)]
+        );
+    }
+
+    #[test]
+    fn part_one_correct() {
+        assert_eq!(
+            part_one(
+                "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4"
+            ),
+            Ok(35)
+        );
+    }
+
+    #[test]
+    fn part_two_correct() {
+        assert_eq!(
+            part_two(
+                "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4"
+            ),
+            Ok(46)
+        );
+    }
+
+    #[test]
+    fn malformed_almanac_is_an_error() {
+        assert_eq!(
+            part_one("seeds: 79 14\n\nseed-to-soil map:\n50 98 x")
+                .unwrap_err()
+                .to_string(),
+            "line 4, column 7: expected a range length but found 'x'"
+        );
+        assert_eq!(
+            part_one("seeds: 79 14\n\nseed-to-soil map:\n50 98 2"),
+            Err(SolveError::new("Expected 7 maps but found 1"))
+        );
+        assert_eq!(
+            part_one("seeds: 79 x").unwrap_err().to_string(),
+            "line 1, column 11: expected a seed number but found 'x'"
+        );
+        assert_eq!(
+            part_two("seeds: 79 14\n\nseed-to-soil\n50 98 2")
+                .unwrap_err()
+                .to_string(),
+            "line 3, column 1: expected a map name ending ' map:' but found 'seed-to-soil'"
+        );
+    }
+
+    #[test]
+    fn large_numbers_are_an_error() {
+        assert_eq!(
+            part_one("seeds: 79 14\n\nseed-to-soil map:\n9223372036854775807 0 2")
+                .unwrap_err()
+                .to_string(),
+            "line 4, column 23: expected a shorter range length but found '2'"
+        );
+        assert_eq!(
+            part_one("seeds: 79 -14").unwrap_err().to_string(),
+            "line 1, column 11: expected a seed number but found '-14'"
+        );
+        assert_eq!(
+            part_one("seeds: 99999999999999999999")
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 8: expected a seed number but found '99999999999999999999'"
+        );
+
+        let maps = "\n\na map:\n0 0 1".repeat(7);
+        assert_eq!(
+            part_two(&format!("seeds: 9223372036854775807 1{maps}")),
+            Err(SolveError::at_line(1, "A seed range is too long"))
+        );
+        assert_eq!(
+            part_one(&format!("seeds: 9223372036854775807{maps}")),
+            Ok(9223372036854775807)
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use crate::error::{parse_value, strip_value, ParseError, SolveError};
+
+#[derive(Debug)]
+pub struct Outcome {
+    _min: i64,
+    _max: i64,
+    pub count: i64,
+}
+
+pub fn solve(t: i64, d: i64) -> Outcome {
+    // The solutions for this puzzle are all integer values 'x' that satisfy:
+    //
+    // (time - s) / 2 < x < (time + s) / 2
+    //
+    // where s = sqrt(time^2 - 4 * distance)
+
+    let tf = t as f64;
+    let df = d as f64;
+
+    let s = (tf * tf - 4.0 * df).sqrt();
+
+    let min = (tf - s) / 2.0;
+    let max = (tf + s) / 2.0;
+
+    // At this point, min and max are the two real values for x where the total distance will
+    // exactly equal the target, so we need to convert to integers:
+
+    let mut min = min.ceil() as i64;
+    let mut max = max.floor() as i64;
+
+    // In the edge case where the real value for x above was already an integer, then we need
+    // to ignore that value since we need our distance to be greater than the target, not equal,
+    // so the following filters out any edge cases that might have been included above
+    // (each while loop is expected to execute zero or one times, unless there are no integer
+    // solutions at all, in which case min ends up past max):
+    while min <= max && min * (t - min) <= d {
+        min += 1;
+    }
+    while max >= min && max * (t - max) <= d {
+        max -= 1;
+    }
+
+    // The number of solutions is now the number of integer values within the range:
+    let count = (max - min + 1).max(0);
+
+    Outcome {
+        _min: min,
+        _max: max,
+        count,
+    }
+}
+
+/// Returns the text following the given label on the given line of the input.
+///
+/// # Arguments
+///
+/// 'input' - The input.
+///
+/// 'line' - The line number (starting from 1) to read.
+///
+/// 'label' - The label expected at the start of the line, such as "Time:".
+fn get_values<'a>(input: &'a str, line: usize, label: &str) -> Result<&'a str, ParseError> {
+    let text = input
+        .lines()
+        .nth(line - 1)
+        .ok_or_else(|| ParseError::missing(input, format!("a line starting '{label}'")))?;
+
+    strip_value(input, text, label)
+}
+
+/// Parses each whitespace separated number on the given line of the input.
+///
+/// # Arguments
+///
+/// 'input' - The input.
+///
+/// 'line' - The line number (starting from 1) to read.
+///
+/// 'label' - The label expected at the start of the line, such as "Time:".
+fn parse_values(input: &str, line: usize, label: &str) -> Result<Vec<i64>, ParseError> {
+    get_values(input, line, label)?
+        .split_whitespace()
+        .map(|n| parse_value(input, n, "a number"))
+        .collect()
+}
+
+/// Parses the digits on the given line of the input as a single number, ignoring the spaces
+/// between them.
+///
+/// # Arguments
+///
+/// 'input' - The input.
+///
+/// 'line' - The line number (starting from 1) to read.
+///
+/// 'label' - The label expected at the start of the line, such as "Time:".
+fn parse_joined_value(input: &str, line: usize, label: &str) -> Result<i64, ParseError> {
+    let values = get_values(input, line, label)?;
+    for n in values.split_whitespace() {
+        parse_value::<i64>(input, n, "a number")?;
+    }
+
+    values
+        .replace(' ', "")
+        .parse()
+        .map_err(|_| ParseError::new(input, values.trim(), "a number"))
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_one(input: &str) -> Result<i64, SolveError> {
+    let times = parse_values(input, 1, "Time:")?;
+    let distances = parse_values(input, 2, "Distance:")?;
+
+    let product = times
+        .iter()
+        .zip(distances.iter())
+        .map(|(t, d)| solve(*t, *d))
+        .map(|o| o.count)
+        .product();
+
+    Ok(product)
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_two(input: &str) -> Result<i64, SolveError> {
+    let time = parse_joined_value(input, 1, "Time:")?;
+    let distance = parse_joined_value(input, 2, "Distance:")?;
+
+    let outcome = solve(time, distance);
+
+    Ok(outcome.count)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2023::day06::*;
+
+    #[test]
+    fn part_one_correct() {
+        assert_eq!(
+            part_one(
+                "Time:      7  15   30
+Distance:  9  40  200"
+            ),
+            Ok(288)
+        );
+    }
+
+    #[test]
+    fn part_two_correct() {
+        assert_eq!(
+            part_two(
+                "Time:      7  15   30
+Distance:  9  40  200"
+            ),
+            Ok(71503)
+        );
+    }
+
+    #[test]
+    fn unbeatable_records_have_no_solutions() {
+        assert_eq!(solve(3, 2).count, 0);
+        assert_eq!(solve(4, 4).count, 0);
+        assert_eq!(solve(5, 100).count, 0);
+        assert_eq!(solve(4, 3).count, 1);
+    }
+
+    #[test]
+    fn malformed_records_are_an_error() {
+        assert_eq!(
+            part_one("Time: 7 15\nDistance: 9 x")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 13: expected a number but found 'x'"
+        );
+        assert_eq!(
+            part_two("Time: 7 15").unwrap_err().to_string(),
+            "line 1, column 11: expected a line starting 'Distance:' but found nothing"
+        );
+        assert_eq!(
+            part_two("Time: 7 15\nDist: 9 40").unwrap_err().to_string(),
+            "line 2, column 1: expected 'Distance:' but found 'Dist: 9 4'"
+        );
+    }
+}
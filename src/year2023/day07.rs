@@ -1,9 +1,10 @@
+use crate::error::{parse_lines, parse_value, ParseError, SolveError};
 use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::str::FromStr;
 
 #[derive(Debug, Ord, PartialOrd, Eq, PartialEq)]
-enum HandType {
+pub enum HandType {
     HighCard,
     OnePair,
     TwoPair,
@@ -14,11 +15,14 @@ enum HandType {
 }
 
 impl FromStr for HandType {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((i, c)) = s.char_indices().find(|(_, c)| Hand::get_value(*c) < 0) {
+            return Err(ParseError::new(s, &s[i..i + c.len_utf8()], "a card"));
+        }
         if s.len() != 5 {
-            return Err(String::from("Hands must be of length 5"));
+            return Err(ParseError::new(s, s, "a hand of 5 cards"));
         }
 
         let mut card_map: HashMap<char, i32> = HashMap::new();
@@ -61,10 +65,10 @@ impl FromStr for HandType {
 }
 
 #[derive(Debug, Eq, PartialEq)]
-struct Hand {
-    hand_type: HandType,
-    hand: String,
-    bid: i32,
+pub struct Hand {
+    pub hand_type: HandType,
+    pub hand: String,
+    pub bid: i32,
 }
 
 impl PartialOrd for Hand {
@@ -90,18 +94,20 @@ impl Ord for Hand {
 }
 
 impl FromStr for Hand {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut parts = s.split_whitespace();
 
-        let hand = String::from(parts.next().unwrap());
-        let hand_type: HandType = hand.parse()?;
+        let hand = parts
+            .next()
+            .ok_or_else(|| ParseError::missing(s, "a hand"))?;
+        let hand_type: HandType = hand.parse().map_err(|e: ParseError| e.within(s, hand))?;
+        let hand = String::from(hand);
         let bid: i32 = parts
             .next()
-            .unwrap()
-            .parse()
-            .map_err(|_| "Error parsing bid")?;
+            .map(|bid| parse_value(s, bid, "a bid"))
+            .unwrap_or_else(|| Err(ParseError::missing(s, "a bid")))?;
 
         Ok(Hand {
             hand_type,
@@ -138,11 +144,8 @@ impl Hand {
 /// #Argument
 ///
 /// 'input' - The input.
-pub fn part_one(input: &str) -> i32 {
-    let mut hands: Vec<_> = input
-        .lines()
-        .map(|line| line.parse::<Hand>().unwrap())
-        .collect();
+pub fn part_one(input: &str) -> Result<i32, SolveError> {
+    let mut hands = parse_lines(input, Hand::from_str)?;
     hands.sort();
 
     let mut winnings = 0;
@@ -150,7 +153,7 @@ pub fn part_one(input: &str) -> i32 {
         winnings += ((i + 1) as i32) * h.bid;
     }
 
-    winnings
+    Ok(winnings)
 }
 
 ///
@@ -158,13 +161,16 @@ pub fn part_one(input: &str) -> i32 {
 /// #Argument
 ///
 /// 'input' - The input.
-pub fn part_two(input: &str) -> i32 {
+pub fn part_two(input: &str) -> Result<i32, SolveError> {
+    // Check the hands before the jokers become wildcards, so that any error shows the original text:
+    parse_lines(input, Hand::from_str)?;
+
     part_one(input.replace('J', "*").as_str())
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day07::*;
+    use crate::year2023::day07::*;
 
     #[test]
     fn hand_type_ordering() {
@@ -217,7 +223,7 @@ KK677 28
 KTJJT 220
 QQQJA 483"
             ),
-            6440
+            Ok(6440)
         );
     }
 
@@ -231,7 +237,23 @@ KK677 28
 KTJJT 220
 QQQJA 483"
             ),
-            5905
+            Ok(5905)
+        );
+    }
+
+    #[test]
+    fn malformed_hand_is_an_error() {
+        assert_eq!(
+            part_one("32T3K 765\nT55J5").unwrap_err().to_string(),
+            "line 2, column 6: expected a bid but found nothing"
+        );
+        assert_eq!(
+            part_two("32T3K 765\nT55J 684").unwrap_err().to_string(),
+            "line 2, column 1: expected a hand of 5 cards but found 'T55J'"
+        );
+        assert_eq!(
+            part_one("32T3K 765\nT55X5 684").unwrap_err().to_string(),
+            "line 2, column 4: expected a card but found 'X'"
         );
     }
 }
@@ -0,0 +1,328 @@
+use crate::direction::Direction;
+use crate::error::{split_value, strip_value, ParseError, SolveError};
+use crate::graph::Graph;
+use crate::parallel;
+use num::integer::lcm;
+
+/// The nodes of the network, named by their labels. Each node has two edges, leading to its left
+/// and right branches in that order.
+pub type Network<'a> = Graph<&'a str>;
+
+pub fn parse_node(s: &str) -> Result<(&str, (&str, &str)), ParseError> {
+    let (key, branches) = split_value(s, s, "=")?;
+
+    let branches = strip_value(s, branches.trim(), "(")?;
+    let branches = branches
+        .strip_suffix(')')
+        .ok_or_else(|| ParseError::missing(s, "')'"))?;
+    let (left, right) = split_value(s, branches, ",")?;
+
+    Ok((key.trim(), (left.trim(), right.trim())))
+}
+
+pub fn parse_input(s: &str) -> Result<(Vec<Direction>, Network<'_>), SolveError> {
+    let (directions, nodes) = s
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::missing(s, "a blank line after the directions"))?;
+
+    if directions.is_empty() {
+        return Err(ParseError::new(s, directions, "directions").into());
+    }
+    if let Some(i) = directions.find(|c| c != 'L' && c != 'R') {
+        let end = directions[i..]
+            .chars()
+            .next()
+            .map_or(i, |c| i + c.len_utf8());
+        return Err(ParseError::new(s, &directions[i..end], "'L' or 'R'").into());
+    }
+    let directions = directions
+        .chars()
+        .filter_map(Direction::from_char)
+        .collect();
+
+    let nodes: Vec<_> = nodes
+        .lines()
+        .map(|line| parse_node(line).map_err(|e| e.within(s, line)))
+        .collect::<Result<_, _>>()?;
+
+    let mut map = Network::new();
+    for (key, _) in &nodes {
+        map.add_node(*key);
+    }
+
+    // Make sure every branch leads somewhere, so that following the map can't get stuck:
+    for (key, (left, right)) in &nodes {
+        if let Some(missing) = [left, right].into_iter().find(|b| map.id(b).is_none()) {
+            return Err(ParseError::new(s, missing, "a known node").into());
+        }
+
+        map.add_edge(key, left, 1);
+        map.add_edge(key, right, 1);
+    }
+
+    Ok((directions, map))
+}
+
+/// Returns the index of the branch taken in the given direction. The directions are only ever
+/// left or right, as nothing else gets through parsing.
+fn branch(direction: Direction) -> usize {
+    match direction {
+        Direction::Left => 0,
+        _ => 1,
+    }
+}
+
+/// Follows the directions from a node, taking at least one step, until reaching an exit. Returns
+/// the exit and the position in the directions at that point, along with the number of steps
+/// taken, or None if no exit can be reached.
+fn calc_steps_to_exit<F>(
+    start: (usize, usize),
+    directions: &[Direction],
+    branches: &Network,
+    is_exit: F,
+) -> Option<((usize, usize), i32)>
+where
+    F: Fn(&str) -> bool,
+{
+    let (mut node, mut index) = start;
+    let mut count = 0;
+
+    // There are only so many combinations of node and position in the directions, so once the
+    // path has taken more steps than that without reaching an exit, it is going round in circles
+    let limit = directions.len() * branches.len();
+
+    loop {
+        node = branches.edges(node)[branch(directions[index])].0;
+        count += 1;
+        index = (index + 1) % directions.len();
+
+        if is_exit(branches.key(node)) {
+            return Some(((node, index), count));
+        }
+        if count as usize > limit {
+            return None;
+        }
+    }
+}
+
+fn is_zzz(s: &str) -> bool {
+    s == "ZZZ"
+}
+
+fn is_exit_node(s: &str) -> bool {
+    s.ends_with('Z')
+}
+
+fn calc_lcm(a: u64, b: u64) -> u64 {
+    lcm(a, b)
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_one(input: &str) -> Result<i32, SolveError> {
+    let model = parse_input(input)?;
+
+    let (directions, branches) = model;
+
+    let start = branches
+        .id(&"AAA")
+        .ok_or(SolveError::new("Missing starting node 'AAA'"))?;
+
+    calc_steps_to_exit((start, 0), &directions, &branches, is_zzz)
+        .map(|(_, count)| count)
+        .ok_or(SolveError::new("Node 'ZZZ' can't be reached from 'AAA'"))
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_two(input: &str) -> Result<u64, SolveError> {
+    let model = parse_input(input)?;
+
+    let (directions, branches) = model;
+    let nodes: Vec<_> = branches
+        .nodes()
+        .filter(|(_, s)| s.ends_with('A'))
+        .map(|(id, _)| (id, 0usize))
+        .collect();
+
+    // It's not expressed in the problem, but each starting node leads to a fixed sized loop
+    // leading repeatedly to an exit node, so we can optimize this solution to look for how many
+    // times each path needs to be looped before everything converges to an equal number of total
+    // moves...
+
+    // We'll start by going through each starting node and mapping it to a list of loop sizes.
+    // We'll also verify that every path does, indeed, result in a loop as a sanity check...
+
+    let loop_lengths = parallel::map(nodes, |node| {
+        let steps_to_exit = |start| {
+            calc_steps_to_exit(start, &directions, &branches, is_exit_node).ok_or_else(|| {
+                SolveError::new(format!(
+                    "No exit can be reached from '{}'",
+                    branches.key(node.0)
+                ))
+            })
+        };
+        let mut result = steps_to_exit(node)?;
+        let first_result = result;
+
+        // Verify that continuing the path results in a loop
+        for _ in 0..directions.len() {
+            let next_result = steps_to_exit(result.0)?;
+
+            if result.0 .0 != next_result.0 .0 || result.1 != next_result.1 {
+                return Err(SolveError::new(format!(
+                    "The path from '{}' does not loop back to the same exit",
+                    branches.key(node.0)
+                )));
+            }
+
+            if first_result.0 .1 == next_result.0 .1 {
+                // We've found our loop
+                break;
+            }
+
+            result = next_result;
+        }
+
+        Ok(result.1 as u64)
+    });
+
+    // We've verified the loop sizes, so calculate the minimum path length (LCM) of all the loops
+    let mut min_path_length = 0u64;
+
+    for loop_length in loop_lengths {
+        let loop_length = loop_length?;
+
+        if min_path_length == 0 {
+            min_path_length = loop_length;
+        } else {
+            min_path_length = calc_lcm(min_path_length, loop_length);
+        }
+    }
+
+    Ok(min_path_length)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2023::day08::*;
+
+    #[test]
+    fn parse_node_correctly() {
+        assert_eq!(parse_node("AAA = (BBB, CCC)"), Ok(("AAA", ("BBB", "CCC"))));
+        assert!(parse_node("AAA = BBB, CCC").is_err());
+    }
+
+    #[test]
+    fn parse_input_correctly() {
+        let (directions, map) = parse_input(
+            "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)",
+        )
+        .unwrap();
+
+        assert_eq!(
+            directions,
+            [Direction::Left, Direction::Left, Direction::Right]
+        );
+        assert_eq!(map.len(), 3);
+        for (node, branches) in [
+            ("AAA", ["BBB", "BBB"]),
+            ("BBB", ["AAA", "ZZZ"]),
+            ("ZZZ", ["ZZZ", "ZZZ"]),
+        ] {
+            let id = map.id(&node).unwrap();
+            let found: Vec<_> = map.neighbours(id).map(|to| *map.key(to)).collect();
+            assert_eq!(found, branches);
+        }
+    }
+
+    #[test]
+    fn part_one_correct() {
+        assert_eq!(
+            part_one(
+                "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)"
+            ),
+            Ok(2)
+        );
+
+        assert_eq!(
+            part_one(
+                "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)"
+            ),
+            Ok(6)
+        );
+    }
+
+    #[test]
+    fn part_two_correct() {
+        assert_eq!(
+            part_two(
+                "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)"
+            ),
+            Ok(6)
+        );
+    }
+
+    #[test]
+    fn malformed_map_is_an_error() {
+        assert_eq!(
+            part_one("LR\n\nAAA = (BBB, ZZZ)\nZZZ = ZZZ, ZZZ")
+                .unwrap_err()
+                .to_string(),
+            "line 4, column 7: expected '(' but found 'Z'"
+        );
+        assert_eq!(
+            part_one("LR\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)")
+                .unwrap_err()
+                .to_string(),
+            "line 3, column 8: expected a known node but found 'BBB'"
+        );
+        assert_eq!(
+            part_one("LX\n\nAAA = (ZZZ, ZZZ)").unwrap_err().to_string(),
+            "line 1, column 2: expected 'L' or 'R' but found 'X'"
+        );
+    }
+
+    #[test]
+    fn unreachable_exit_is_an_error() {
+        assert_eq!(
+            part_one("LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)"),
+            Err(SolveError::new("Node 'ZZZ' can't be reached from 'AAA'"))
+        );
+        assert_eq!(
+            part_two("L\n\n11A = (11B, 11Z)\n11B = (11A, 11Z)\n11Z = (11Z, 11Z)"),
+            Err(SolveError::new("No exit can be reached from '11A'"))
+        );
+    }
+}
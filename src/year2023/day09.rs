@@ -0,0 +1,132 @@
+use crate::error::{parse_lines, parse_value, ParseError, SolveError};
+
+fn get_appended(v: &[i64], d: i64) -> Option<i64> {
+    v[v.len() - 1].checked_add(d)
+}
+
+fn get_prepended(v: &[i64], d: i64) -> Option<i64> {
+    v[0].checked_sub(d)
+}
+
+/// Extrapolates a value at one end of a sequence from the differences between its values, or
+/// returns None if any of the values are too large to hold.
+fn calc_end_value<F>(v: &[i64], f: &F) -> Option<i64>
+where
+    F: Fn(&[i64], i64) -> Option<i64>,
+{
+    // If everything is zero, then the next value is also zero
+    if v.iter().all(|d| *d == 0) {
+        return Some(0);
+    }
+
+    // Build next vector
+    let mut next_vector = Vec::with_capacity(v.len() - 1);
+    for i in 1..v.len() {
+        next_vector.push(v[i].checked_sub(v[i - 1])?);
+    }
+
+    let next_diff = calc_end_value(&next_vector, f)?;
+
+    f(v, next_diff)
+}
+
+pub fn calc_next_value(v: &[i64]) -> Option<i64> {
+    calc_end_value(v, &get_appended)
+}
+
+pub fn calc_previous_value(v: &[i64]) -> Option<i64> {
+    calc_end_value(v, &get_prepended)
+}
+
+pub fn to_vec(s: &str) -> Result<Vec<i64>, ParseError> {
+    s.split_whitespace()
+        .map(|v| parse_value(s, v, "a number"))
+        .collect()
+}
+
+/// Extrapolates a value for each history and adds them up, reporting the line of the first
+/// history where the values grow too large to hold.
+///
+/// # Arguments
+///
+/// 'input' - The input.
+///
+/// 'extrapolate' - The function that extrapolates a value for a history.
+fn sum_extrapolated<F>(input: &str, extrapolate: F) -> Result<i64, SolveError>
+where
+    F: Fn(&[i64]) -> Option<i64>,
+{
+    let histories = parse_lines(input, to_vec)?;
+
+    histories
+        .iter()
+        .enumerate()
+        .try_fold(0i64, |total, (i, v)| {
+            extrapolate(v)
+                .and_then(|value| total.checked_add(value))
+                .ok_or_else(|| SolveError::at_line(i + 1, "The extrapolated values are too large"))
+        })
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_one(input: &str) -> Result<i64, SolveError> {
+    sum_extrapolated(input, calc_next_value)
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_two(input: &str) -> Result<i64, SolveError> {
+    sum_extrapolated(input, calc_previous_value)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2023::day09::*;
+
+    #[test]
+    fn part_one_correct() {
+        assert_eq!(
+            part_one(
+                "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45"
+            ),
+            Ok(114)
+        );
+    }
+
+    #[test]
+    fn part_two_correct() {
+        assert_eq!(
+            part_two(
+                "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45"
+            ),
+            Ok(2)
+        );
+    }
+
+    #[test]
+    fn large_values_are_an_error() {
+        assert_eq!(part_one("2147483647 -2147483648"), Ok(-6442450943));
+        assert_eq!(
+            part_two("1 2\n9223372036854775807 -9223372036854775808"),
+            Err(SolveError::at_line(
+                2,
+                "The extrapolated values are too large"
+            ))
+        );
+        assert_eq!(
+            part_one("0 3\n9223372036854775807 0 0").unwrap_err().line,
+            Some(2)
+        );
+    }
+}
@@ -0,0 +1,440 @@
+use crate::direction::Direction::{self, *};
+use crate::error::{ParseError, SolveError};
+use crate::grid::{char_at_position, Grid};
+use crate::visualize::{Color, Frame, Visualizer};
+use std::collections::VecDeque;
+
+#[derive(Eq, PartialEq)]
+enum State {
+    Outside,
+    OnTopEdge,
+    OnBottomEdge,
+    Inside,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Pipe {
+    pub exits: Vec<Direction>,
+    pub distance: Option<usize>,
+}
+
+impl Pipe {
+    /// Returns whether the pipe has an exit in the given direction.
+    fn connects(&self, direction: Direction) -> bool {
+        self.exits.contains(&direction)
+    }
+}
+
+/// The pipes in the map, or None for ground.
+pub type PipeMap = Grid<Option<Pipe>>;
+
+/// Returns the directions of the two exits of the pipe shown by a character of the map, or None
+/// if the character isn't a pipe.
+///
+/// # Arguments
+///
+/// 'c' - The character from the map.
+fn to_exits(c: char) -> Option<Vec<Direction>> {
+    match c {
+        '|' => Some(vec![Up, Down]),
+        '-' => Some(vec![Left, Right]),
+        'L' => Some(vec![Up, Right]),
+        'J' => Some(vec![Up, Left]),
+        '7' => Some(vec![Down, Left]),
+        'F' => Some(vec![Down, Right]),
+        _ => None,
+    }
+}
+
+pub fn read_map(s: &str) -> Result<((usize, usize), PipeMap), SolveError> {
+    let chars = Grid::parse(s, "a pipe or ground", Some)?;
+
+    // A pipe that leads off the edge of the map can't be part of the loop, so treat it as ground
+    let mut map = Grid::from_fn(chars.width(), chars.height(), |pos| {
+        to_exits(chars[pos])
+            .filter(|exits| {
+                exits
+                    .iter()
+                    .all(|exit| chars.offset(pos, exit.vector()).is_some())
+            })
+            .map(|exits| Pipe {
+                exits,
+                distance: None,
+            })
+    });
+
+    let mut starts = chars.iter().filter(|(_, c)| **c == 'S').map(|(pos, _)| pos);
+    let start = starts.next();
+
+    if let Some(pos) = starts.next() {
+        let found = char_at_position(s, pos);
+        return Err(ParseError::new(s, found, "a single starting position").into());
+    }
+
+    // Now, figure out the shape of the starting cell from the pipes that lead into it
+    let start = start.ok_or_else(|| ParseError::missing(s, "a starting position 'S'"))?;
+
+    let exits = Direction::ALL
+        .into_iter()
+        .filter(|exit| {
+            map.offset(start, exit.vector())
+                .and_then(|pos| map[pos].as_ref())
+                .is_some_and(|pipe| pipe.connects(exit.reverse()))
+        })
+        .collect();
+
+    map[start] = Some(Pipe {
+        exits,
+        distance: None,
+    });
+
+    Ok((start, map))
+}
+
+pub fn set_node_distances(start: (usize, usize), map: &mut PipeMap) {
+    let mut nodes: VecDeque<((usize, usize), usize)> = VecDeque::new();
+    nodes.push_back((start, 0));
+
+    while let Some(node) = nodes.pop_front() {
+        let cur_pos = node.0;
+        let cur_dist = node.1;
+
+        // A pipe may lead off into the ground, which is a dead end:
+        let Some(Some(pipe)) = map.get_mut(cur_pos) else {
+            continue;
+        };
+
+        if pipe.distance.is_none() {
+            pipe.distance = Some(cur_dist);
+
+            let exits = pipe.exits.clone();
+
+            for key in exits
+                .iter()
+                .filter_map(|exit| map.offset(cur_pos, exit.vector()))
+            {
+                nodes.push_back((key, cur_dist + 1));
+            }
+        }
+    }
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_one(input: &str) -> Result<usize, SolveError> {
+    let mut map = read_map(input)?;
+
+    set_node_distances(map.0, &mut map.1);
+
+    Ok(map
+        .1
+        .iter()
+        .filter_map(|(_, p)| p.as_ref()?.distance)
+        .max()
+        .unwrap_or(0))
+}
+
+/// Returns the positions of the tiles enclosed by the loop, scanning each row and keeping track
+/// of whether the loop has been crossed an odd number of times. The distances along the loop must
+/// already have been set, so that pipes that aren't part of it can be told apart.
+///
+/// # Arguments
+///
+/// 'map' - The map of pipes.
+pub fn find_enclosed(map: &PipeMap) -> Vec<(usize, usize)> {
+    let mut enclosed = vec![];
+
+    for y in 0..map.height() {
+        let mut state = State::Outside;
+
+        for (x, pipe) in map.row(y).iter().enumerate() {
+            if let Some(pipe) = pipe {
+                if pipe.distance.is_some() {
+                    if !pipe.connects(Left) && !pipe.connects(Right) {
+                        // |
+                        state = if state == State::Outside {
+                            State::Inside
+                        } else {
+                            State::Outside
+                        };
+                    } else if !pipe.connects(Left) && !pipe.connects(Up) {
+                        // F
+                        state = if state == State::Outside {
+                            State::OnTopEdge
+                        } else {
+                            State::OnBottomEdge
+                        };
+                    } else if !pipe.connects(Left) && !pipe.connects(Down) {
+                        // L
+                        state = if state == State::Outside {
+                            State::OnBottomEdge
+                        } else {
+                            State::OnTopEdge
+                        };
+                    } else if !pipe.connects(Right) && !pipe.connects(Up) {
+                        // 7
+                        state = if state == State::OnTopEdge {
+                            State::Outside
+                        } else {
+                            State::Inside
+                        };
+                    } else if !pipe.connects(Right) && !pipe.connects(Down) {
+                        // J
+                        state = if state == State::OnTopEdge {
+                            State::Inside
+                        } else {
+                            State::Outside
+                        };
+                    }
+                } else if state == State::Inside {
+                    enclosed.push((x, y));
+                }
+            } else if state == State::Inside {
+                enclosed.push((x, y));
+            }
+        }
+    }
+
+    enclosed
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_two(input: &str) -> Result<usize, SolveError> {
+    let mut map = read_map(input)?;
+    set_node_distances(map.0, &mut map.1);
+
+    Ok(find_enclosed(&map.1).len())
+}
+
+/// Draws the loop spreading out from the start, one step further along it in each frame, and
+/// then picks out the tiles it encloses.
+///
+/// # Arguments
+///
+/// 'input' - The input.
+///
+/// 'visualizer' - Where to show the frames.
+pub fn visualize(input: &str, visualizer: &mut Visualizer) -> Result<(), SolveError> {
+    let (start, mut map) = read_map(input)?;
+    set_node_distances(start, &mut map);
+
+    let furthest = map
+        .iter()
+        .filter_map(|(_, p)| p.as_ref()?.distance)
+        .max()
+        .unwrap_or(0);
+
+    let draw = |caption: String, steps: usize| {
+        Frame::new(caption, &map, |pos, pipe| match pipe {
+            _ if pos == start => ('S', Color::Red),
+            Some(pipe) if pipe.distance.is_some_and(|d| d <= steps) => {
+                (box_drawing(&pipe.exits), Color::Yellow)
+            }
+            Some(pipe) if pipe.distance.is_some() => (box_drawing(&pipe.exits), Color::Plain),
+            Some(pipe) => (box_drawing(&pipe.exits), Color::Dim),
+            None => ('.', Color::Dim),
+        })
+    };
+
+    for steps in 0..=furthest {
+        visualizer.step(|| {
+            draw(
+                format!("Following the loop: {steps} steps from the start"),
+                steps,
+            )
+        })?;
+    }
+
+    let enclosed = find_enclosed(&map);
+    let mut frame = draw(
+        format!("{} tiles enclosed by the loop", enclosed.len()),
+        furthest,
+    );
+    for pos in enclosed {
+        frame.cells[pos] = ('I', Color::Green);
+    }
+
+    visualizer.show(frame)
+}
+
+/// Returns the box drawing character for a pipe, which shows the shape of the loop more clearly
+/// than the letters in the input.
+///
+/// # Arguments
+///
+/// 'exits' - The directions the pipe leads in.
+fn box_drawing(exits: &[Direction]) -> char {
+    let connects = |direction| exits.contains(&direction);
+
+    match (
+        connects(Up),
+        connects(Down),
+        connects(Left),
+        connects(Right),
+    ) {
+        (true, true, _, _) => '│',
+        (_, _, true, true) => '─',
+        (true, _, _, true) => '└',
+        (true, _, true, _) => '┘',
+        (_, true, true, _) => '┐',
+        (_, true, _, true) => '┌',
+        _ => '·',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2023::day10::*;
+
+    #[test]
+    fn parse_map_input() {
+        let map = read_map(
+            ".....
+.S-7.
+.|.|.
+.L-J.
+.....",
+        )
+        .unwrap();
+
+        assert_eq!(map.0, (1, 1));
+        assert!(map.1[(0, 0)].is_none());
+        assert_eq!(
+            map.1[(2, 1)],
+            Some(Pipe {
+                exits: vec![Left, Right],
+                distance: None,
+            })
+        );
+        assert_eq!(
+            map.1[(1, 2)],
+            Some(Pipe {
+                exits: vec![Up, Down],
+                distance: None,
+            })
+        );
+        assert_eq!(
+            map.1[(1, 3)],
+            Some(Pipe {
+                exits: vec![Up, Right],
+                distance: None,
+            })
+        );
+        assert_eq!(
+            map.1[(3, 3)],
+            Some(Pipe {
+                exits: vec![Up, Left],
+                distance: None,
+            })
+        );
+        assert_eq!(
+            map.1[(3, 1)],
+            Some(Pipe {
+                exits: vec![Down, Left],
+                distance: None,
+            })
+        );
+
+        assert_eq!(
+            map.1[(1, 1)],
+            Some(Pipe {
+                exits: vec![Down, Right],
+                distance: None,
+            })
+        );
+    }
+
+    #[test]
+    fn part_one_correct() {
+        assert_eq!(
+            part_one(
+                ".....
+.S-7.
+.|.|.
+.L-J.
+....."
+            ),
+            Ok(4)
+        );
+
+        assert_eq!(
+            part_one(
+                "..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ..."
+            ),
+            Ok(8)
+        );
+    }
+
+    #[test]
+    fn part_two_correct() {
+        assert_eq!(
+            part_two(
+                "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+..........."
+            ),
+            Ok(4)
+        );
+
+        assert_eq!(
+            part_two(
+                ".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ..."
+            ),
+            Ok(8)
+        );
+
+        assert_eq!(
+            part_two(
+                "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L"
+            ),
+            Ok(10)
+        )
+    }
+
+    #[test]
+    fn missing_start_is_an_error() {
+        assert_eq!(
+            part_one(".F-7.\n.|.|.\n.L-J.").unwrap_err().to_string(),
+            "line 3, column 6: expected a starting position 'S' but found nothing"
+        );
+        assert_eq!(
+            part_two("SF-7.\n.|.|.\n.L-JS").unwrap_err().to_string(),
+            "line 3, column 5: expected a single starting position but found 'S'"
+        );
+    }
+}
@@ -1,15 +1,18 @@
-fn parse_input(s: &str) -> Vec<(i32, i32)> {
-    let mut result = vec![];
-
-    for (y, line) in s.lines().enumerate() {
-        for (x, char) in line.chars().enumerate() {
-            if char == '#' {
-                result.push((x as i32, y as i32));
-            }
-        }
-    }
-
-    result
+use crate::error::{ParseError, SolveError};
+use crate::grid::Grid;
+
+pub fn parse_input(s: &str) -> Result<Vec<(i32, i32)>, ParseError> {
+    let map = Grid::parse(s, "'.' or '#'", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+
+    Ok(map
+        .iter()
+        .filter(|(_, galaxy)| **galaxy)
+        .map(|((x, y), _)| (x as i32, y as i32))
+        .collect())
 }
 
 fn expand_rows(factor: i32, map: &mut [(i32, i32)]) {
@@ -56,19 +59,19 @@ fn expand_columns(factor: i32, map: &mut [(i32, i32)]) {
     }
 }
 
-fn expand_map(factor: i32, map: &mut [(i32, i32)]) {
+pub fn expand_map(factor: i32, map: &mut [(i32, i32)]) {
     expand_rows(factor, map);
     expand_columns(factor, map);
 }
 
-fn sum_distances_with_expansion(input: &str, factor: i32) -> i64 {
-    let mut map = parse_input(input);
+pub fn sum_distances_with_expansion(input: &str, factor: i32) -> Result<i64, SolveError> {
+    let mut map = parse_input(input)?;
 
     expand_map(factor, &mut map);
 
     let mut total_distance = 0;
 
-    for a in 0..(map.len() - 1) {
+    for a in 0..map.len().saturating_sub(1) {
         let map_a = map[a];
 
         for map_b in map.iter().skip(a + 1) {
@@ -81,7 +84,7 @@ fn sum_distances_with_expansion(input: &str, factor: i32) -> i64 {
         }
     }
 
-    total_distance
+    Ok(total_distance)
 }
 
 ///
@@ -89,7 +92,7 @@ fn sum_distances_with_expansion(input: &str, factor: i32) -> i64 {
 /// #Argument
 ///
 /// 'input' - The input.
-pub fn part_one(input: &str) -> i64 {
+pub fn part_one(input: &str) -> Result<i64, SolveError> {
     sum_distances_with_expansion(input, 2)
 }
 
@@ -98,13 +101,13 @@ pub fn part_one(input: &str) -> i64 {
 /// #Argument
 ///
 /// 'input' - The input.
-pub fn part_two(input: &str) -> i64 {
+pub fn part_two(input: &str) -> Result<i64, SolveError> {
     sum_distances_with_expansion(input, 1000000)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day11::*;
+    use crate::year2023::day11::*;
 
     #[test]
     fn test_parse_input() {
@@ -119,7 +122,8 @@ mod tests {
 ..........
 .......#..
 #...#.....",
-        );
+        )
+        .unwrap();
 
         assert_eq!(map.len(), 9);
         assert_eq!(map[0], (3, 0));
@@ -140,7 +144,8 @@ mod tests {
 ..........
 .......#..
 #...#.....",
-        );
+        )
+        .unwrap();
         expand_map(2, &mut map);
 
         assert_eq!(map.len(), 9);
@@ -164,7 +169,7 @@ mod tests {
 .......#..
 #...#....."
             ),
-            374
+            Ok(374)
         );
     }
 
@@ -184,7 +189,7 @@ mod tests {
 #...#.....",
                 10
             ),
-            1030
+            Ok(1030)
         );
 
         assert_eq!(
@@ -201,7 +206,15 @@ mod tests {
 #...#.....",
                 100
             ),
-            8410
+            Ok(8410)
+        );
+    }
+
+    #[test]
+    fn malformed_images_are_an_error() {
+        assert_eq!(
+            part_one("...#\n.#x.").unwrap_err().to_string(),
+            "line 2, column 3: expected '.' or '#' but found 'x'"
         );
     }
 }
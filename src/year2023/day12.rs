@@ -1,4 +1,5 @@
-use crate::day12::DfaState::*;
+use crate::error::{parse_lines_parallel, parse_value, ParseError, SolveError};
+use crate::year2023::day12::DfaState::*;
 use itertools::Itertools;
 
 #[derive(Debug, Eq, PartialEq)]
@@ -30,17 +31,57 @@ fn build_dfa(checksum: &Vec<i32>) -> Vec<DfaState> {
     result
 }
 
-fn get_valid_possibility_count(s: &str) -> i64 {
-    let (a, b) = s.split_whitespace().collect_tuple().unwrap();
+/// Splits a row of the input into the spring pattern and the sizes of the groups of damaged
+/// springs, checking that both are valid.
+///
+/// # Arguments
+///
+/// 's' - The row to split.
+pub fn split_row(s: &str) -> Result<(&str, Vec<i32>), ParseError> {
+    let (a, b) = s
+        .split_whitespace()
+        .collect_tuple()
+        .ok_or_else(|| ParseError::missing(s, "a spring pattern followed by group sizes"))?;
+
+    if let Some((i, c)) = a
+        .char_indices()
+        .find(|(_, c)| !matches!(c, '.' | '#' | '?'))
+    {
+        return Err(invalid_spring(s, &a[i..i + c.len_utf8()]));
+    }
+
+    let checksum = b
+        .split(',')
+        .map(|n| match parse_value(s, n, "a group size") {
+            Ok(size) if size <= 0 => Err(ParseError::new(s, n, "a group size")),
+            size => size,
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((a, checksum))
+}
 
-    let checksum = b.split(',').flat_map(|s| s.parse::<i32>()).collect_vec();
+/// Creates the error for a character that isn't a valid spring.
+///
+/// # Arguments
+///
+/// 's' - The row being parsed.
+///
+/// 'c' - The slice of the row holding the character.
+fn invalid_spring(s: &str, c: &str) -> ParseError {
+    ParseError::new(s, c, "'.', '#' or '?'")
+}
+
+pub fn get_valid_possibility_count(s: &str) -> Result<i64, ParseError> {
+    let (a, checksum) = split_row(s)?;
 
     let dfa = build_dfa(&checksum);
     let mut heads = vec![0; dfa.len()];
     heads[0] = 1;
 
     // println!("Heads: {:?}", heads);
-    for c in a.chars() {
+    for (pos, c) in a.char_indices() {
+        let invalid = || invalid_spring(s, &a[pos..pos + c.len_utf8()]);
         let mut next_heads = vec![0; dfa.len()];
 
         for i in 0..heads.len() {
@@ -58,7 +99,7 @@ fn get_valid_possibility_count(s: &str) -> i64 {
                         next_heads[index + 1] += heads[index];
                     }
                     _ => {
-                        panic!("Invalid character in spring pattern");
+                        return Err(invalid());
                     }
                 },
                 Hash => {
@@ -68,7 +109,7 @@ fn get_valid_possibility_count(s: &str) -> i64 {
                         }
                         '.' => {} // reject
                         _ => {
-                            panic!("Invalid character in spring pattern");
+                            return Err(invalid());
                         }
                     }
                 }
@@ -79,7 +120,7 @@ fn get_valid_possibility_count(s: &str) -> i64 {
                         }
                         '#' => {} // reject
                         _ => {
-                            panic!("Invalid character in spring pattern");
+                            return Err(invalid());
                         }
                     }
                 }
@@ -90,7 +131,7 @@ fn get_valid_possibility_count(s: &str) -> i64 {
                         }
                         '#' => {} // reject
                         _ => {
-                            panic!("Invalid character in spring pattern");
+                            return Err(invalid());
                         }
                     }
                 }
@@ -101,13 +142,14 @@ fn get_valid_possibility_count(s: &str) -> i64 {
     }
 
     // The last node is our only accept node, so just return how many heads made it there:
-    heads[heads.len() - 1]
+    Ok(heads[heads.len() - 1])
 }
 
-fn expand_input(s: &str) -> String {
-    let (a, b) = s.split_whitespace().collect_tuple().unwrap();
+pub fn expand_input(s: &str) -> Result<String, ParseError> {
+    let (a, checksum) = split_row(s)?;
+    let b = checksum.iter().join(",");
 
-    format!("{a}?{a}?{a}?{a}?{a} {b},{b},{b},{b},{b}")
+    Ok(format!("{a}?{a}?{a}?{a}?{a} {b},{b},{b},{b},{b}"))
 }
 
 ///
@@ -115,8 +157,10 @@ fn expand_input(s: &str) -> String {
 /// #Argument
 ///
 /// 'input' - The input.
-pub fn part_one(input: &str) -> i64 {
-    input.lines().map(get_valid_possibility_count).sum()
+pub fn part_one(input: &str) -> Result<i64, SolveError> {
+    let counts = parse_lines_parallel(input, get_valid_possibility_count)?;
+
+    Ok(counts.iter().sum())
 }
 
 ///
@@ -124,17 +168,17 @@ pub fn part_one(input: &str) -> i64 {
 /// #Argument
 ///
 /// 'input' - The input.
-pub fn part_two(input: &str) -> i64 {
-    input
-        .lines()
-        .map(expand_input)
-        .map(|line| get_valid_possibility_count(line.as_str()))
-        .sum()
+pub fn part_two(input: &str) -> Result<i64, SolveError> {
+    let counts = parse_lines_parallel(input, |line| {
+        get_valid_possibility_count(expand_input(line)?.as_str())
+    })?;
+
+    Ok(counts.iter().sum())
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day12::*;
+    use crate::year2023::day12::*;
 
     #[test]
     fn test_build_dfa() {
@@ -158,19 +202,25 @@ mod tests {
 
     #[test]
     fn test_get_valid_possibility_count() {
-        assert_eq!(get_valid_possibility_count("???.### 1,1,3"), 1);
-        assert_eq!(get_valid_possibility_count(".??..??...?##. 1,1,3"), 4);
-        assert_eq!(get_valid_possibility_count("?#?#?#?#?#?#?#? 1,3,1,6"), 1);
-        assert_eq!(get_valid_possibility_count("????.#...#... 4,1,1"), 1);
-        assert_eq!(get_valid_possibility_count("????.######..#####. 1,6,5"), 4);
-        assert_eq!(get_valid_possibility_count("?###???????? 3,2,1"), 10);
+        assert_eq!(get_valid_possibility_count("???.### 1,1,3"), Ok(1));
+        assert_eq!(get_valid_possibility_count(".??..??...?##. 1,1,3"), Ok(4));
+        assert_eq!(
+            get_valid_possibility_count("?#?#?#?#?#?#?#? 1,3,1,6"),
+            Ok(1)
+        );
+        assert_eq!(get_valid_possibility_count("????.#...#... 4,1,1"), Ok(1));
+        assert_eq!(
+            get_valid_possibility_count("????.######..#####. 1,6,5"),
+            Ok(4)
+        );
+        assert_eq!(get_valid_possibility_count("?###???????? 3,2,1"), Ok(10));
     }
 
     #[test]
     fn test_expand() {
-        assert_eq!(expand_input(".# 1"), ".#?.#?.#?.#?.# 1,1,1,1,1");
+        assert_eq!(expand_input(".# 1").unwrap(), ".#?.#?.#?.#?.# 1,1,1,1,1");
         assert_eq!(
-            expand_input("???.### 1,1,3"),
+            expand_input("???.### 1,1,3").unwrap(),
             "???.###????.###????.###????.###????.### 1,1,3,1,1,3,1,1,3,1,1,3,1,1,3"
         );
     }
@@ -186,7 +236,7 @@ mod tests {
 ????.######..#####. 1,6,5
 ?###???????? 3,2,1"
             ),
-            21
+            Ok(21)
         );
     }
 
@@ -201,7 +251,23 @@ mod tests {
 ????.######..#####. 1,6,5
 ?###???????? 3,2,1"
             ),
-            525152
+            Ok(525152)
+        );
+    }
+
+    #[test]
+    fn malformed_rows_are_an_error() {
+        assert_eq!(
+            part_one("???.### 1,1,3\n.??.x 1").unwrap_err().to_string(),
+            "line 2, column 5: expected '.', '#' or '?' but found 'x'"
+        );
+        assert_eq!(
+            part_two("???.### 1,1,3\n???.###").unwrap_err().to_string(),
+            "line 2, column 8: expected a spring pattern followed by group sizes but found nothing"
+        );
+        assert_eq!(
+            part_one("???.### 1,0,3").unwrap_err().to_string(),
+            "line 1, column 11: expected a group size but found '0'"
         );
     }
 }
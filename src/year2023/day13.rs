@@ -1,43 +1,18 @@
-fn encode_by_row(s: &str) -> Vec<i32> {
-    let mut result = vec![];
-
-    for line in s.lines() {
-        let mut total = 0;
-
-        for c in line.chars() {
-            total <<= 1;
-            if c == '#' {
-                total += 1;
-            }
-        }
-
-        result.push(total);
-    }
-
-    result
+use crate::error::{ParseError, SolveError};
+use crate::grid::Grid;
+
+pub fn encode_by_row(pattern: &Grid<bool>) -> Vec<i32> {
+    pattern
+        .rows()
+        .map(|row| {
+            row.iter()
+                .fold(0, |total, rock| (total << 1) + *rock as i32)
+        })
+        .collect()
 }
 
-fn encode_by_column(s: &str) -> Vec<i32> {
-    let width = s.lines().next().unwrap().len();
-
-    let mut result = vec![];
-
-    for i in 0..width {
-        let mut total = 0;
-
-        for line in s.lines() {
-            total <<= 1;
-
-            let c = &line[i..i + 1];
-            if c == "#" {
-                total += 1;
-            }
-        }
-
-        result.push(total);
-    }
-
-    result
+pub fn encode_by_column(pattern: &Grid<bool>) -> Vec<i32> {
+    encode_by_row(&pattern.transpose())
 }
 
 fn is_reflection_without_smudge(left: &[i32], right: &[i32]) -> bool {
@@ -100,7 +75,7 @@ fn is_reflection(left: &[i32], right: &[i32], smudge: bool) -> bool {
     }
 }
 
-fn find_reflection(values: &[i32], smudge: bool) -> Option<usize> {
+pub fn find_reflection(values: &[i32], smudge: bool) -> Option<usize> {
     let mut left: &[i32];
     let mut right: &[i32];
     let mut index = 1;
@@ -118,30 +93,67 @@ fn find_reflection(values: &[i32], smudge: bool) -> Option<usize> {
     None
 }
 
-fn get_reflection_value(s: &str, smudge: bool) -> i32 {
-    let values = encode_by_row(s);
+pub fn get_reflection_value(pattern: &Grid<bool>, smudge: bool) -> Option<i32> {
+    let values = encode_by_row(pattern);
     let reflection = find_reflection(&values, smudge);
 
     if let Some(value) = reflection {
-        return 100 * (value as i32);
+        return Some(100 * (value as i32));
     }
 
-    let values = encode_by_column(s);
+    let values = encode_by_column(pattern);
     let reflection = find_reflection(&values, smudge);
 
-    reflection.unwrap() as i32
+    reflection.map(|value| value as i32)
 }
 
+/// Parses a pattern of '.' and '#' characters into a grid, where '#' marks a rock.
 ///
+/// # Arguments
 ///
-/// #Argument
+/// 'input' - The input containing the pattern.
+///
+/// 'pattern' - The slice of the input holding the pattern to parse.
+fn parse_pattern(input: &str, pattern: &str) -> Result<Grid<bool>, ParseError> {
+    Grid::parse(pattern, "'.' or '#'", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+    .map_err(|e| e.within(input, pattern))
+}
+
+/// Parses all the patterns in the input, which are separated by blank lines.
+///
+/// # Arguments
 ///
 /// 'input' - The input.
-pub fn part_one(input: &str) -> i32 {
+pub fn parse_patterns(input: &str) -> Result<Vec<Grid<bool>>, ParseError> {
     input
         .split("\n\n")
-        .map(|line| get_reflection_value(line, false))
-        .sum()
+        .map(|pattern| parse_pattern(input, pattern))
+        .collect()
+}
+
+/// Returns the sum of the reflection values of all the patterns in the input.
+///
+/// # Arguments
+///
+/// 'input' - The input.
+///
+/// 'smudge' - Whether each reflection must have exactly one smudge.
+fn sum_reflection_values(input: &str, smudge: bool) -> Result<i32, SolveError> {
+    let mut total = 0;
+    let mut first_line = 1;
+
+    for (pattern, grid) in input.split("\n\n").zip(parse_patterns(input)?) {
+        total += get_reflection_value(&grid, smudge)
+            .ok_or(SolveError::at_line(first_line, "No reflection found"))?;
+
+        first_line += pattern.lines().count() + 1;
+    }
+
+    Ok(total)
 }
 
 ///
@@ -149,29 +161,34 @@ pub fn part_one(input: &str) -> i32 {
 /// #Argument
 ///
 /// 'input' - The input.
-pub fn part_two(input: &str) -> i32 {
-    input
-        .split("\n\n")
-        .map(|line| get_reflection_value(line, true))
-        .sum()
+pub fn part_one(input: &str) -> Result<i32, SolveError> {
+    sum_reflection_values(input, false)
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_two(input: &str) -> Result<i32, SolveError> {
+    sum_reflection_values(input, true)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day13::*;
+    use crate::year2023::day13::*;
 
     #[test]
     fn test_encode_by_row() {
-        assert_eq!(
-            encode_by_row(
-                "#.##..##.
+        let pattern = "#.##..##.
 ..#.##.#.
 ##......#
 ##......#
 ..#.##.#.
 ..##..##.
-#.#.##.#."
-            ),
+#.#.##.#.";
+        assert_eq!(
+            encode_by_row(&parse_pattern(pattern, pattern).unwrap()),
             vec![
                 0b101100110,
                 0b001011010,
@@ -186,16 +203,15 @@ mod tests {
 
     #[test]
     fn test_encode_by_column() {
-        assert_eq!(
-            encode_by_column(
-                "#.##..##.
+        let pattern = "#.##..##.
 ..#.##.#.
 ##......#
 ##......#
 ..#.##.#.
 ..##..##.
-#.#.##.#."
-            ),
+#.#.##.#.";
+        assert_eq!(
+            encode_by_column(&parse_pattern(pattern, pattern).unwrap()),
             vec![
                 0b1011001, 0b0011000, 0b1100111, 0b1000010, 0b0100101, 0b0100101, 0b1000010,
                 0b1100111, 0b0011000
@@ -284,7 +300,7 @@ mod tests {
 ..##..###
 #....#..#"
             ),
-            405
+            Ok(405)
         );
     }
 
@@ -308,7 +324,7 @@ mod tests {
 ..##..###
 #....#..#"
             ),
-            400
+            Ok(400)
         );
 
         assert_eq!(
@@ -321,7 +337,23 @@ mod tests {
 #####.#..####
 ....######..#"
             ),
-            2
+            Ok(2)
+        );
+    }
+
+    #[test]
+    fn malformed_patterns_are_an_error() {
+        assert_eq!(
+            part_one("##\n##\n\n##\n#").unwrap_err().to_string(),
+            "line 5, column 1: expected a row of 2 characters but found '#'"
+        );
+        assert_eq!(
+            part_one("##\n##\n\n##\n#O").unwrap_err().to_string(),
+            "line 5, column 2: expected '.' or '#' but found 'O'"
+        );
+        assert_eq!(
+            part_one("#.\n.#"),
+            Err(SolveError::at_line(1, "No reflection found"))
         );
     }
 }
@@ -0,0 +1,191 @@
+use crate::cycle::find_cycle;
+use crate::error::{ParseError, SolveError};
+use crate::grid::Grid;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+#[derive(Clone)]
+pub struct Map {
+    rocks: Grid<char>,
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.rocks)
+    }
+}
+
+impl Map {
+    fn calculate_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.rocks.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    pub fn tilt_north(&mut self) {
+        for x in 0..self.rocks.width() {
+            let mut next_y = 0;
+
+            for y in 0..self.rocks.height() {
+                match self.rocks[(x, y)] {
+                    '#' => next_y = y + 1,
+                    'O' => {
+                        self.rocks[(x, y)] = '.';
+                        self.rocks[(x, next_y)] = 'O';
+                        next_y += 1;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Tilts the map north, west, south and then east. Each tilt is made by tilting north and
+    /// then rotating the map clockwise, which leaves it facing the same way after all four.
+    pub fn spin_cycle(&mut self) {
+        for _ in 0..4 {
+            self.tilt_north();
+            self.rocks = self.rocks.rotate_clockwise();
+        }
+    }
+
+    pub fn calculate_load(&self) -> usize {
+        self.rocks
+            .iter()
+            .filter(|(_, c)| **c == 'O')
+            .map(|((_, y), _)| self.rocks.height() - y)
+            .sum()
+    }
+}
+
+pub fn parse_map(s: &str) -> Result<Map, ParseError> {
+    let rocks = Grid::parse(s, "'O', '#' or '.'", |c| {
+        matches!(c, 'O' | '#' | '.').then_some(c)
+    })?;
+
+    Ok(Map { rocks })
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_one(input: &str) -> Result<usize, SolveError> {
+    let mut map = parse_map(input)?;
+    // println!("{map}");
+    map.tilt_north();
+    // println!("{map}");
+    Ok(map.calculate_load())
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_two(input: &str) -> Result<usize, SolveError> {
+    let map = parse_map(input)?;
+
+    load_after_spins(map, 1_000_000_000)
+}
+
+/// Returns the load on the north beams after a number of spin cycles. The rocks settle into a
+/// loop, so only the spin cycles up to the end of the first time round the loop are made.
+///
+/// # Arguments
+///
+/// 'map' - The map before it is spun.
+///
+/// 'spins' - The number of spin cycles to make.
+pub fn load_after_spins(map: Map, spins: usize) -> Result<usize, SolveError> {
+    let spin = |map: &Map| {
+        let mut map = map.clone();
+        map.spin_cycle();
+        map
+    };
+
+    let (cycle, maps) = find_cycle(map, spin, Map::calculate_hash, 10000)
+        .ok_or(SolveError::new("No cycle found in map progression"))?;
+
+    Ok(maps[cycle.equivalent_step(spins)].calculate_load())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2023::day14::*;
+
+    #[test]
+    fn part_one_correct() {
+        assert_eq!(
+            part_one(
+                "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#...."
+            ),
+            Ok(136)
+        );
+    }
+
+    #[test]
+    fn part_two_correct() {
+        assert_eq!(
+            part_two(
+                "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#...."
+            ),
+            Ok(64)
+        );
+    }
+
+    #[test]
+    fn malformed_maps_are_an_error() {
+        assert_eq!(
+            part_one("O..#\n.O.").unwrap_err().to_string(),
+            "line 2, column 1: expected a row of 4 characters but found '.O.'"
+        );
+        assert_eq!(
+            part_two("O..#\n.Ox.").unwrap_err().to_string(),
+            "line 2, column 3: expected 'O', '#' or '.' but found 'x'"
+        );
+    }
+
+    #[test]
+    fn load_after_any_number_of_spins() {
+        let input = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+        let mut map = parse_map(input).unwrap();
+
+        for spins in 0..30 {
+            assert_eq!(
+                load_after_spins(parse_map(input).unwrap(), spins),
+                Ok(map.calculate_load())
+            );
+            map.spin_cycle();
+        }
+    }
+}
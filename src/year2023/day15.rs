@@ -1,4 +1,4 @@
-use itertools::Itertools;
+use crate::error::{parse_value, ParseError, SolveError};
 use std::collections::VecDeque;
 
 #[derive(Debug, Copy, Clone)]
@@ -8,39 +8,55 @@ struct Lens<'a> {
 }
 
 #[derive(Debug)]
-enum Operation {
+pub enum Operation {
     Remove,
     Add(i32),
 }
 
 #[derive(Debug)]
-struct Step<'a> {
-    label: &'a str,
-    operation: Operation,
+pub struct Step<'a> {
+    pub label: &'a str,
+    pub operation: Operation,
 }
 
-impl<'a> From<&'a str> for Step<'a> {
-    fn from(value: &'a str) -> Self {
+impl<'a> TryFrom<&'a str> for Step<'a> {
+    type Error = ParseError;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
         let index = value.find('-');
         if let Some(end) = index {
-            return Step {
+            return Ok(Step {
                 label: &value[0..end],
                 operation: Operation::Remove,
-            };
+            });
         }
 
-        let mut parts = value.split('=');
-        let label = parts.next().unwrap();
-        let focal_length = parts.next().unwrap().parse::<i32>().unwrap();
+        let (label, focal_length) = value
+            .split_once('=')
+            .ok_or_else(|| ParseError::missing(value, "'-' or '='"))?;
+        let focal_length = parse_value(value, focal_length, "a focal length")?;
 
-        Step {
+        Ok(Step {
             label,
             operation: Operation::Add(focal_length),
-        }
+        })
     }
 }
 
-fn calculate_hash(s: &str) -> i32 {
+/// Parses the comma separated steps of the initialization sequence.
+///
+/// # Arguments
+///
+/// 'input' - The input.
+pub fn parse_steps(input: &str) -> Result<Vec<Step<'_>>, ParseError> {
+    input
+        .trim_end()
+        .split(',')
+        .map(|step| Step::try_from(step).map_err(|e| e.within(input, step)))
+        .collect()
+}
+
+pub fn calculate_hash(s: &str) -> i32 {
     let mut hash = 0;
 
     for c in s.chars() {
@@ -57,8 +73,8 @@ fn calculate_hash(s: &str) -> i32 {
 /// #Argument
 ///
 /// 'input' - The input.
-pub fn part_one(input: &str) -> i32 {
-    input.split(',').map(calculate_hash).sum()
+pub fn part_one(input: &str) -> Result<i32, SolveError> {
+    Ok(input.trim_end().split(',').map(calculate_hash).sum())
 }
 
 ///
@@ -66,10 +82,10 @@ pub fn part_one(input: &str) -> i32 {
 /// #Argument
 ///
 /// 'input' - The input.
-pub fn part_two(input: &str) -> i32 {
+pub fn part_two(input: &str) -> Result<i32, SolveError> {
     let mut boxes = vec![VecDeque::<Lens>::new(); 256];
 
-    let steps = input.split(',').map(Step::from).collect_vec();
+    let steps = parse_steps(input)?;
 
     for step in steps {
         let hash = calculate_hash(step.label);
@@ -100,18 +116,18 @@ pub fn part_two(input: &str) -> i32 {
         }
     }
 
-    total
+    Ok(total)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day15::*;
+    use crate::year2023::day15::*;
 
     #[test]
     fn part_one_correct() {
         assert_eq!(
             part_one("rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7"),
-            1320
+            Ok(1320)
         );
     }
 
@@ -119,7 +135,19 @@ mod tests {
     fn part_two_correct() {
         assert_eq!(
             part_two("rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7"),
-            145
+            Ok(145)
+        );
+    }
+
+    #[test]
+    fn malformed_step_is_an_error() {
+        assert_eq!(
+            part_two("rn=1,cm-,qp").unwrap_err().to_string(),
+            "line 1, column 12: expected '-' or '=' but found nothing"
+        );
+        assert_eq!(
+            part_two("rn=1,cm=x").unwrap_err().to_string(),
+            "line 1, column 9: expected a focal length but found 'x'"
         );
     }
 }
@@ -0,0 +1,304 @@
+use crate::direction::Direction::{self, *};
+use crate::error::{ParseError, SolveError};
+use crate::grid::Grid;
+use crate::parallel;
+use crate::visualize::{Color, Frame, Visualizer};
+use crate::year2023::day16::Entity::*;
+
+#[derive(Clone, Eq, PartialEq)]
+pub enum Entity {
+    Empty,
+    UpRightMirror,
+    DownRightMirror,
+    HSplitter,
+    VSplitter,
+}
+
+#[derive(Clone)]
+pub struct Cell {
+    entity: Entity,
+    energized: bool,
+    traced: [bool; 4],
+}
+
+impl Cell {
+    fn new(entity: Entity) -> Cell {
+        Cell {
+            entity,
+            energized: false,
+            traced: [false; 4],
+        }
+    }
+}
+
+pub fn parse_map(s: &str) -> Result<Grid<Cell>, ParseError> {
+    Grid::parse(s, "a mirror, a splitter or '.'", |c| {
+        let entity = match c {
+            '/' => UpRightMirror,
+            '\\' => DownRightMirror,
+            '-' => HSplitter,
+            '|' => VSplitter,
+            '.' => Empty,
+            _ => return None,
+        };
+
+        Some(Cell::new(entity))
+    })
+}
+
+pub fn trace_beam(map: &mut Grid<Cell>, start: (usize, usize), direction: Direction) {
+    trace_beam_with(map, start, direction, &mut |_, _| {});
+}
+
+/// Traces a beam like 'trace_beam', calling a function each time the beam enters a cell so that
+/// its progress can be watched.
+///
+/// # Arguments
+///
+/// 'map' - The contraption, which records the cells the beam passes through.
+///
+/// 'start' - The position the beam enters the contraption at.
+///
+/// 'direction' - The direction the beam is travelling in.
+///
+/// 'on_step' - The function called with the map and the position of the beam at each step.
+pub fn trace_beam_with<F>(
+    map: &mut Grid<Cell>,
+    start: (usize, usize),
+    mut direction: Direction,
+    on_step: &mut F,
+) where
+    F: FnMut(&Grid<Cell>, (usize, usize)),
+{
+    let mut pos = start;
+
+    loop {
+        let cell = &mut map[pos];
+
+        if cell.traced[direction as usize] {
+            return;
+        }
+        cell.traced[direction as usize] = true;
+        cell.energized = true;
+        on_step(map, pos);
+        let cell = &map[pos];
+
+        let mut directions = match cell.entity {
+            Empty => vec![direction],
+            VSplitter if !direction.is_vertical() => vec![Up, Down],
+            HSplitter if direction.is_vertical() => vec![Left, Right],
+            VSplitter | HSplitter => vec![direction],
+            UpRightMirror if direction.is_vertical() => vec![direction.turn_right()],
+            UpRightMirror => vec![direction.turn_left()],
+            DownRightMirror if direction.is_vertical() => vec![direction.turn_left()],
+            DownRightMirror => vec![direction.turn_right()],
+        };
+
+        // Follow the last direction here, and trace any other beam from a splitter separately
+        let last = directions.pop().unwrap_or(direction);
+        for next in directions {
+            if let Some(next_pos) = map.offset(pos, next.vector()) {
+                trace_beam_with(map, next_pos, next, on_step);
+            }
+        }
+
+        match map.offset(pos, last.vector()) {
+            Some(next_pos) => {
+                pos = next_pos;
+                direction = last;
+            }
+            None => return,
+        }
+    }
+}
+
+pub fn reset(map: &mut Grid<Cell>) {
+    for (_, cell) in map.iter_mut() {
+        cell.energized = false;
+        cell.traced = [false; 4];
+    }
+}
+
+pub fn calculate_energy(map: &Grid<Cell>) -> i32 {
+    map.iter().filter(|(_, c)| c.energized).count() as i32
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_one(input: &str) -> Result<i32, SolveError> {
+    let mut map = parse_map(input)?;
+    trace_beam(&mut map, (0, 0), Right);
+
+    Ok(calculate_energy(&map))
+}
+
+/// Returns every position and direction a beam can enter the contraption from its edges.
+///
+/// # Arguments
+///
+/// 'map' - The contraption.
+fn edge_launches(map: &Grid<Cell>) -> Vec<((usize, usize), Direction)> {
+    let height = map.height();
+    let width = map.width();
+
+    (0..width)
+        .flat_map(|x| [((x, 0), Down), ((x, height - 1), Up)])
+        .chain((0..height).flat_map(|y| [((0, y), Right), ((width - 1, y), Left)]))
+        .collect()
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_two(input: &str) -> Result<i32, SolveError> {
+    let map = parse_map(input)?;
+
+    let launches = edge_launches(&map);
+
+    // Each thread traces its beams on its own copy of the map, resetting it after every beam
+    let energies = parallel::map_with(
+        launches,
+        || map.clone(),
+        |map, (start, direction)| {
+            trace_beam(map, start, direction);
+            let energy = calculate_energy(map);
+            reset(map);
+            energy
+        },
+    );
+
+    Ok(energies.into_iter().max().unwrap_or(0))
+}
+
+/// Draws the beam from the top left corner working its way through the contraption, one cell at
+/// a time, and then the most energized contraption from any starting point on the edge.
+///
+/// # Arguments
+///
+/// 'input' - The input.
+///
+/// 'visualizer' - Where to show the frames.
+pub fn visualize(input: &str, visualizer: &mut Visualizer) -> Result<(), SolveError> {
+    let mut map = parse_map(input)?;
+    let mut result = Ok(());
+
+    trace_beam_with(&mut map, (0, 0), Right, &mut |map, pos| {
+        if result.is_ok() {
+            result = visualizer.step(|| {
+                let caption = format!(
+                    "Tracing the beam: {} tiles energized",
+                    calculate_energy(map)
+                );
+                let mut frame = draw(caption, map);
+                frame.paint(pos, Color::Red);
+                frame
+            });
+        }
+    });
+    result?;
+
+    let best = part_two(input)?;
+
+    for (start, direction) in edge_launches(&map) {
+        reset(&mut map);
+        trace_beam(&mut map, start, direction);
+
+        if calculate_energy(&map) == best {
+            let caption = format!("The most energizing beam: {best} tiles energized");
+            let mut frame = draw(caption, &map);
+            frame.paint(start, Color::Red);
+            return visualizer.show(frame);
+        }
+    }
+
+    Ok(())
+}
+
+/// Draws the contraption, with the energized tiles lit up.
+///
+/// # Arguments
+///
+/// 'caption' - The description of the step shown.
+///
+/// 'map' - The contraption.
+fn draw(caption: String, map: &Grid<Cell>) -> Frame {
+    Frame::new(caption, map, |_, cell| {
+        let c = match cell.entity {
+            Empty if cell.energized => '#',
+            Empty => '.',
+            UpRightMirror => '/',
+            DownRightMirror => '\\',
+            HSplitter => '-',
+            VSplitter => '|',
+        };
+
+        (
+            c,
+            if cell.energized {
+                Color::Yellow
+            } else {
+                Color::Dim
+            },
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2023::day16::*;
+
+    #[test]
+    fn part_one_correct() {
+        assert_eq!(
+            part_one(
+                ".|...\\....
+|.-.\\.....
+.....|-...
+........|.
+..........
+.........\\
+..../.\\\\..
+.-.-/..|..
+.|....-|.\\
+..//.|...."
+            ),
+            Ok(46)
+        );
+    }
+
+    #[test]
+    fn part_two_correct() {
+        assert_eq!(
+            part_two(
+                ".|...\\....
+|.-.\\.....
+.....|-...
+........|.
+..........
+.........\\
+..../.\\\\..
+.-.-/..|..
+.|....-|.\\
+..//.|...."
+            ),
+            Ok(51)
+        );
+    }
+
+    #[test]
+    fn malformed_maps_are_an_error() {
+        assert_eq!(
+            part_one(".|..\n..x.").unwrap_err().to_string(),
+            "line 2, column 3: expected a mirror, a splitter or '.' but found 'x'"
+        );
+        assert_eq!(
+            part_two("").unwrap_err().to_string(),
+            "line 1, column 1: expected a grid but found nothing"
+        );
+    }
+}
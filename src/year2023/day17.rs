@@ -0,0 +1,421 @@
+use crate::direction::Direction::{self, Down, Left, Right, Up};
+use crate::error::{ParseError, SolveError};
+use crate::grid::Grid;
+use crate::visualize::{Color, Frame, Visualizer};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+#[derive(Debug)]
+struct Node {
+    x: usize,
+    y: usize,
+    cost: usize,
+    last_direction: Direction,
+}
+
+impl Eq for Node {}
+
+impl PartialEq<Self> for Node {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
+    }
+}
+
+impl PartialOrd<Self> for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.cmp(&self.cost)
+    }
+}
+
+pub struct Map {
+    pub heat: Grid<usize>,
+}
+
+impl TryFrom<&str> for Map {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let heat = Grid::parse(value, "a digit", |c| c.to_digit(10).map(|d| d as usize))?;
+
+        Ok(Map { heat })
+    }
+}
+
+impl Map {
+    fn create_nodes_in_direction(
+        &self,
+        node: &Node,
+        dir: Direction,
+        min: usize,
+        max: usize,
+    ) -> Vec<Node> {
+        let mut result = vec![];
+        let mut pos = (node.x, node.y);
+        let mut cost = node.cost;
+
+        for i in 0..max {
+            let Some(new_pos) = self.heat.offset(pos, dir.vector()) else {
+                break;
+            };
+            pos = new_pos;
+            cost += self.heat[pos];
+            if i + 1 >= min {
+                result.push(Node {
+                    x: pos.0,
+                    y: pos.1,
+                    last_direction: dir,
+                    cost,
+                });
+            }
+        }
+
+        result
+    }
+
+    fn get_next_nodes(&self, node: &Node, min: usize, max: usize) -> Vec<Node> {
+        let mut result =
+            self.create_nodes_in_direction(node, node.last_direction.turn_left(), min, max);
+        result.append(&mut self.create_nodes_in_direction(
+            node,
+            node.last_direction.turn_right(),
+            min,
+            max,
+        ));
+
+        result
+    }
+}
+
+/// Finds the cheapest cost of reaching each block from the top left, keeping two costs for each
+/// block: the cost when arriving vertically, then when arriving horizontally.
+///
+/// # Arguments
+///
+/// 'map' - The map of heat loss.
+///
+/// 'min' - The fewest blocks the crucible can move before turning.
+///
+/// 'max' - The most blocks the crucible can move before turning.
+///
+/// 'on_improve' - The function called with the costs found so far and the position of a block
+/// each time a cheaper way of reaching it is found.
+pub fn find_costs<F>(map: &Map, min: usize, max: usize, mut on_improve: F) -> Grid<(usize, usize)>
+where
+    F: FnMut(&Grid<(usize, usize)>, (usize, usize)),
+{
+    // Keep two cost values for each node, depending on if we reach it from a horizontal direction vs. vertical
+    let mut costs = Grid::new(
+        map.heat.width(),
+        map.heat.height(),
+        (usize::MAX, usize::MAX),
+    );
+    costs[(0, 0)] = (0, 0);
+
+    let mut heap = BinaryHeap::new();
+    let start = Node {
+        x: 0,
+        y: 0,
+        cost: 0,
+        last_direction: Right,
+    };
+    let mut start_nodes = map.create_nodes_in_direction(&start, Right, min, max);
+    start_nodes.append(&mut map.create_nodes_in_direction(&start, Down, min, max));
+    for node in start_nodes {
+        heap.push(node);
+    }
+
+    while let Some(node) = heap.pop() {
+        let old_cost = if node.last_direction.is_vertical() {
+            &mut costs[(node.x, node.y)].0
+        } else {
+            &mut costs[(node.x, node.y)].1
+        };
+
+        if node.cost < *old_cost {
+            *old_cost = node.cost;
+            on_improve(&costs, (node.x, node.y));
+
+            let mut new_nodes = map.get_next_nodes(&node, min, max);
+            while let Some(node) = new_nodes.pop() {
+                heap.push(node);
+            }
+        }
+    }
+
+    costs
+}
+
+/// Returns the blocks along a cheapest path from the top left to the bottom right, starting with
+/// the top left, by working back from the end through the costs found by 'find_costs'. Returns
+/// None if the end can't be reached.
+///
+/// # Arguments
+///
+/// 'map' - The map of heat loss.
+///
+/// 'costs' - The costs of reaching each block.
+///
+/// 'min' - The fewest blocks the crucible can move before turning.
+///
+/// 'max' - The most blocks the crucible can move before turning.
+pub fn cheapest_path(
+    map: &Map,
+    costs: &Grid<(usize, usize)>,
+    min: usize,
+    max: usize,
+) -> Option<Vec<(usize, usize)>> {
+    let mut pos = (map.heat.width() - 1, map.heat.height() - 1);
+    let (vertical, horizontal) = costs[pos];
+    let mut arrived_vertically = vertical <= horizontal;
+    let mut cost = vertical.min(horizontal);
+    let mut path = vec![pos];
+
+    if cost == usize::MAX {
+        return None;
+    }
+
+    // Each leg of the path is a straight line, arriving from the opposite direction to the leg
+    // before it, so look back along the line for a block whose cost accounts for the difference.
+    while pos != (0, 0) {
+        let back = if arrived_vertically {
+            [Up, Down]
+        } else {
+            [Left, Right]
+        };
+        let mut found = None;
+
+        'search: for direction in back {
+            let mut leg = vec![];
+            let mut leg_cost = 0;
+            let mut from = pos;
+
+            for i in 0..max {
+                leg_cost += map.heat[from];
+                leg.push(from);
+                from = match map.heat.offset(from, direction.vector()) {
+                    Some(from) => from,
+                    None => break,
+                };
+
+                let before = if arrived_vertically {
+                    costs[from].1
+                } else {
+                    costs[from].0
+                };
+                if i + 1 >= min && before.checked_add(leg_cost) == Some(cost) {
+                    found = Some((from, before, leg));
+                    break 'search;
+                }
+            }
+        }
+
+        let (from, before, leg) = found?;
+        path.extend(leg.into_iter().skip(1));
+        path.push(from);
+        (pos, cost, arrived_vertically) = (from, before, !arrived_vertically);
+    }
+
+    path.reverse();
+    Some(path)
+}
+
+pub fn solve_with_min_max(input: &str, min: usize, max: usize) -> Result<usize, SolveError> {
+    let map = Map::try_from(input)?;
+    let costs = find_costs(&map, min, max, |_, _| {});
+
+    let dest_x = map.heat.width() - 1;
+    let dest_y = map.heat.height() - 1;
+
+    let cost = costs[(dest_x, dest_y)];
+    match cost.0.min(cost.1) {
+        usize::MAX => Err(SolveError::new("No path reaches the bottom right corner")),
+        cost => Ok(cost),
+    }
+}
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_one(input: &str) -> Result<usize, SolveError> {
+    solve_with_min_max(input, 1, 3)
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_two(input: &str) -> Result<usize, SolveError> {
+    solve_with_min_max(input, 4, 10)
+}
+
+/// Draws the search for the cheapest path spreading out from the top left for each crucible,
+/// and then the cheapest path it found.
+///
+/// # Arguments
+///
+/// 'input' - The input.
+///
+/// 'visualizer' - Where to show the frames.
+pub fn visualize(input: &str, visualizer: &mut Visualizer) -> Result<(), SolveError> {
+    let map = Map::try_from(input)?;
+
+    for (name, min, max) in [("crucible", 1, 3), ("ultra crucible", 4, 10)] {
+        let mut result = Ok(());
+        let mut improvements = 0;
+
+        let costs = find_costs(&map, min, max, |costs, pos| {
+            improvements += 1;
+            if result.is_ok() {
+                result = visualizer.step(|| {
+                    let caption = format!("Searching for the {name}: {improvements} improvements");
+                    let mut frame =
+                        draw(caption, &map, |pos| costs[pos] != (usize::MAX, usize::MAX));
+                    frame.paint(pos, Color::Red);
+                    frame
+                });
+            }
+        });
+        result?;
+
+        let path = cheapest_path(&map, &costs, min, max)
+            .ok_or(SolveError::new("No path reaches the bottom right corner"))?;
+        let cost: usize = path.iter().skip(1).map(|pos| map.heat[*pos]).sum();
+
+        let mut frame = draw(format!("The {name}'s path loses {cost} heat"), &map, |_| {
+            false
+        });
+        for pos in path {
+            frame.paint(pos, Color::Yellow);
+        }
+        visualizer.show(frame)?;
+    }
+
+    Ok(())
+}
+
+/// Draws the map of heat loss, with some blocks picked out.
+///
+/// # Arguments
+///
+/// 'caption' - The description of the step shown.
+///
+/// 'map' - The map of heat loss.
+///
+/// 'reached' - The function that says whether a block has been reached.
+fn draw<F>(caption: String, map: &Map, reached: F) -> Frame
+where
+    F: Fn((usize, usize)) -> bool,
+{
+    Frame::new(caption, &map.heat, |pos, heat| {
+        let c = char::from_digit(*heat as u32, 10).unwrap_or('?');
+        (
+            c,
+            if reached(pos) {
+                Color::Blue
+            } else {
+                Color::Dim
+            },
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2023::day17::*;
+
+    #[test]
+    fn cheapest_path_matches_the_cost() {
+        let map = Map::try_from("24134\n32154\n32552\n34465\n45466").unwrap();
+
+        for (min, max) in [(1, 3), (4, 10)] {
+            let costs = find_costs(&map, min, max, |_, _| {});
+            let path = cheapest_path(&map, &costs, min, max).unwrap();
+            let (vertical, horizontal) = costs[(4, 4)];
+
+            assert_eq!(path.first(), Some(&(0, 0)));
+            assert_eq!(path.last(), Some(&(4, 4)));
+            assert!(path
+                .windows(2)
+                .all(|w| w[0].0.abs_diff(w[1].0) + w[0].1.abs_diff(w[1].1) == 1));
+            assert_eq!(
+                path.iter().skip(1).map(|pos| map.heat[*pos]).sum::<usize>(),
+                vertical.min(horizontal)
+            );
+        }
+    }
+
+    #[test]
+    fn part_one_correct() {
+        assert_eq!(
+            part_one(
+                "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533"
+            ),
+            Ok(102)
+        );
+    }
+
+    #[test]
+    fn part_two_correct() {
+        assert_eq!(
+            part_two(
+                "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533"
+            ),
+            Ok(94)
+        );
+
+        assert_eq!(
+            part_two(
+                "111111111111
+999999999991
+999999999991
+999999999991
+999999999991"
+            ),
+            Ok(71)
+        )
+    }
+
+    #[test]
+    fn malformed_map_is_an_error() {
+        assert_eq!(
+            part_one("123\n45").unwrap_err().to_string(),
+            "line 2, column 1: expected a row of 3 characters but found '45'"
+        );
+        assert_eq!(
+            part_one("123\n4x6").unwrap_err().to_string(),
+            "line 2, column 2: expected a digit but found 'x'"
+        );
+    }
+}
@@ -1,49 +1,71 @@
-use crate::day18::State::{Bottom, Inside, Outside, Top};
+use crate::direction::Direction::{self, Down, Left, Right, Up};
+use crate::error::{parse_lines, parse_value, ParseError, SolveError};
+use crate::year2023::day18::State::{Bottom, Inside, Outside, Top};
 use itertools::Itertools;
 
-struct Command {
-    vector: (i64, i64),
-    vector_large: (i64, i64),
+pub struct Command {
+    pub vector: (i64, i64),
+    pub vector_large: (i64, i64),
 }
 
-fn decode_large_vector(value: &str) -> (i64, i64) {
-    let code = value.strip_prefix("(#").unwrap();
+fn decode_large_vector(value: &str) -> Result<(i64, i64), ParseError> {
+    let code = value
+        .strip_prefix("(#")
+        .and_then(|code| code.strip_suffix(')'))
+        .filter(|code| code.len() == 6 && code.is_ascii())
+        .ok_or_else(|| ParseError::new(value, value, "a color code such as '(#70c710)'"))?;
 
     let size_code = &code[0..5];
     let dir = &code[5..6];
 
-    let size = i64::from_str_radix(size_code, 16).unwrap();
+    let size = i64::from_str_radix(size_code, 16)
+        .map_err(|_| ParseError::new(value, size_code, "a hexadecimal distance"))?;
 
-    match dir {
-        "0" => Some((size, 0)),
-        "1" => Some((0, size)),
-        "2" => Some((-size, 0)),
-        "3" => Some((0, -size)),
-        _ => None,
-    }
-    .unwrap()
+    let direction = match dir {
+        "0" => Right,
+        "1" => Down,
+        "2" => Left,
+        "3" => Up,
+        _ => return Err(ParseError::new(value, dir, "a direction from 0 to 3")),
+    };
+
+    Ok(scale(direction, size))
 }
 
-impl From<&str> for Command {
-    fn from(value: &str) -> Self {
-        let (dir, dist, vector_large) = value.split_whitespace().collect_tuple().unwrap();
-        let dist = dist.parse::<i64>().unwrap();
+/// Returns the vector for moving the given distance in a direction.
+///
+/// # Arguments
+///
+/// 'direction' - The direction to move in.
+///
+/// 'distance' - The number of steps to take.
+fn scale(direction: Direction, distance: i64) -> (i64, i64) {
+    let (x, y) = direction.vector();
+    (x * distance, y * distance)
+}
+
+impl TryFrom<&str> for Command {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (dir, dist, vector_large) = value
+            .split_whitespace()
+            .collect_tuple()
+            .ok_or_else(|| ParseError::missing(value, "a direction, distance and color code"))?;
+        let dist = parse_value::<i64>(value, dist, "a distance")?;
 
         let vector = match dir {
-            "R" => Some((dist, 0)),
-            "L" => Some((-dist, 0)),
-            "U" => Some((0, -dist)),
-            "D" => Some((0, dist)),
-            _ => None,
-        }
-        .unwrap();
+            "R" | "L" | "U" | "D" => Ok(scale(dir.parse()?, dist)),
+            _ => Err(ParseError::new(value, dir, "'R', 'L', 'U' or 'D'")),
+        }?;
 
-        let vector_large = decode_large_vector(vector_large);
+        let vector_large =
+            decode_large_vector(vector_large).map_err(|e| e.within(value, vector_large))?;
 
-        Command {
+        Ok(Command {
             vector,
             vector_large,
-        }
+        })
     }
 }
 
@@ -119,9 +141,13 @@ enum State {
     Inside,
 }
 
-fn calculate_volume(commands: Vec<Command>) -> i64 {
+pub fn calculate_volume(commands: Vec<Command>) -> i64 {
     let mut map = Map::new();
 
+    if commands.is_empty() {
+        return 0;
+    }
+
     for cmd in commands {
         map.execute(&cmd);
     }
@@ -205,10 +231,10 @@ fn calculate_volume(commands: Vec<Command>) -> i64 {
 /// #Argument
 ///
 /// 'input' - The input.
-pub fn part_one(input: &str) -> i64 {
-    let commands = input.lines().map(Command::from).collect_vec();
+pub fn part_one(input: &str) -> Result<i64, SolveError> {
+    let commands = parse_lines(input, Command::try_from)?;
 
-    calculate_volume(commands)
+    Ok(calculate_volume(commands))
 }
 
 ///
@@ -216,22 +242,21 @@ pub fn part_one(input: &str) -> i64 {
 /// #Argument
 ///
 /// 'input' - The input.
-pub fn part_two(input: &str) -> i64 {
-    let commands = input
-        .lines()
-        .map(Command::from)
+pub fn part_two(input: &str) -> Result<i64, SolveError> {
+    let commands = parse_lines(input, Command::try_from)?
+        .into_iter()
         .map(|cmd| Command {
             vector: cmd.vector_large,
             vector_large: cmd.vector,
         })
         .collect_vec();
 
-    calculate_volume(commands)
+    Ok(calculate_volume(commands))
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day18::*;
+    use crate::year2023::day18::*;
 
     #[test]
     fn part_one_correct() {
@@ -252,7 +277,7 @@ U 3 (#a77fa3)
 L 2 (#015232)
 U 2 (#7a21e3)"
             ),
-            62
+            Ok(62)
         );
     }
 
@@ -275,7 +300,29 @@ U 3 (#a77fa3)
 L 2 (#015232)
 U 2 (#7a21e3)"
             ),
-            952408144115
+            Ok(952408144115)
+        );
+    }
+
+    #[test]
+    fn malformed_command_is_an_error() {
+        assert_eq!(
+            part_one("R 6 (#70c710)\nX 5 (#0dc571)")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 1: expected 'R', 'L', 'U' or 'D' but found 'X'"
+        );
+        assert_eq!(
+            part_two("R 6 (#70c710)\nD 5 (#0dc57)")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 5: expected a color code such as '(#70c710)' but found '(#0dc57)'"
+        );
+        assert_eq!(
+            part_two("R 6 (#70c710)\nD 5 (#0dc574)")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 12: expected a direction from 0 to 3 but found '4'"
         );
     }
 }
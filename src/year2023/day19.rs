@@ -0,0 +1,604 @@
+use crate::error::{
+    char_at, parse_lines, parse_value, split_value, strip_value, ParseError, SolveError,
+};
+use crate::graph::Graph;
+use crate::interval::{Interval, Region};
+use crate::year2023::day19::Operator::{Greater, Less};
+use crate::year2023::day19::Variable::{A, M, S, X};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+#[derive(Debug, Copy, Clone)]
+pub enum Variable {
+    X,
+    M,
+    A,
+    S,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Operator {
+    Less,
+    Greater,
+}
+
+#[derive(Debug)]
+pub struct Rule<'a> {
+    pub var: Variable,
+    pub op: Operator,
+    pub val: u32,
+    pub result: &'a str,
+}
+
+impl<'a> TryFrom<&'a str> for Rule<'a> {
+    type Error = ParseError;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let var = match char_at(value, 0) {
+            "x" => X,
+            "m" => M,
+            "a" => A,
+            "s" => S,
+            c => return Err(ParseError::new(value, c, "'x', 'm', 'a' or 's'")),
+        };
+
+        let op = match char_at(value, 1) {
+            "<" => Less,
+            ">" => Greater,
+            c => return Err(ParseError::new(value, c, "'<' or '>'")),
+        };
+
+        let (val_text, result) = split_value(value, &value[2..], ":")?;
+
+        let val = parse_value::<u32>(value, val_text, "a value")?;
+        if val == 0 {
+            return Err(ParseError::new(value, val_text, "a value greater than 0"));
+        }
+
+        Ok(Rule {
+            var,
+            op,
+            val,
+            result,
+        })
+    }
+}
+
+impl<'a> Rule<'a> {
+    /// Splits a template into the part that matches the rule, which is sent on to the rule's
+    /// result, and the part that doesn't, which stays with the template's current workflow.
+    /// Either part is None if it would be empty.
+    ///
+    /// # Arguments
+    ///
+    /// 'template' - The template to split.
+    pub fn split_template(&self, template: &Template) -> (Option<Template>, Option<Template>) {
+        let axis = self.var as usize;
+        let val = i64::from(self.val);
+
+        let (matched, rest) = match self.op {
+            Less => template.region.split_at(axis, val),
+            Greater => {
+                let (below, above) = template.region.split_at(axis, val + 1);
+                (above, below)
+            }
+        };
+
+        (
+            matched.map(|region| Template {
+                rule: String::from(self.result),
+                region,
+            }),
+            rest.map(|region| Template {
+                rule: template.rule.clone(),
+                region,
+            }),
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct Workflow<'a> {
+    pub rules: Vec<Rule<'a>>,
+    pub default: &'a str,
+}
+
+impl<'a> TryFrom<&'a str> for Workflow<'a> {
+    type Error = ParseError;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let mut parts = value.split(',').collect_vec();
+        let default = parts.pop().filter(|d| !d.is_empty());
+        let default = default.ok_or_else(|| ParseError::missing(value, "a default result"))?;
+
+        let rules = parts
+            .iter()
+            .map(|r| Rule::try_from(*r).map_err(|e| e.within(value, r)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Workflow { rules, default })
+    }
+}
+
+impl<'a> Workflow<'a> {
+    pub fn get_result(&self, part: &Part) -> &'a str {
+        for rule in &self.rules {
+            let var = match rule.var {
+                X => part.x,
+                M => part.m,
+                A => part.a,
+                S => part.s,
+            };
+
+            let val = rule.val;
+
+            let success = match rule.op {
+                Less => var < val,
+                Greater => var > val,
+            };
+
+            if success {
+                return rule.result;
+            }
+        }
+
+        self.default
+    }
+
+    pub fn split_template(&self, template: &Template) -> Vec<Template> {
+        let mut t = template.clone();
+        let mut result = vec![];
+
+        for rule in &self.rules {
+            let (a, b) = rule.split_template(&t);
+            if let Some(a) = a {
+                result.push(a);
+            }
+
+            if let Some(b) = b {
+                t = b;
+            } else {
+                // Every part was matched by this rule, so none are left for the default:
+                return result;
+            }
+        }
+        t.rule = String::from(self.default);
+        result.push(t);
+
+        result
+    }
+}
+
+pub struct Part {
+    pub x: u32,
+    pub m: u32,
+    pub a: u32,
+    pub s: u32,
+}
+
+impl TryFrom<&str> for Part {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let values = strip_value(value, value, "{")?
+            .strip_suffix('}')
+            .ok_or_else(|| ParseError::missing(value, "'}'"))?
+            .split(',')
+            .collect_vec();
+        let mut i = values.iter();
+
+        let mut rating = |name: &str| {
+            let v = i
+                .next()
+                .ok_or_else(|| ParseError::new(value, &value[value.len() - 1..], "','"))?;
+            let v = strip_value(value, v, &format!("{name}="))?;
+            parse_value::<u32>(value, v, "a rating")
+        };
+
+        let x = rating("x")?;
+        let m = rating("m")?;
+        let a = rating("a")?;
+        let s = rating("s")?;
+
+        Ok(Part { x, m, a, s })
+    }
+}
+
+/// A set of parts waiting to be sent through a workflow, with each rating in a range of values.
+/// The ranges are kept in a region indexed by the rating's variable.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Template {
+    pub rule: String,
+    pub region: Region<4>,
+}
+
+impl Default for Template {
+    fn default() -> Self {
+        Template::new()
+    }
+}
+
+impl Template {
+    pub fn new() -> Self {
+        Template {
+            rule: String::from("in"),
+            region: Region::new([Interval::inclusive(1, 4000); 4]),
+        }
+    }
+
+    pub fn get_distinct_count(&self) -> u64 {
+        self.region.volume() as u64
+    }
+}
+
+pub fn parse_workflow(s: &str) -> Result<(&str, Workflow<'_>), ParseError> {
+    let body = s
+        .strip_suffix('}')
+        .ok_or_else(|| ParseError::missing(s, "'}'"))?;
+    let (name, rules) = split_value(s, body, "{")?;
+
+    let workflow = Workflow::try_from(rules).map_err(|e| e.within(s, rules))?;
+
+    Ok((name, workflow))
+}
+
+/// Splits the input into the text of the workflows and the text of the parts.
+///
+/// # Arguments
+///
+/// 'input' - The input.
+fn split_input(input: &str) -> Result<(&str, &str), SolveError> {
+    input.split_once("\n\n").ok_or_else(|| {
+        ParseError::missing(input, "a blank line between workflows and parts").into()
+    })
+}
+
+/// Parses the workflows, checking that every result they can lead to is either "A", "R", or
+/// another workflow, and that no workflow can lead back to itself, so that every part is
+/// eventually accepted or rejected.
+///
+/// # Arguments
+///
+/// 'text' - The workflows section of the input.
+fn parse_workflows(text: &str) -> Result<HashMap<&str, Workflow<'_>>, SolveError> {
+    let workflows = parse_lines(text, parse_workflow)?;
+    let names: HashMap<_, _> = workflows
+        .iter()
+        .enumerate()
+        .map(|(i, (name, _))| (*name, i))
+        .collect();
+
+    if !names.contains_key("in") {
+        return Err(SolveError::new("Missing starting workflow 'in'"));
+    }
+
+    let mut graph = Graph::new();
+    for (name, workflow) in &workflows {
+        let results = workflow.rules.iter().map(|r| r.result);
+        for result in results.chain([workflow.default]) {
+            if result == "A" || result == "R" {
+                continue;
+            }
+            if !names.contains_key(result) {
+                return Err(ParseError::new(text, result, "a known workflow").into());
+            }
+            graph.add_edge(*name, result, 1);
+        }
+    }
+
+    if graph.topological_sort().is_none() {
+        // There's a loop somewhere, so find the first workflow on it to report
+        let on_loop = |name| {
+            let Some(id) = graph.id(name) else {
+                return false;
+            };
+            graph.neighbours(id).any(|to| graph.dfs(to).contains(&id))
+        };
+        if let Some((line, (name, _))) = workflows
+            .iter()
+            .enumerate()
+            .find(|(_, (name, _))| on_loop(name))
+        {
+            return Err(SolveError::at_line(
+                line + 1,
+                format!("Workflow '{name}' can lead back to itself"),
+            ));
+        }
+    }
+
+    Ok(workflows.into_iter().collect())
+}
+
+/// Parses the workflows and the parts that are to be sent through them.
+///
+/// # Arguments
+///
+/// 'input' - The input.
+pub fn parse_system(input: &str) -> Result<(HashMap<&str, Workflow<'_>>, Vec<Part>), SolveError> {
+    let (workflows, parts) = split_input(input)?;
+    let parts = parse_lines(parts, Part::try_from)
+        .map_err(|e| e.offset_line(workflows.lines().count() + 1))?;
+    let workflows = parse_workflows(workflows)?;
+
+    Ok((workflows, parts))
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_one(input: &str) -> Result<u64, SolveError> {
+    let (workflows, parts) = parse_system(input)?;
+
+    let mut accepted = vec![];
+
+    for part in &parts {
+        let mut cur = "in";
+
+        loop {
+            let result = workflows[cur].get_result(part);
+            if result == "A" {
+                accepted.push(part);
+                break;
+            } else if result == "R" {
+                break;
+            } else {
+                cur = result;
+            }
+        }
+    }
+
+    Ok(accepted
+        .iter()
+        .map(|p| [p.x, p.m, p.a, p.s].into_iter().map(u64::from).sum::<u64>())
+        .sum())
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_two(input: &str) -> Result<u64, SolveError> {
+    let (workflows, _) = split_input(input)?;
+    let workflows = parse_workflows(workflows)?;
+
+    let mut templates = vec![Template::new()];
+    let mut accepted = vec![];
+
+    while let Some(template) = templates.pop() {
+        let rule = &workflows[template.rule.as_str()];
+        let new_templates = rule.split_template(&template);
+
+        for t in new_templates {
+            if t.rule == "A" {
+                accepted.push(t);
+            } else if t.rule != "R" {
+                templates.push(t);
+            }
+        }
+    }
+
+    Ok(accepted.iter().map(|a| a.get_distinct_count()).sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2023::day19::*;
+
+    fn make_template(rule: &str, ranges: [(i64, i64); 4]) -> Template {
+        Template {
+            rule: String::from(rule),
+            region: Region::new(ranges.map(|(first, last)| Interval::inclusive(first, last))),
+        }
+    }
+
+    #[test]
+    fn test_split_template_on_rule() {
+        let template = Template::new();
+        let rule = Rule {
+            var: X,
+            op: Less,
+            val: 1000,
+            result: "a",
+        };
+        let (a, b) = rule.split_template(&template);
+
+        assert_eq!(
+            a,
+            Some(make_template(
+                "a",
+                [(1, 999), (1, 4000), (1, 4000), (1, 4000)]
+            ))
+        );
+        assert_eq!(
+            b,
+            Some(make_template(
+                "in",
+                [(1000, 4000), (1, 4000), (1, 4000), (1, 4000)]
+            ))
+        );
+
+        let rule = Rule {
+            var: X,
+            op: Greater,
+            val: 1000,
+            result: "a",
+        };
+        let (a, b) = rule.split_template(&template);
+
+        assert_eq!(
+            a,
+            Some(make_template(
+                "a",
+                [(1001, 4000), (1, 4000), (1, 4000), (1, 4000)]
+            ))
+        );
+        assert_eq!(
+            b,
+            Some(make_template(
+                "in",
+                [(1, 1000), (1, 4000), (1, 4000), (1, 4000)]
+            ))
+        );
+
+        let rule = Rule {
+            var: M,
+            op: Less,
+            val: 1000,
+            result: "a",
+        };
+        let (a, b) = rule.split_template(&template);
+
+        assert_eq!(
+            a,
+            Some(make_template(
+                "a",
+                [(1, 4000), (1, 999), (1, 4000), (1, 4000)]
+            ))
+        );
+        assert_eq!(
+            b,
+            Some(make_template(
+                "in",
+                [(1, 4000), (1000, 4000), (1, 4000), (1, 4000)]
+            ))
+        );
+
+        let (a, b) = rule.split_template(&b.unwrap());
+
+        assert_eq!(a, None);
+        assert_eq!(
+            b,
+            Some(make_template(
+                "in",
+                [(1, 4000), (1000, 4000), (1, 4000), (1, 4000)]
+            ))
+        );
+    }
+    #[test]
+    fn test_split_template_on_workflow() {
+        let workflow = Workflow::try_from("m>1000:A,x<10:px,R").unwrap();
+        let templates = workflow.split_template(&Template::new());
+
+        assert_eq!(
+            templates,
+            vec![
+                make_template("A", [(1, 4000), (1001, 4000), (1, 4000), (1, 4000)]),
+                make_template("px", [(1, 9), (1, 1000), (1, 4000), (1, 4000)]),
+                make_template("R", [(10, 4000), (1, 1000), (1, 4000), (1, 4000)]),
+            ]
+        );
+
+        // When a rule matches the whole template, there is nothing left for the default:
+        let template = make_template("in", [(1, 4000), (2000, 3000), (1, 4000), (1, 4000)]);
+        assert_eq!(
+            workflow.split_template(&template),
+            vec![make_template(
+                "A",
+                [(1, 4000), (2000, 3000), (1, 4000), (1, 4000)]
+            )]
+        );
+    }
+
+    #[test]
+    fn part_one_correct() {
+        assert_eq!(
+            part_one(
+                "px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}"
+            ),
+            Ok(19114)
+        );
+    }
+
+    #[test]
+    fn part_two_correct() {
+        assert_eq!(
+            part_two(
+                "px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}"
+            ),
+            Ok(167409079868000)
+        );
+    }
+
+    #[test]
+    fn malformed_system_is_an_error() {
+        assert_eq!(
+            part_one("in{x<10:A,R}\n\n{x=1,m=2,a=3,s=4}\n{x=1,m=2,a=3}")
+                .unwrap_err()
+                .to_string(),
+            "line 4, column 13: expected ',' but found '}'"
+        );
+        assert_eq!(
+            part_two("in{x<10:px,R}\n\n{x=1,m=2,a=3,s=4}")
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 9: expected a known workflow but found 'px'"
+        );
+        assert_eq!(
+            part_two("in{y<10:A,R}\n\n").unwrap_err().to_string(),
+            "line 1, column 4: expected 'x', 'm', 'a' or 's' but found 'y'"
+        );
+        assert_eq!(
+            part_one("in{x<0:A,R}\n\n{x=1,m=2,a=3,s=4}")
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 6: expected a value greater than 0 but found '0'"
+        );
+        assert_eq!(
+            part_one("in{x<10:in,in}\n\n{x=1,m=2,a=3,s=4}"),
+            Err(SolveError::at_line(
+                1,
+                "Workflow 'in' can lead back to itself"
+            ))
+        );
+        assert_eq!(
+            part_two("in{x<10:px,A}\npx{a>5:R,qq}\nqq{px}\n\n"),
+            Err(SolveError::at_line(
+                2,
+                "Workflow 'px' can lead back to itself"
+            ))
+        );
+    }
+
+    #[test]
+    fn large_ratings_do_not_overflow() {
+        assert_eq!(
+            part_one("in{A}\n\n{x=4294967295,m=4294967295,a=1,s=1}"),
+            Ok(2 * 4294967295 + 2)
+        );
+    }
+}
@@ -0,0 +1,330 @@
+use crate::error::{char_at, parse_lines, split_value, ParseError, SolveError};
+use crate::graph::Graph;
+use crate::year2023::day20::Type::{Broadcast, Conjunction, FlipFlop};
+use itertools::Itertools;
+use std::collections::{HashMap, VecDeque};
+
+#[derive(Debug)]
+pub struct Message<'a> {
+    pub source: &'a str,
+    pub target: &'a str,
+    pub high: bool,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Type {
+    FlipFlop,
+    Conjunction,
+    Broadcast,
+}
+
+#[derive(Debug)]
+pub struct Module<'a> {
+    pub name: &'a str,
+    pub kind: Type,
+    pub targets: Vec<&'a str>,
+    pub on: bool,
+    pub last_input: HashMap<String, bool>,
+}
+
+impl<'a> TryFrom<&'a str> for Module<'a> {
+    type Error = ParseError;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let (name, targets) = split_value(value, value, " -> ")?;
+
+        let (name, kind) = match char_at(name, 0) {
+            "%" => (&name[1..], FlipFlop),
+            "&" => (&name[1..], Conjunction),
+            _ => (name, Broadcast),
+        };
+        if name.is_empty() {
+            return Err(ParseError::new(value, name, "a module name"));
+        }
+
+        let targets = targets.split(',').map(|s| s.trim()).collect_vec();
+
+        Ok(Module {
+            kind,
+            name,
+            targets,
+            on: false,
+            last_input: HashMap::new(),
+        })
+    }
+}
+
+impl<'a> Module<'a> {
+    pub fn get_output(&mut self, msg: &Message) -> Vec<Message<'a>> {
+        let mut queue = vec![];
+
+        match self.kind {
+            Broadcast => {
+                for t in &self.targets {
+                    queue.push(Message {
+                        source: self.name,
+                        target: t,
+                        high: msg.high,
+                    });
+                }
+            }
+            FlipFlop => {
+                if !msg.high {
+                    self.on = !self.on;
+                    let high = self.on;
+
+                    for t in &self.targets {
+                        queue.push(Message {
+                            source: self.name,
+                            target: t,
+                            high,
+                        });
+                    }
+                }
+            }
+            Conjunction => {
+                self.last_input.insert(String::from(msg.source), msg.high);
+
+                let high = !self.last_input.iter().all(|(_, h)| *h);
+
+                for t in &self.targets {
+                    queue.push(Message {
+                        source: self.name,
+                        target: t,
+                        high,
+                    });
+                }
+            }
+        }
+
+        queue
+    }
+}
+
+/// The modules of the machine, along with a graph of the connections between them. The modules
+/// are indexed by their node id, and there is no module for an output that only receives
+/// messages.
+pub struct Machine<'a> {
+    pub graph: Graph<&'a str>,
+    pub modules: Vec<Option<Module<'a>>>,
+}
+
+impl<'a> Machine<'a> {
+    /// Returns the module with the given name, or None if nothing is sent on from that name.
+    ///
+    /// # Arguments
+    ///
+    /// 'name' - The name of the module.
+    fn module_mut(&mut self, name: &str) -> Option<&mut Module<'a>> {
+        let id = self.graph.id(&name)?;
+        self.modules[id].as_mut()
+    }
+}
+
+pub fn parse_modules(input: &str) -> Result<Machine<'_>, SolveError> {
+    let parsed = parse_lines(input, Module::try_from)?;
+
+    let mut graph = Graph::new();
+    for module in &parsed {
+        graph.add_node(module.name);
+    }
+    for module in &parsed {
+        for target in &module.targets {
+            graph.add_edge(module.name, *target, 1);
+        }
+    }
+
+    let mut modules: Vec<_> = (0..graph.len()).map(|_| None).collect();
+    for mut module in parsed {
+        let id = graph.add_node(module.name);
+
+        if module.kind == Conjunction {
+            for source in graph.predecessors(id) {
+                module
+                    .last_input
+                    .insert(String::from(*graph.key(source)), false);
+            }
+        }
+
+        modules[id] = Some(module);
+    }
+
+    Ok(Machine { graph, modules })
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_one(input: &str) -> Result<i32, SolveError> {
+    let mut machine = parse_modules(input)?;
+
+    let mut high_count = 0;
+    let mut low_count = 0;
+
+    for _ in 0..1000 {
+        let mut message_queue = VecDeque::new();
+        message_queue.push_back(Message {
+            source: "button",
+            high: false,
+            target: "broadcaster",
+        });
+
+        while let Some(msg) = message_queue.pop_front() {
+            if msg.high {
+                high_count += 1;
+            } else {
+                low_count += 1;
+            }
+
+            if let Some(module) = machine.module_mut(msg.target) {
+                let new_messages = module.get_output(&msg);
+
+                for m in new_messages {
+                    message_queue.push_back(m);
+                }
+            }
+        }
+    }
+
+    Ok(low_count * high_count)
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_two(input: &str) -> Result<i64, SolveError> {
+    let mut machine = parse_modules(input)?;
+
+    // Find the module that output to "rx"...
+    let last_module = machine
+        .graph
+        .id(&"rx")
+        .map(|rx| machine.graph.predecessors(rx))
+        .unwrap_or_default();
+    let last_module = match last_module[..] {
+        [id] if machine.modules[id]
+            .as_ref()
+            .is_some_and(|m| m.kind == Conjunction) =>
+        {
+            id
+        }
+        _ => {
+            return Err(SolveError::new(
+                "Expected a single conjunction module to output to 'rx'",
+            ))
+        }
+    };
+
+    // Find the modules that all output to the last module...
+    let final_modules = machine.graph.predecessors(last_module);
+    let mut final_modules: HashMap<_, _> = final_modules
+        .iter()
+        .map(|id| (*machine.graph.key(*id), 0i64))
+        .collect();
+    if final_modules.is_empty() {
+        return Err(SolveError::new(format!(
+            "No modules output to '{}'",
+            machine.graph.key(last_module)
+        )));
+    }
+
+    // We'll need to count how many button presses it takes to make each of those final modules output a high signal.
+    // That will be the cycle time for each module.
+    // The number of button presses for the system to output a low signal is equal to the LCM of all the cycle times
+    // of all the final modules. We'll assume that we can just multiply them together...
+
+    let mut btn_count = 0i64;
+    let mut output = 0i64;
+
+    while output == 0 {
+        btn_count += 1;
+
+        let mut message_queue = VecDeque::new();
+        message_queue.push_back(Message {
+            source: "button",
+            high: false,
+            target: "broadcaster",
+        });
+
+        while let Some(msg) = message_queue.pop_front() {
+            if let Some(fm) = final_modules.get(msg.source) {
+                if *fm == 0 && msg.high {
+                    final_modules.insert(msg.source, btn_count);
+
+                    output = final_modules.values().product();
+
+                    // Output will remain at zero until we've found a cycle time for all of the final modules
+                    if output != 0 {
+                        break;
+                    }
+                }
+            }
+
+            if let Some(module) = machine.module_mut(msg.target) {
+                let new_messages = module.get_output(&msg);
+
+                for m in new_messages {
+                    message_queue.push_back(m);
+                }
+            }
+        }
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2023::day20::*;
+
+    #[test]
+    fn part_one_correct() {
+        assert_eq!(
+            part_one(
+                "broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a"
+            ),
+            Ok(32000000)
+        );
+
+        assert_eq!(
+            part_one(
+                "broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output"
+            ),
+            Ok(11687500)
+        );
+    }
+
+    #[test]
+    fn malformed_modules_are_an_error() {
+        assert_eq!(
+            part_one("broadcaster -> a\n%a").unwrap_err().to_string(),
+            "line 2, column 3: expected ' -> ' but found nothing"
+        );
+        assert_eq!(
+            part_one("broadcaster -> a\n% -> b")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 2: expected a module name but found nothing"
+        );
+        assert_eq!(
+            part_two("broadcaster -> a\n%a -> rx"),
+            Err(SolveError::new(
+                "Expected a single conjunction module to output to 'rx'"
+            ))
+        );
+    }
+
+    // No provided test values for part 2
+}
@@ -0,0 +1,361 @@
+use crate::error::{ParseError, SolveError};
+use crate::grid::{char_at_position, Grid};
+use crate::visualize::{Color, Frame, Visualizer};
+use std::collections::{HashMap, HashSet};
+
+pub struct Map {
+    map: Grid<char>,
+    start: (usize, usize),
+    move_cache: HashMap<(usize, usize), Vec<(usize, usize)>>,
+}
+
+impl TryFrom<&str> for Map {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let map = Grid::parse(value, "'.', '#' or 'S'", |c| {
+            matches!(c, '.' | '#' | 'S').then_some(c)
+        })?;
+
+        let starts: Vec<_> = map
+            .iter()
+            .filter(|(_, c)| **c == 'S')
+            .map(|(pos, _)| pos)
+            .collect();
+        let start = *starts
+            .first()
+            .ok_or_else(|| ParseError::missing(value, "a starting position 'S'"))?;
+
+        if let Some(pos) = starts.get(1) {
+            let found = char_at_position(value, *pos);
+            return Err(ParseError::new(value, found, "a single starting position"));
+        }
+
+        Ok(Map {
+            map,
+            start,
+            move_cache: HashMap::new(),
+        })
+    }
+}
+
+impl Map {
+    pub fn get(&self, (x, y): (usize, usize)) -> char {
+        *self.map.get_wrapping((x as i64, y as i64))
+    }
+
+    fn get_moves(&mut self, start: (usize, usize)) -> Vec<(usize, usize)> {
+        if let Some(result) = self.move_cache.get(&start) {
+            return result.clone();
+        }
+
+        let mut result: Vec<_> = self.map.neighbours(start).collect();
+        result.retain(|p| self.get(*p) != '#');
+
+        self.move_cache.insert(start, result.clone());
+
+        result
+    }
+}
+
+fn iterate(map: &mut Map, start: HashSet<(usize, usize)>, steps: usize) -> HashSet<(usize, usize)> {
+    let mut start_set = start;
+
+    for _ in 0..steps {
+        let mut next_set = HashSet::new();
+
+        for s in start_set {
+            let next = map.get_moves(s);
+            for n in next {
+                next_set.insert(n);
+            }
+        }
+
+        start_set = next_set;
+    }
+
+    start_set
+}
+
+pub fn solve(input: &str, steps: usize) -> Result<usize, SolveError> {
+    let mut map = Map::try_from(input)?;
+
+    let mut start_set = HashSet::new();
+    start_set.insert(map.start);
+
+    start_set = iterate(&mut map, start_set, steps);
+
+    Ok(start_set.len())
+}
+
+/// The observation that the start, the middle of each edge, and each corner can be reached from
+/// one another as quickly as possible, thanks to the open row and column through the start.
+const OPEN_PATHS: &str = "The map must have open paths from the start and corners to each edge";
+
+/// Checks one of the observations about the shape of the map that solving the infinite map
+/// relies on.
+///
+/// # Arguments
+///
+/// 'condition' - Whether the observation holds.
+///
+/// 'message' - A description of the observation.
+fn check_shape(condition: bool, message: &str) -> Result<(), SolveError> {
+    if condition {
+        Ok(())
+    } else {
+        Err(SolveError::new(message))
+    }
+}
+
+pub fn solve_infinite(input: &str, steps: usize) -> Result<usize, SolveError> {
+    let mut map = Map::try_from(input)?;
+
+    let size = map.map.width().max(map.map.height());
+    let center = (size - 1) / 2;
+
+    // println!("Map size: {size}");
+    // println!("Center dist: {center}");
+
+    // Test our observations that the map size is always odd...
+    check_shape(
+        map.map.width() == map.map.height() && size % 2 == 1 && size > 1,
+        "The map must be square with an odd size",
+    )?;
+    // ...and the total steps will always land us on the far edge of a map tile from the center.
+    check_shape(
+        steps >= center && (steps - center).is_multiple_of(size),
+        "The steps must end on the far edge of a map tile",
+    )?;
+
+    // Find the counts for our even and odd parity full squares...
+    let mut set = HashSet::new();
+    set.insert(map.start);
+    set = iterate(&mut map, set, size);
+    check_shape(!set.contains(&(center, center)), OPEN_PATHS)?;
+    let full_odd = set.len();
+    set = iterate(&mut map, set, 1);
+    check_shape(set.contains(&(center, center)), OPEN_PATHS)?;
+    let full_even = set.len();
+
+    // println!("Even: {full_even}");
+    // println!("Odd: {full_odd}");
+
+    // Find the counts for the final tiles is we walk the total number of steps in each of the four
+    // cardinal directions
+    set.clear();
+    set.insert((0, center));
+    set = iterate(&mut map, set, size - 1);
+    check_shape(set.contains(&(size - 1, center)), OPEN_PATHS)?;
+    check_shape(set.contains(&(center, 0)), OPEN_PATHS)?;
+    check_shape(set.contains(&(center, size - 1)), OPEN_PATHS)?;
+    let east = set.len();
+
+    set.clear();
+    set.insert((size - 1, center));
+    set = iterate(&mut map, set, size - 1);
+    check_shape(set.contains(&(0, center)), OPEN_PATHS)?;
+    check_shape(set.contains(&(center, 0)), OPEN_PATHS)?;
+    check_shape(set.contains(&(center, size - 1)), OPEN_PATHS)?;
+    let west = set.len();
+
+    set.clear();
+    set.insert((center, size - 1));
+    set = iterate(&mut map, set, size - 1);
+    check_shape(set.contains(&(center, 0)), OPEN_PATHS)?;
+    check_shape(set.contains(&(0, center)), OPEN_PATHS)?;
+    check_shape(set.contains(&(size - 1, center)), OPEN_PATHS)?;
+    let north = set.len();
+
+    set.clear();
+    set.insert((center, 0));
+    set = iterate(&mut map, set, size - 1);
+    check_shape(set.contains(&(center, size - 1)), OPEN_PATHS)?;
+    check_shape(set.contains(&(0, center)), OPEN_PATHS)?;
+    check_shape(set.contains(&(size - 1, center)), OPEN_PATHS)?;
+    let south = set.len();
+
+    // println!("North: {north}");
+    // println!("South: {south}");
+    // println!("East: {east}");
+    // println!("West: {west}");
+
+    // Find our counts for the northwest corners
+    set.clear();
+    set.insert((size - 1, size - 1));
+    set = iterate(&mut map, set, center - 1);
+    check_shape(set.contains(&(center + 1, size - 1)), OPEN_PATHS)?;
+    check_shape(set.contains(&(size - 1, center + 1)), OPEN_PATHS)?;
+    let nw_small = set.len();
+    set = iterate(&mut map, set, size);
+    check_shape(set.contains(&(0, center)), OPEN_PATHS)?;
+    check_shape(set.contains(&(center, 0)), OPEN_PATHS)?;
+    let nw_big = set.len();
+
+    // println!("Northwest: {nw_small}, {nw_big}");
+
+    // Find our counts for the northeast corners
+    set.clear();
+    set.insert((0, size - 1));
+    set = iterate(&mut map, set, center - 1);
+    let ne_small = set.len();
+    set = iterate(&mut map, set, size);
+    let ne_big = set.len();
+
+    // println!("Northeast: {ne_small}, {ne_big}");
+
+    // Find our counts for the southwest corners
+    set.clear();
+    set.insert((size - 1, 0));
+    set = iterate(&mut map, set, center - 1);
+    let sw_small = set.len();
+    set = iterate(&mut map, set, size);
+    let sw_big = set.len();
+
+    // println!("Southwest: {sw_small}, {sw_big}");
+
+    // Find our counts for the southeast corners
+    set.clear();
+    set.insert((0, 0));
+    set = iterate(&mut map, set, center - 1);
+    let se_small = set.len();
+    set = iterate(&mut map, set, size);
+    let se_big = set.len();
+
+    // println!("Southeast: {se_small}, {se_big}");
+
+    let factor = (steps - center) / size;
+    check_shape(
+        factor.is_multiple_of(2) && factor > 0,
+        "The steps must cross an even number of map tiles",
+    )?;
+    let full_count = 2 * factor * factor - 2 * factor + 1;
+    let odd_count = factor * factor - 2 * factor + 1;
+    let even_count = full_count - odd_count;
+
+    // println!("Factor: {factor}");
+    // println!("Even Parity Count: {even_count}");
+    // println!("Odd Parity Count: {odd_count}");
+
+    Ok(odd_count * full_odd
+        + even_count * full_even
+        + north
+        + south
+        + east
+        + west
+        + factor * (nw_small + ne_small + se_small + sw_small)
+        + (factor - 1) * (nw_big + ne_big + se_big + sw_big))
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_one(input: &str) -> Result<usize, SolveError> {
+    solve(input, 64)
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_two(input: &str) -> Result<usize, SolveError> {
+    solve_infinite(input, 26_501_365)
+}
+
+/// Draws the garden plots the elf could be standing on after each of the steps taken in the
+/// first part.
+///
+/// # Arguments
+///
+/// 'input' - The input.
+///
+/// 'visualizer' - Where to show the frames.
+pub fn visualize(input: &str, visualizer: &mut Visualizer) -> Result<(), SolveError> {
+    let mut map = Map::try_from(input)?;
+    let mut reached = HashSet::from([map.start]);
+
+    for step in 0..=64 {
+        if step > 0 {
+            reached = iterate(&mut map, reached, 1);
+        }
+
+        let draw = || {
+            let caption = format!("After {step} steps: {} plots reachable", reached.len());
+            Frame::new(caption, &map.map, |pos, c| match c {
+                _ if reached.contains(&pos) => ('O', Color::Green),
+                '#' => ('#', Color::Dim),
+                c => (*c, Color::Plain),
+            })
+        };
+
+        // Always show the final step, however the steps are being throttled
+        if step < 64 {
+            visualizer.step(draw)?;
+        } else {
+            visualizer.show(draw())?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2023::day21::*;
+
+    #[test]
+    fn part_one_correct() {
+        assert_eq!(
+            solve(
+                "...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........",
+                6,
+            ),
+            Ok(16)
+        );
+    }
+
+    #[test]
+    fn part_two_correct() {
+        // The provided example won't let us make the required optimizations the actual input allows
+        // so we'll come up with our own simplified test case...
+
+        let input = ".......
+.......
+.......
+...S...
+.......
+.......
+.......";
+
+        assert_eq!(solve_infinite(input, 17), Ok(324));
+    }
+
+    #[test]
+    fn malformed_map_is_an_error() {
+        assert_eq!(
+            part_one("...\n.S.\n.x.").unwrap_err().to_string(),
+            "line 3, column 2: expected '.', '#' or 'S' but found 'x'"
+        );
+        assert_eq!(
+            part_one("...\n...\n...").unwrap_err().to_string(),
+            "line 3, column 4: expected a starting position 'S' but found nothing"
+        );
+        assert_eq!(
+            part_two("....\n.S..\n....\n...."),
+            Err(SolveError::new("The map must be square with an odd size"))
+        );
+    }
+}
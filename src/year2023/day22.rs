@@ -0,0 +1,274 @@
+use crate::error::{parse_lines, parse_value, split_value, ParseError, SolveError};
+use crate::parallel;
+use itertools::Itertools;
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub struct Brick {
+    pub blocks: Vec<(i32, i32, i32)>,
+    pub supported_by: HashSet<usize>,
+}
+
+/// The length of the longest brick that will be accepted. Each cube of a brick is stored
+/// separately, so this stops a malformed snapshot from using up all the memory.
+const MAX_BRICK_LENGTH: i32 = 1000;
+
+/// Parses the 'x,y,z' coordinates of one end of a brick.
+///
+/// # Arguments
+///
+/// 'text' - The text being parsed.
+///
+/// 'value' - The slice of the text holding the coordinates.
+fn parse_coordinates(text: &str, value: &str) -> Result<(i32, i32, i32), ParseError> {
+    value
+        .split(',')
+        .map(|x| match parse_value::<i32>(text, x, "a coordinate") {
+            Ok(x) if x >= 0 => Ok(x),
+            _ => Err(ParseError::new(text, x, "a coordinate")),
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .collect_tuple()
+        .ok_or_else(|| ParseError::new(text, value, "coordinates 'x,y,z'"))
+}
+
+impl TryFrom<&str> for Brick {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (a, b) = split_value(value, value, "~")?;
+        let (x1, y1, z1) = parse_coordinates(value, a)?;
+        let (x2, y2, z2) = parse_coordinates(value, b)?;
+
+        // Blocks should always be given to us in ascending order, but let's be sure...
+        if x1 > x2 || y1 > y2 || z1 > z2 {
+            return Err(ParseError::new(
+                value,
+                b,
+                format!("coordinates no lower than '{a}'"),
+            ));
+        }
+        if z1 < 1 {
+            return Err(ParseError::new(value, a, "coordinates above the ground"));
+        }
+        let lengths = [x2 - x1, y2 - y1, z2 - z1];
+        if lengths.iter().filter(|l| **l > 0).count() > 1 {
+            return Err(ParseError::new(value, b, "a brick along a single axis"));
+        }
+        if lengths.iter().any(|l| *l >= MAX_BRICK_LENGTH) {
+            return Err(ParseError::new(
+                value,
+                b,
+                format!("a brick at most {MAX_BRICK_LENGTH} cubes long"),
+            ));
+        }
+
+        let mut blocks = vec![];
+
+        for x in x1..=x2 {
+            for y in y1..=y2 {
+                for z in z1..=z2 {
+                    blocks.push((x, y, z));
+                }
+            }
+        }
+
+        Ok(Brick {
+            blocks,
+            supported_by: HashSet::new(),
+        })
+    }
+}
+
+pub fn settle(bricks: &mut [Brick]) {
+    if bricks.is_empty() {
+        return;
+    }
+
+    let width = bricks
+        .iter()
+        .map(|b| b.blocks.iter().map(|(x, _, _)| *x).max().unwrap())
+        .max()
+        .unwrap();
+    let depth = bricks
+        .iter()
+        .map(|b| b.blocks.iter().map(|(_, y, _)| *y).max().unwrap())
+        .max()
+        .unwrap();
+
+    let mut map = vec![vec![(0, None); (depth + 1) as usize]; (width + 1) as usize];
+
+    for (i, brick) in bricks.iter_mut().enumerate() {
+        let max_height_below = brick
+            .blocks
+            .iter()
+            .map(|(x, y, _)| map[*x as usize][*y as usize].0)
+            .max()
+            .unwrap();
+
+        brick.supported_by.clear();
+        for (x, y, _) in &brick.blocks {
+            let m = &map[*x as usize][*y as usize];
+            if m.0 == max_height_below {
+                if let Some(v) = m.1 {
+                    brick.supported_by.insert(v);
+                }
+            }
+        }
+
+        let min_block_height = brick.blocks.iter().map(|(_, _, z)| *z).min().unwrap();
+
+        let diff = (min_block_height - max_height_below) - 1;
+
+        for b in &mut brick.blocks {
+            b.2 -= diff;
+
+            map[b.0 as usize][b.1 as usize] = (b.2, Some(i));
+        }
+    }
+}
+
+/// Parses the bricks in the input, sorted from the lowest to the highest.
+///
+/// # Arguments
+///
+/// 'input' - The input.
+pub fn parse_bricks(input: &str) -> Result<Vec<Brick>, SolveError> {
+    let bricks = parse_lines(input, Brick::try_from)?;
+
+    Ok(bricks
+        .into_iter()
+        .sorted_by_key(|b| b.blocks.iter().map(|(_, _, z)| *z).min())
+        .collect_vec())
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_one(input: &str) -> Result<i32, SolveError> {
+    let mut bricks = parse_bricks(input)?;
+
+    settle(&mut bricks);
+
+    let mut safe_count = 0;
+
+    for (i, _) in bricks.iter().enumerate() {
+        let mut filter = HashSet::new();
+        filter.insert(i);
+
+        let any_match = bricks.iter().any(|b| b.supported_by == filter);
+
+        if !any_match {
+            // println!("Destroying brick {i} cause 0 bricks to fall");
+            safe_count += 1;
+        }
+    }
+
+    Ok(safe_count)
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_two(input: &str) -> Result<i32, SolveError> {
+    let mut bricks = parse_bricks(input)?;
+
+    settle(&mut bricks);
+
+    // Removing each brick is independent of removing any other, so count them separately
+    let fall_counts = parallel::map((0..bricks.len()).collect(), |i| {
+        let mut destroyed = HashSet::new();
+        destroyed.insert(i);
+
+        let mut fall_count = 0;
+
+        for (j, b) in bricks.iter().enumerate() {
+            if j > i {
+                let all_supports_destroyed = !b.supported_by.is_empty()
+                    && b.supported_by.iter().all(|s| destroyed.contains(s));
+
+                if all_supports_destroyed {
+                    // This brick will fall...
+                    fall_count += 1;
+                    destroyed.insert(j);
+                }
+            }
+        }
+
+        fall_count
+    });
+
+    let total_fall_count = fall_counts.iter().sum();
+
+    Ok(total_fall_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2023::day22::*;
+
+    #[test]
+    fn part_one_correct() {
+        assert_eq!(
+            part_one(
+                "1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9"
+            ),
+            Ok(5)
+        );
+    }
+
+    #[test]
+    fn part_two_correct() {
+        assert_eq!(
+            part_two(
+                "1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9"
+            ),
+            Ok(7)
+        );
+    }
+
+    #[test]
+    fn malformed_brick_is_an_error() {
+        assert_eq!(
+            part_one("1,0,1~1,2,1\n0,0,2~2,0").unwrap_err().to_string(),
+            "line 2, column 7: expected coordinates 'x,y,z' but found '2,0'"
+        );
+        assert_eq!(
+            part_two("1,0,1~1,2,1\n2,0,2~0,0,2")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 7: expected coordinates no lower than '2,0,2' but found '0,0,2'"
+        );
+        assert_eq!(
+            part_one("1,0,1~1,2,1\n0,-1,2~2,0,2")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 3: expected a coordinate but found '-1'"
+        );
+        assert_eq!(
+            part_one("1,0,1~1,2,3").unwrap_err().to_string(),
+            "line 1, column 7: expected a brick along a single axis but found '1,2,3'"
+        );
+        assert_eq!(
+            part_one("0,0,1~0,0,5000").unwrap_err().to_string(),
+            "line 1, column 7: expected a brick at most 1000 cubes long but found '0,0,5000'"
+        );
+    }
+}
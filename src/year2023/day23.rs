@@ -0,0 +1,384 @@
+use crate::direction::Direction::{self, *};
+use crate::error::{ParseError, SolveError};
+use crate::graph::Graph;
+use crate::grid::{char_at_position, Grid};
+use crate::visualize::{Color, Frame, Visualizer};
+use std::collections::{HashSet, VecDeque};
+
+/// The (x, y) position of a node in the map.
+type Position = (usize, usize);
+
+fn get_move_options(map: &Grid<char>, pos: (usize, usize)) -> Vec<(usize, usize)> {
+    [Left, Up, Right, Down]
+        .into_iter()
+        // Never climb back out of the bottom row, where the trail ends
+        .filter(|direction| *direction != Up || pos.1 < map.height() - 1)
+        .filter_map(|direction| {
+            let next = map.offset(pos, direction.vector())?;
+            let slope = Direction::from_char(map[next]);
+
+            // A slope can't be climbed from the side it points towards
+            (map[next] != '#' && slope != Some(direction.reverse())).then_some(next)
+        })
+        .collect()
+}
+
+/// Returns the positions along the path leading away from a node, in order, up to and including
+/// the next node, which is the first position where the path doesn't simply carry on.
+///
+/// # Arguments
+///
+/// 'map' - The map of the trails.
+///
+/// 'first' - The first position along the path.
+///
+/// 'node' - The position of the node the path leads away from.
+fn follow_path(map: &Grid<char>, first: Position, node: Position) -> Vec<Position> {
+    let mut path = vec![first];
+    let mut last = node;
+
+    loop {
+        let pos = path[path.len() - 1];
+        let mut options = get_move_options(map, pos);
+        options.retain(|option| *option != last);
+
+        match options[..] {
+            [next] => {
+                path.push(next);
+                last = pos;
+            }
+            _ => return path,
+        }
+    }
+}
+
+/// Returns the node at the end of each path leading away from a node, along with the length of
+/// the path.
+///
+/// # Arguments
+///
+/// 'map' - The map of the trails.
+///
+/// 'pos' - The position of the node.
+fn find_paths(map: &Grid<char>, pos: Position) -> Vec<(Position, usize)> {
+    get_move_options(map, pos)
+        .into_iter()
+        .map(|m| {
+            let path = follow_path(map, m, pos);
+            (path[path.len() - 1], path.len())
+        })
+        .collect()
+}
+
+/// Parses the map of the trails, checking that the trail starts in the top left.
+///
+/// # Arguments
+///
+/// 'input' - The input.
+pub fn parse_map(input: &str) -> Result<Grid<char>, SolveError> {
+    let map = Grid::parse(input, "a path, forest or slope", |c| {
+        "#.<>^v".contains(c).then_some(c)
+    })?;
+
+    if map.height() < 2 || map.width() < 2 {
+        return Err(ParseError::missing(input, "a map of at least 2 by 2 characters").into());
+    }
+    if map[(1, 0)] != '.' {
+        let found = char_at_position(input, (1, 0));
+        return Err(ParseError::new(input, found, "the start of the trail '.'").into());
+    }
+
+    Ok(map)
+}
+
+/// Builds a graph of the junctions of the trails, along with the start and end, where each edge
+/// is a path that can be followed from one junction to the next, weighted by its length.
+///
+/// # Arguments
+///
+/// 'input' - The input.
+pub fn build_graph(input: &str) -> Result<Graph<Position>, SolveError> {
+    let map = parse_map(input)?;
+
+    let mut searched = HashSet::new();
+    let mut nodes = VecDeque::new();
+    let mut graph = Graph::new();
+    nodes.push_back((1, 0));
+    graph.add_node((1, 0));
+
+    while let Some(node) = nodes.pop_front() {
+        searched.insert(node);
+
+        for (end, cost) in find_paths(&map, node) {
+            if !searched.contains(&end) && !nodes.contains(&end) {
+                nodes.push_back(end);
+            }
+            graph.add_edge(node, end, cost);
+        }
+    }
+
+    Ok(graph)
+}
+
+/// Returns the ids of the start node, which is the node with the lowest y value, and the end
+/// node, which is the node with the highest y value.
+///
+/// # Arguments
+///
+/// 'graph' - The graph of the paths between the nodes.
+fn find_start_and_end(graph: &Graph<Position>) -> Result<(usize, usize), SolveError> {
+    let start = graph.nodes().min_by_key(|(_, (_, y))| *y);
+    let end = graph.nodes().max_by_key(|(_, (_, y))| *y);
+
+    start
+        .zip(end)
+        .map(|((start, _), (end, _))| (start, end))
+        .filter(|(start, _)| !graph.edges(*start).is_empty())
+        .ok_or(SolveError::new("No paths lead away from the start"))
+}
+
+/// Returns the length of the longest hike down the slopes from the start to the end, along with
+/// the nodes it passes through.
+///
+/// # Arguments
+///
+/// 'graph' - The graph of the paths between the nodes, which must follow the slopes.
+fn longest_downhill(graph: &Graph<Position>) -> Result<(usize, Vec<usize>), SolveError> {
+    let (start, end) = find_start_and_end(graph)?;
+
+    // The slopes only allow each path to be followed one way, so the longest route to each node
+    // can be found by visiting the nodes in order
+    let order = graph.topological_sort().ok_or(SolveError::new(
+        "The slopes must stop the trails from looping back on themselves",
+    ))?;
+
+    let mut costs = vec![None; graph.len()];
+    let mut previous = vec![None; graph.len()];
+    costs[start] = Some(0);
+
+    for n in order {
+        if let Some(start_cost) = costs[n] {
+            for (end, cost) in graph.edges(n) {
+                if costs[*end] < Some(start_cost + cost) {
+                    costs[*end] = Some(start_cost + cost);
+                    previous[*end] = Some(n);
+                }
+            }
+        }
+    }
+
+    let cost = costs[end].ok_or(SolveError::new("No trail leads to the end"))?;
+    let mut path: Vec<_> = std::iter::successors(Some(end), |n| previous[*n]).collect();
+    path.reverse();
+
+    Ok((cost, path))
+}
+
+/// Returns the length of the longest hike from the start to the end, trying every route that
+/// doesn't visit a node twice, along with the nodes it passes through.
+///
+/// # Arguments
+///
+/// 'graph' - The graph of the paths between the nodes.
+fn longest_hike(graph: &Graph<Position>) -> Result<(usize, Vec<usize>), SolveError> {
+    let (start, end) = find_start_and_end(graph)?;
+
+    let mut path_heads = VecDeque::new();
+    path_heads.push_back((vec![start], 0));
+
+    let mut longest = (0, vec![]);
+
+    while let Some(ph) = path_heads.pop_front() {
+        let tail = ph.0.last().unwrap();
+
+        if *tail == end {
+            if ph.1 > longest.0 {
+                longest = (ph.1, ph.0);
+            }
+        } else {
+            graph
+                .edges(*tail)
+                .iter()
+                .filter(|(end, _)| !ph.0.contains(end))
+                .for_each(|(end, cost)| {
+                    let mut new_path_head = ph.0.clone();
+                    new_path_head.push(*end);
+                    path_heads.push_back((new_path_head, ph.1 + cost));
+                });
+        }
+    }
+
+    Ok(longest)
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_one(input: &str) -> Result<usize, SolveError> {
+    let graph = build_graph(input)?;
+
+    Ok(longest_downhill(&graph)?.0)
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_two(input: &str) -> Result<usize, SolveError> {
+    let input = input.replace(['>', '<', '^', 'v'], ".");
+
+    let graph = build_graph(input.as_str())?;
+
+    Ok(longest_hike(&graph)?.0)
+}
+
+/// Draws the longest hike down the slopes, and then the longest hike when the slopes can be
+/// climbed, following each one a path at a time from the start.
+///
+/// # Arguments
+///
+/// 'input' - The input.
+///
+/// 'visualizer' - Where to show the frames.
+pub fn visualize(input: &str, visualizer: &mut Visualizer) -> Result<(), SolveError> {
+    let dry = input.replace(['>', '<', '^', 'v'], ".");
+    let downhill = build_graph(input)?;
+    let anywhere = build_graph(&dry)?;
+
+    let hikes = [
+        (
+            "down the slopes",
+            input,
+            longest_downhill(&downhill)?,
+            downhill,
+        ),
+        (
+            "climbing the slopes",
+            &dry,
+            longest_hike(&anywhere)?,
+            anywhere,
+        ),
+    ];
+
+    for (name, input, (length, nodes), graph) in hikes {
+        // Draw the hike over the map it was found on, so that its paths can be followed again
+        let map = parse_map(input)?;
+        let mut hiked = HashSet::new();
+        let mut steps = 0;
+
+        for (i, pair) in nodes.windows(2).enumerate() {
+            let (from, to) = (*graph.key(pair[0]), *graph.key(pair[1]));
+            hiked.insert(from);
+
+            // Find which of the paths leaving the node leads to the next one
+            let path = get_move_options(&map, from)
+                .into_iter()
+                .map(|first| follow_path(&map, first, from))
+                .find(|path| path[path.len() - 1] == to)
+                .unwrap_or_default();
+            steps += path.len();
+            hiked.extend(path);
+
+            let draw = || {
+                let caption = format!("The longest hike {name}: {steps} of {length} steps");
+                Frame::new(caption, &map, |pos, c| match c {
+                    _ if hiked.contains(&pos) => ('O', Color::Red),
+                    '#' => ('#', Color::Dim),
+                    c => (*c, Color::Plain),
+                })
+            };
+
+            if i + 2 < nodes.len() {
+                visualizer.step(draw)?;
+            } else {
+                visualizer.show(draw())?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2023::day23::*;
+
+    #[test]
+    fn part_one_correct() {
+        assert_eq!(
+            part_one(
+                "#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#"
+            ),
+            Ok(94)
+        );
+    }
+
+    #[test]
+    fn part_two_correct() {
+        assert_eq!(
+            part_two(
+                "#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#"
+            ),
+            Ok(154)
+        );
+    }
+
+    #[test]
+    fn malformed_map_is_an_error() {
+        assert_eq!(
+            part_one("#.#\n#x#\n#.#").unwrap_err().to_string(),
+            "line 2, column 2: expected a path, forest or slope but found 'x'"
+        );
+        assert_eq!(
+            part_two("##.\n#.#\n#.#").unwrap_err().to_string(),
+            "line 1, column 2: expected the start of the trail '.' but found '#'"
+        );
+    }
+}
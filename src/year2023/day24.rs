@@ -1,38 +1,47 @@
+use crate::error::{parse_lines, parse_value, split_value, ParseError, SolveError};
 use itertools::Itertools;
 use rulinalg::matrix::Matrix;
 use std::ops::Mul;
 
 #[derive(Debug)]
-struct Vector {
-    x: f64,
-    y: f64,
-    z: f64,
+pub struct Vector {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
 }
 
-impl From<&str> for Vector {
-    fn from(value: &str) -> Self {
+impl TryFrom<&str> for Vector {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
         let (x, y, z) = value
             .split(',')
-            .map(|s| s.trim())
-            .flat_map(|s| s.parse::<f64>())
+            .map(|s| parse_value::<f64>(value, s.trim(), "a number"))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
             .collect_tuple()
-            .unwrap();
+            .ok_or_else(|| ParseError::new(value, value.trim(), "a vector 'x, y, z'"))?;
 
-        Vector { x, y, z }
+        Ok(Vector { x, y, z })
     }
 }
 
 #[derive(Debug)]
-struct Hailstone {
-    pos: Vector,
-    vec: Vector,
+pub struct Hailstone {
+    pub pos: Vector,
+    pub vec: Vector,
 }
 
-impl From<&str> for Hailstone {
-    fn from(value: &str) -> Self {
-        let (pos, vec) = value.split('@').map(Vector::from).collect_tuple().unwrap();
+impl TryFrom<&str> for Hailstone {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (pos, vec) = split_value(value, value, "@")?;
 
-        Hailstone { pos, vec }
+        Ok(Hailstone {
+            pos: Vector::try_from(pos).map_err(|e| e.within(value, pos))?,
+            vec: Vector::try_from(vec).map_err(|e| e.within(value, vec))?,
+        })
     }
 }
 
@@ -57,8 +66,8 @@ fn get_intersect_x_y(a: &Hailstone, b: &Hailstone) -> Option<(f64, f64)> {
     Some((a.pos.x + c * a.vec.x, a.pos.y + c * a.vec.y))
 }
 
-fn solve_part_one(input: &str, bounds: (f64, f64)) -> i32 {
-    let stones = input.lines().map(Hailstone::from).collect_vec();
+pub fn solve_part_one(input: &str, bounds: (f64, f64)) -> Result<i32, SolveError> {
+    let stones = parse_lines(input, Hailstone::try_from)?;
 
     let mut count = 0;
 
@@ -72,7 +81,7 @@ fn solve_part_one(input: &str, bounds: (f64, f64)) -> i32 {
         }
     }
 
-    count
+    Ok(count)
 }
 
 fn get_coefficients(h1: &Hailstone, h2: &Hailstone) -> (Vec<f64>, Vec<f64>) {
@@ -91,7 +100,7 @@ fn get_coefficients(h1: &Hailstone, h2: &Hailstone) -> (Vec<f64>, Vec<f64>) {
 /// #Argument
 ///
 /// 'input' - The input.
-pub fn part_one(input: &str) -> i32 {
+pub fn part_one(input: &str) -> Result<i32, SolveError> {
     solve_part_one(input, (200000000000000.0, 400000000000000.0))
 }
 
@@ -100,8 +109,11 @@ pub fn part_one(input: &str) -> i32 {
 /// #Argument
 ///
 /// 'input' - The input.
-pub fn part_two(input: &str) -> i64 {
-    let stones = input.lines().map(Hailstone::from).collect_vec();
+pub fn part_two(input: &str) -> Result<i64, SolveError> {
+    let stones = parse_lines(input, Hailstone::try_from)?;
+    if stones.len() < 5 {
+        return Err(SolveError::new("At least 5 hailstones are needed"));
+    }
 
     let mut coefficients = (vec![], vec![]);
 
@@ -116,7 +128,11 @@ pub fn part_two(input: &str) -> i64 {
     let a = Matrix::new(4, 4, coefficients.0);
     let x = Matrix::new(4, 1, coefficients.1);
 
-    let result = a.inverse().unwrap().mul(x).into_vec();
+    let result = a
+        .inverse()
+        .map_err(|_| SolveError::new("The hailstones' paths can't be solved for a single rock"))?
+        .mul(x)
+        .into_vec();
     let (a, b, d, e) = result.iter().copied().collect_tuple().unwrap();
 
     let t1 = (a - h1.pos.x) / (h1.vec.x - d);
@@ -134,12 +150,12 @@ pub fn part_two(input: &str) -> i64 {
         .collect_tuple()
         .unwrap();
 
-    a + b + c
+    Ok(a + b + c)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day24::*;
+    use crate::year2023::day24::*;
 
     #[test]
     fn part_one_correct() {
@@ -152,7 +168,7 @@ mod tests {
 20, 19, 15 @  1, -5, -3",
                 (7.0, 27.0)
             ),
-            2
+            Ok(2)
         );
     }
 
@@ -166,7 +182,27 @@ mod tests {
 12, 31, 28 @ -1, -2, -1
 20, 19, 15 @  1, -5, -3"
             ),
-            47
+            Ok(47)
+        );
+    }
+
+    #[test]
+    fn malformed_hailstone_is_an_error() {
+        assert_eq!(
+            part_one("19, 13, 30 @ -2, 1, -2\n18, 19 @ -1, -1, -2")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 1: expected a vector 'x, y, z' but found '18, 19'"
+        );
+        assert_eq!(
+            part_one("19, 13, 30 @ -2, 1, -2\n18, 19, 22 @ -1, y, -2")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 18: expected a number but found 'y'"
+        );
+        assert_eq!(
+            part_two("19, 13, 30 @ -2, 1, -2"),
+            Err(SolveError::new("At least 5 hailstones are needed"))
         );
     }
 }
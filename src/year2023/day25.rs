@@ -0,0 +1,110 @@
+use crate::error::{split_value, ParseError, SolveError};
+use crate::graph::Graph;
+use crate::parallel;
+
+/// How many trials of Karger's algorithm to run before finding the cut the slow but certain way.
+/// On a real input, these take about as long as the Stoer-Wagner algorithm does.
+const TRIALS: usize = 50;
+
+/// Parses the wiring diagram into an undirected graph of the components.
+///
+/// # Arguments
+///
+/// 'input' - The wiring diagram, listing the components each component is connected to.
+pub fn parse_graph(input: &str) -> Result<Graph<&str>, ParseError> {
+    let mut graph = Graph::new();
+
+    for line in input.lines() {
+        let (start, ends) = split_value(input, line, ":")?;
+
+        for end in ends.split_whitespace() {
+            graph.add_undirected_edge(start, end, 1);
+        }
+    }
+
+    if graph.len() < 2 {
+        return Err(ParseError::missing(input, "at least two components"));
+    }
+
+    Ok(graph)
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_one(input: &str) -> Result<usize, SolveError> {
+    let g = parse_graph(input)?;
+
+    // Theoretically, we can find the minimal cut of the graph by iterating through Karger's algorithm N * N * ln(N)
+    // times (where N is the number of nodes in the graph) and keeping the the result with the lowest number of
+    // remaining edges. However, we know from the puzzle description that the minimal cut will have three edges, so
+    // we only have to keep repeating until we find a solution that collapses into only three edges. In practice that
+    // takes only a few dozen iterations, so after TRIALS of them we stop guessing and find the minimal cut with the
+    // slower but certain Stoer-Wagner algorithm instead, which also tells us if there's no cut of three edges at all.
+    let cut = match parallel::repeat_until(TRIALS, || g.contract().filter(|cut| cut.edges <= 3)) {
+        Some(cut) => cut,
+        None => g
+            .stoer_wagner()
+            .ok_or(SolveError::new("No cut of three wires found"))?,
+    };
+
+    match cut.edges {
+        0 => Err(SolveError::new("The components must all be connected")),
+        3 => Ok(cut.sizes.0 * cut.sizes.1),
+        _ => Err(SolveError::new("No cut of three wires found")),
+    }
+}
+
+///
+///
+/// #Argument
+///
+/// 'input' - The input.
+pub fn part_two(_: &str) -> Result<i32, SolveError> {
+    Ok(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::year2023::day25::*;
+
+    #[test]
+    fn part_one_correct() {
+        assert_eq!(
+            part_one(
+                "jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr"
+            ),
+            Ok(54)
+        );
+    }
+
+    #[test]
+    fn malformed_graph_is_an_error() {
+        assert_eq!(
+            part_one("jqt: rhn xhk\nrsh frs").unwrap_err().to_string(),
+            "line 2, column 8: expected ':' but found nothing"
+        );
+        assert_eq!(
+            part_one("jqt: rhn\nrsh: frs"),
+            Err(SolveError::new("The components must all be connected"))
+        );
+        assert_eq!(
+            part_one("jqt: rhn xhk"),
+            Err(SolveError::new("No cut of three wires found"))
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use advent_of_code_2023::interval::Interval;
+use advent_of_code_2023::solver;
+use advent_of_code_2023::year2023::{day05, day09, day17};
+use std::str::FromStr;
+
+#[test]
+fn call_day_functions_directly() {
+    let input = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+    assert_eq!(day09::part_one(input), Ok(114));
+    assert_eq!(day09::part_two(input), Ok(2));
+    assert_eq!(day09::calc_next_value(&[0, 3, 6, 9, 12, 15]), Some(18));
+}
+
+#[test]
+fn reuse_day_types() {
+    let mapper = day05::Mapper::from_str(
+        "seed-to-soil map:
+50 98 2
+52 50 48",
+    )
+    .unwrap();
+
+    assert_eq!(mapper.name, "seed-to-soil");
+    assert_eq!(mapper.eval(79), 81);
+    assert_eq!(mapper.eval(10), 10);
+    assert_eq!(
+        mapper.eval_ranges(&[Interval::new(79, 93)]),
+        vec![Interval::new(81, 95)]
+    );
+
+    let map = day17::Map::try_from("123\n456").unwrap();
+    assert_eq!((map.heat.width(), map.heat.height()), (3, 2));
+    assert_eq!(map.heat[(2, 1)], 6);
+}
+
+#[test]
+fn run_through_registry() {
+    let solver = solver::find(2023, 9).unwrap();
+
+    assert_eq!(solver.title(), "Mirage Maintenance");
+    assert_eq!(solver.part_one("1 2 3").unwrap().to_string(), "4");
+
+    let error = solver.part_two("1 2\n3 x").err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "day 9, line 2, column 3: expected a number but found 'x'"
+    );
+}
@@ -0,0 +1,155 @@
+//! Checks the days that rely on a shortcut against a simple brute force version of the same
+//! calculation, using small randomly generated inputs.
+
+use advent_of_code_2023::interval::{Interval, Region};
+use advent_of_code_2023::year2023::{day05, day06, day12, day19};
+use proptest::prelude::*;
+use std::collections::BTreeSet;
+
+/// Counts the whole numbers of milliseconds the button can be held for that beat the record, by
+/// trying every one.
+fn race_brute_force(t: i64, d: i64) -> i64 {
+    (0..=t).filter(|x| x * (t - x) > d).count() as i64
+}
+
+/// Counts the ways of replacing each '?' with '.' or '#' that give the expected groups of '#'.
+fn springs_brute_force(pattern: &[char], groups: &[usize]) -> i64 {
+    let unknown: Vec<_> = (0..pattern.len()).filter(|&i| pattern[i] == '?').collect();
+
+    (0..1u32 << unknown.len())
+        .filter(|bits| {
+            let mut springs = pattern.to_vec();
+            for (bit, &i) in unknown.iter().enumerate() {
+                springs[i] = if bits & (1 << bit) == 0 { '.' } else { '#' };
+            }
+
+            let found: Vec<_> = springs
+                .split(|c| *c == '.')
+                .map(|g| g.len())
+                .filter(|&len| len > 0)
+                .collect();
+            found == groups
+        })
+        .count() as i64
+}
+
+/// Returns every value found in a set of intervals.
+fn values(intervals: &[Interval]) -> BTreeSet<i64> {
+    intervals.iter().flat_map(|i| i.start..i.end).collect()
+}
+
+/// Generates the text of a map whose partial maps don't overlap, as the puzzle's never do.
+fn mapper_text() -> impl Strategy<Value = String> {
+    (
+        0..10i64,
+        prop::collection::vec((0..5i64, 1..6i64, 0..40i64), 0..4),
+    )
+        .prop_map(|(mut source, partials)| {
+            let mut text = String::from("seed-to-soil map:");
+            for (gap, length, destination) in partials {
+                source += gap;
+                text += &format!("\n{destination} {source} {length}");
+                source += length;
+            }
+            text
+        })
+}
+
+/// Generates the text of a workflow with up to four rules comparing against small values.
+fn workflow_text() -> impl Strategy<Value = String> {
+    let result = prop::sample::select(vec!["A", "R", "px"]);
+    let rule = (
+        prop::sample::select(vec!['x', 'm', 'a', 's']),
+        prop::sample::select(vec!['<', '>']),
+        1..8u32,
+        result.clone(),
+    )
+        .prop_map(|(var, op, val, result)| format!("{var}{op}{val}:{result},"));
+
+    (prop::collection::vec(rule, 0..4), result)
+        .prop_map(|(rules, default)| format!("{}{default}", rules.concat()))
+}
+
+proptest! {
+    #[test]
+    fn race_solutions_match_brute_force(t in 0..100i64, d in 0..2600i64) {
+        prop_assert_eq!(day06::solve(t, d).count, race_brute_force(t, d));
+    }
+
+    #[test]
+    fn spring_arrangements_match_brute_force(
+        pattern in prop::collection::vec(prop::sample::select(vec!['.', '#', '?']), 1..12),
+        groups in prop::collection::vec(1..4usize, 1..4),
+    ) {
+        let row = format!(
+            "{} {}",
+            pattern.iter().collect::<String>(),
+            groups.iter().map(|g| g.to_string()).collect::<Vec<_>>().join(",")
+        );
+
+        prop_assert_eq!(
+            day12::get_valid_possibility_count(&row),
+            Ok(springs_brute_force(&pattern, &groups))
+        );
+    }
+
+    #[test]
+    fn mapped_ranges_match_brute_force(
+        text in mapper_text(),
+        ranges in prop::collection::vec((0..50i64, 0..8i64), 0..4),
+    ) {
+        let mapper: day05::Mapper = text.parse().unwrap();
+        let ranges: Vec<_> = ranges.iter().map(|&(s, l)| Interval::new(s, s + l)).collect();
+        let mapped = mapper.eval_ranges(&ranges);
+
+        let expected: BTreeSet<_> = values(&ranges).iter().map(|&v| mapper.eval(v)).collect();
+        prop_assert_eq!(values(&mapped), expected);
+
+        // The result should be as few ranges as possible, in order:
+        for pair in mapped.windows(2) {
+            prop_assert!(pair[0].end < pair[1].start);
+        }
+        prop_assert!(mapped.iter().all(|r| !r.is_empty()));
+    }
+
+    #[test]
+    fn split_templates_match_brute_force(
+        text in workflow_text(),
+        axes in prop::array::uniform4((1..8i64, 0..4i64)),
+    ) {
+        let workflow = day19::Workflow::try_from(text.as_str()).unwrap();
+        let region = Region::new(axes.map(|(first, length)| Interval::new(first, first + length)));
+        let template = day19::Template { rule: String::from("in"), region };
+        let templates = workflow.split_template(&template);
+
+        // Every part in the template should end up in exactly one of the split templates, and it
+        // should be the one for the result the workflow gives that part.
+        let mut count = 0;
+        for x in region.axes[0].start..region.axes[0].end {
+            for m in region.axes[1].start..region.axes[1].end {
+                for a in region.axes[2].start..region.axes[2].end {
+                    for s in region.axes[3].start..region.axes[3].end {
+                        let point = [x, m, a, s];
+                        let part = day19::Part {
+                            x: x as u32,
+                            m: m as u32,
+                            a: a as u32,
+                            s: s as u32,
+                        };
+                        let containing: Vec<_> = templates
+                            .iter()
+                            .filter(|t| t.region.contains(point))
+                            .collect();
+
+                        prop_assert_eq!(containing.len(), 1);
+                        prop_assert_eq!(containing[0].rule.as_str(), workflow.get_result(&part));
+                        count += 1;
+                    }
+                }
+            }
+        }
+
+        let total: u64 = templates.iter().map(|t| t.get_distinct_count()).sum();
+        prop_assert_eq!(total, count);
+    }
+}